@@ -12,6 +12,7 @@ FEATS=(
 	dxgi
 	gdi
 	'gdi mf'
+	gdiplus
 	gui
 	'gui shell'
 	kernel
@@ -24,7 +25,10 @@ FEATS=(
 	user
 	uxtheme
 	version
+	winhttp
 	winspool
+	ws2
+	wtsapi
 )
 
 set -e
@@ -15,6 +15,11 @@ use crate::prelude::*;
 /// leave the others as default. The needed mask flags will be automatically
 /// set.
 ///
+/// If `mask` has [`co::SEE_MASK::NOCLOSEPROCESS`](crate::co::SEE_MASK::NOCLOSEPROCESS),
+/// returns the handle to the new process. It can still be `None`, when no
+/// process was launched – e.g., the file was handled by an already running
+/// instance of the program.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -27,11 +32,45 @@ use crate::prelude::*;
 /// })?;
 /// # w::SysResult::Ok(())
 /// ```
-pub fn ShellExecuteEx(exec_info: &SHELLEXECUTEINFO) -> SysResult<()> {
+///
+/// Running a program with elevated privileges, which will prompt the user
+/// with UAC, then waiting for it to finish:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hprocess = w::ShellExecuteEx(&w::SHELLEXECUTEINFO {
+///     mask: co::SEE_MASK::NOCLOSEPROCESS,
+///     verb: Some("runas"),
+///     file: "C:\\Temp\\setup.exe",
+///     parameters: Some("/silent"),
+///     show: co::SW::SHOWNORMAL,
+///     ..Default::default()
+/// })?;
+///
+/// if let Some(hprocess) = hprocess {
+///     hprocess.WaitForSingleObject(None)?;
+///     let exit_code = hprocess.GetExitCodeProcess()?;
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// If the user refuses the elevation,
+/// [`co::ERROR::CANCELLED`](crate::co::ERROR::CANCELLED) is returned.
+pub fn ShellExecuteEx(
+	exec_info: &SHELLEXECUTEINFO,
+) -> SysResult<Option<CloseHandleGuard<HPROCESS>>>
+{
 	let mut buf = exec_info.to_raw();
 	bool_to_sysresult(
 		unsafe { ffi::ShellExecuteExW(&mut buf.raw as *mut _ as _) },
-	)
+	).map(|_| {
+		if buf.raw.hProcess == HPROCESS::NULL {
+			None
+		} else {
+			Some(unsafe { CloseHandleGuard::new(buf.raw.hProcess.raw_copy()) })
+		}
+	})
 }
 
 /// [`SHGetKnownFolderPath`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath)
@@ -3,6 +3,43 @@
 use crate::co::*;
 use crate::comctl::privs::*;
 
+const_wm! { ACM;
+	/// Animation control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-messages)
+	/// (`u32`).
+	=>
+	OPEN WM::USER.raw() + 103
+	PLAY WM::USER.raw() + 101
+	STOP WM::USER.raw() + 102
+	ISPLAYING WM::USER.raw() + 104
+}
+
+const_cmd! { ACN;
+	/// Animation control `WM_COMMAND`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-notifications)
+	/// (`u16`).
+	=>
+	START 1
+	STOP 2
+}
+
+const_ws! { ACS: u32;
+	/// Animation control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/animation-control-styles)
+	/// (`u32`).
+	=>
+	/// Centers the animation in the control window.
+	CENTER 0x0001
+	/// Draws the animation using a transparent background rather than the
+	/// background color specified in the animation clip.
+	TRANSPARENT 0x0002
+	/// Starts playing the animation as soon as the AVI clip is opened.
+	AUTOPLAY 0x0004
+	/// Plays the clip without creating a thread; the control uses a timer to
+	/// synchronize playback.
+	TIMER 0x0008
+}
+
 const_ordinary! { ADRF: u32;
 	/// [`NMTVASYNCDRAW`](crate::NMTVASYNCDRAW) `dwRetFlags` (`u32`).
 	///
@@ -185,6 +222,26 @@ const_wm! { CCM;
 	DPISCALE CCM_FIRST + 0xc
 }
 
+const_ws! { CCS: u32;
+	/// Common control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/common-control-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOP 0x0000_0001
+	NOMOVEY 0x0000_0002
+	BOTTOM 0x0000_0003
+	NORESIZE 0x0000_0004
+	NOPARENTALIGN 0x0000_0008
+	ADJUSTABLE 0x0000_0020
+	NODIVIDER 0x0000_0040
+	VERT 0x0000_0080
+	LEFT Self::VERT.0 | Self::TOP.0
+	RIGHT Self::VERT.0 | Self::BOTTOM.0
+	NOMOVEX Self::VERT.0 | Self::NOMOVEY.0
+}
+
 const_bitflag! { CDDS: u32;
 	/// [`NMCUSTOMDRAW`](crate::NMCUSTOMDRAW) `dwDrawStage` (`u32`).
 	=>
@@ -218,7 +275,7 @@ const_bitflag! { CDIS: u32;
 	DROPHILITED 0x1000
 }
 
-const_ordinary! { CDRF: u32;
+const_bitflag! { CDRF: u32;
 	/// [`NM_CUSTOMDRAW`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-customdraw)
 	/// return value (`u32`).
 	=>
@@ -520,6 +577,30 @@ const_bitflag! { HHT: u32;
 	ONOVERFLOW 0x4000
 }
 
+const_bitflag! { HKCOMB: u16;
+	/// [`hkm::SetRules`](crate::msg::hkm::SetRules) invalid key combinations
+	/// (`u16`).
+	=>
+	NONE 0x0001
+	S 0x0002
+	C 0x0004
+	A 0x0008
+	SC 0x0010
+	SA 0x0020
+	CA 0x0040
+	SCA 0x0080
+}
+
+const_wm! { HKM;
+	/// Hot key control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-hot-key-control-reference-messages)
+	/// (`u32`).
+	=>
+	SETHOTKEY WM::USER.raw() + 1
+	GETHOTKEY WM::USER.raw() + 2
+	SETRULES WM::USER.raw() + 3
+}
+
 const_bitflag! { ICC: u32;
 	/// [`INITCOMMONCONTROLSEX`](crate::INITCOMMONCONTROLSEX) `icc` (`u32`).
 	=>
@@ -1602,6 +1683,46 @@ const_wm! { RB;
 	SETBANDWIDTH WM::USER.raw() + 44
 }
 
+const_bitflag! { RBBIM: u32;
+	/// [`REBARBANDINFO`](crate::REBARBANDINFO) `fMask` (`u32`).
+	=>
+	STYLE 0x0000_0001
+	COLORS 0x0000_0002
+	TEXT 0x0000_0004
+	IMAGE 0x0000_0008
+	CHILD 0x0000_0010
+	CHILDSIZE 0x0000_0020
+	SIZE 0x0000_0040
+	BACKGROUND 0x0000_0080
+	ID 0x0000_0100
+	IDEALSIZE 0x0000_0200
+	LPARAM 0x0000_0400
+	HEADERSIZE 0x0000_0800
+	CHEVRONLOCATION 0x0000_1000
+	CHEVRONSTATE 0x0000_2000
+}
+
+const_bitflag! { RBBS: u32;
+	/// Rebar control band
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-rebarbandinfow)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	BREAK 0x0000_0001
+	FIXEDSIZE 0x0000_0002
+	CHILDEDGE 0x0000_0004
+	HIDDEN 0x0000_0008
+	NOVERT 0x0000_0010
+	FIXEDBMP 0x0000_0020
+	VARIABLEHEIGHT 0x0000_0040
+	GRIPPERALWAYS 0x0000_0080
+	NOGRIPPER 0x0000_0100
+	USECHEVRON 0x0000_0200
+	HIDETITLE 0x0000_0400
+	TOPALIGN 0x0000_0800
+}
+
 const_nm! { RBN;
 	/// Rebar control `WM_NOTIFY`
 	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-notifications)
@@ -1622,6 +1743,14 @@ const_nm! { RBN;
 	AUTOBREAK RBN_FIRST - 22
 }
 
+const_bitflag! { RBNM: u32;
+	/// [`NMREBAR`](crate::NMREBAR) `dwMask` (`u32`).
+	=>
+	ID 0x0000_0001
+	STYLE 0x0000_0002
+	LPARAM 0x0000_0004
+}
+
 const_ws! { RBS: u32;
 	/// Rebar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/rebar-control-styles)
@@ -1728,6 +1857,15 @@ const_bitflag! { TBBF: u32;
 	LARGE 0x0001
 }
 
+const_ordinary! { TBDDRET: u32;
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// return value (`u32`).
+	=>
+	DEFAULT 0
+	NODEFAULT 1
+	TREATPRESSED 2
+}
+
 const_bitflag! { TBIF: u32;
 	/// [`TBBUTTONINFO`](crate::TBBUTTONINFO) `dwFlags` (`u32`).
 	=>
@@ -1936,6 +2074,25 @@ const_bitflag! { TBSTATE: u8;
 	MARKED 0x80
 }
 
+const_ws! { TBSTYLE: u32;
+	/// Toolbar control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-control-and-button-styles)
+	/// (`u32`).
+	///
+	/// For the button styles, see [`BTNS`](crate::co::BTNS).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOOLTIPS 0x0100
+	WRAPABLE 0x0200
+	ALTDRAG 0x0400
+	FLAT 0x0800
+	LIST 0x1000
+	CUSTOMERASE 0x2000
+	REGISTERDROP 0x4000
+	TRANSPARENT 0x8000
+}
+
 const_wsex! { TBSTYLE_EX;
 	/// Extended toolbar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-extended-styles)
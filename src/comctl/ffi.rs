@@ -4,6 +4,7 @@ extern_sys! { "comctl32";
 	CreatePropertySheetPageW(PCVOID) -> HANDLE
 	DefSubclassProc(HANDLE, u32, usize, isize) -> isize
 	DestroyPropertySheetPage(HANDLE) -> BOOL
+	GetWindowSubclass(HANDLE, PFUNC, usize, *mut usize) -> BOOL
 	ImageList_Add(HANDLE, HANDLE, HANDLE) -> i32
 	ImageList_AddMasked(HANDLE, HANDLE, u32) -> i32
 	ImageList_BeginDrag(HANDLE, i32, i32, i32) -> BOOL
@@ -145,6 +145,12 @@ pub trait comctl_Himagelist: Handle {
 	/// [`ImageList_Create`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-imagelist_create)
 	/// function.
 	///
+	/// The returned guard destroys the image list when it goes out of scope. If
+	/// the image list is given to a control – like the `set_image_list` methods
+	/// of the native controls in the `gui` module, which take the guard itself –
+	/// the control becomes the owner, and destroys the image list when the
+	/// control is destroyed.
+	///
 	/// # Examples
 	///
 	/// ```no_run
@@ -160,6 +166,33 @@ pub trait comctl_Himagelist: Handle {
 	/// // ImageList_Destroy() automatically called
 	/// # w::HrResult::Ok(())
 	/// ```
+	///
+	/// Adding icons and drawing them:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hicon_file: w::HICON; // initialized somewhere
+	/// let hicon_folder: w::HICON;
+	/// let hdc: w::HDC;
+	/// # let hicon_file = w::HICON::NULL;
+	/// # let hicon_folder = w::HICON::NULL;
+	/// # let hdc = w::HDC::NULL;
+	///
+	/// let himgl = w::HIMAGELIST::Create(
+	///     w::SIZE::new(16, 16),
+	///     co::ILC::COLOR32 | co::ILC::MASK,
+	///     2,
+	///     1,
+	/// )?;
+	///
+	/// let idx_file = himgl.AddIcon(&hicon_file)?; // icons are copied
+	/// let idx_folder = himgl.AddIcon(&hicon_folder)?;
+	///
+	/// himgl.Draw(idx_file, &hdc, w::POINT::new(10, 10), co::ILD::NORMAL)?;
+	/// himgl.Draw(idx_folder, &hdc, w::POINT::new(30, 10), co::ILD::TRANSPARENT)?;
+	/// # w::HrResult::Ok(())
+	/// ```
 	#[must_use]
 	fn Create(
 		image_sz: SIZE,
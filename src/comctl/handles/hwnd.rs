@@ -1,9 +1,12 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::rc::Rc;
+
 use crate::co;
-use crate::comctl::ffi;
+use crate::comctl::{ffi, proc};
 use crate::decl::*;
 use crate::kernel::privs::*;
+use crate::msg::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
 
@@ -18,6 +21,103 @@ impl comctl_Hwnd for HWND {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait comctl_Hwnd: user_Hwnd {
+	/// Removes a closure subclass previously installed with
+	/// [`HWND::set_window_subclass`](crate::prelude::comctl_Hwnd::set_window_subclass),
+	/// calling
+	/// [`RemoveWindowSubclass`](crate::prelude::comctl_Hwnd::RemoveWindowSubclass)
+	/// and freeing the closure.
+	///
+	/// Returns [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if no closure subclass with the given ID is installed.
+	fn remove_window_subclass(&self, subclass_id: usize) -> SysResult<()> {
+		let mut ref_data = usize::default();
+		if unsafe {
+			ffi::GetWindowSubclass(
+				self.ptr(),
+				proc::func_subclass_closure as _,
+				subclass_id,
+				&mut ref_data,
+			)
+		} == 0 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		self.RemoveWindowSubclass(proc::func_subclass_closure, subclass_id)?;
+		let _ = unsafe { Box::from_raw(ref_data as *mut proc::SubclassClosure) };
+		Ok(())
+	}
+
+	/// Calls
+	/// [`SetWindowSubclass`](crate::prelude::comctl_Hwnd::SetWindowSubclass)
+	/// to subclass the window with a closure, which is a safe way to intercept
+	/// the messages of controls which are not directly wrapped by the library,
+	/// like the edit inside a combo box.
+	///
+	/// If the closure returns `None`, the message is passed to
+	/// [`DefSubclassProc`](crate::prelude::comctl_Hwnd::DefSubclassProc),
+	/// otherwise the returned value is used as the message result.
+	///
+	/// The closure is stored as the subclass reference data, and it is
+	/// automatically freed upon
+	/// [`WM_NCDESTROY`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-ncdestroy),
+	/// or when
+	/// [`HWND::remove_window_subclass`](crate::prelude::comctl_Hwnd::remove_window_subclass)
+	/// is called. If a closure with the same ID is already installed, it is
+	/// replaced.
+	///
+	/// The closure may safely remove or replace its own subclass, or trigger
+	/// re-entrant messages: it's kept alive until the current call returns.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, msg};
+	///
+	/// let hedit: w::HWND; // initialized somewhere
+	/// # let hedit = w::HWND::NULL;
+	///
+	/// hedit.set_window_subclass(1, |_: w::HWND, p: msg::WndMsg| {
+	///     if p.msg_id == co::WM::CHAR && p.wparam == 'x' as _ {
+	///         Some(0) // swallow the x key
+	///     } else {
+	///         None // default processing
+	///     }
+	/// })?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn set_window_subclass<F>(&self, subclass_id: usize, func: F) -> SysResult<()>
+		where F: Fn(HWND, WndMsg) -> Option<isize> + 'static,
+	{
+		let mut prev_ref_data = usize::default();
+		let has_prev = unsafe {
+			ffi::GetWindowSubclass(
+				self.ptr(),
+				proc::func_subclass_closure as _,
+				subclass_id,
+				&mut prev_ref_data,
+			)
+		} != 0;
+
+		let boxed: Box<proc::SubclassClosure> = Box::new(Rc::new(func));
+		let ref_data = Box::into_raw(boxed);
+
+		if let Err(e) = unsafe {
+			self.SetWindowSubclass(
+				proc::func_subclass_closure,
+				subclass_id,
+				ref_data as _,
+			)
+		} {
+			let _ = unsafe { Box::from_raw(ref_data) }; // not installed, free it
+			return Err(e);
+		}
+
+		if has_prev { // previous closure was replaced, free it
+			let _ = unsafe { Box::from_raw(prev_ref_data as *mut proc::SubclassClosure) };
+		}
+		Ok(())
+	}
+
 	/// [`DefSubclassProc`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-defsubclassproc)
 	/// function.
 	///
@@ -0,0 +1,110 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`ACM_ISPLAYING`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-isplaying)
+/// message, which has no parameters.
+///
+/// Return type: `bool`.
+pub struct IsPlaying {}
+
+impl MsgSend for IsPlaying {
+	type RetType = bool;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::ISPLAYING.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`ACM_OPEN`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-open)
+/// message parameters.
+///
+/// If `avi` is `None`, the current AVI clip is closed. If `avi` is a resource
+/// ID, `hinstance` is the module which contains the resource; otherwise it's
+/// the path of an AVI file.
+///
+/// Return type: `SysResult<()>`.
+pub struct Open<'a> {
+	pub hinstance: Option<&'a HINSTANCE>,
+	pub avi: Option<IdStr>,
+}
+
+impl<'a> MsgSend for Open<'a> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		match &self.avi {
+			None => Ok(()), // closing always succeeds
+			Some(_) => zero_as_badargs(v).map(|_| ()),
+		}
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::OPEN.into(),
+			wparam: self.hinstance.map_or(0, |h| h.ptr() as _),
+			lparam: self.avi.as_ref().map_or(0, |avi| avi.as_ptr() as _),
+		}
+	}
+}
+
+/// [`ACM_PLAY`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-play)
+/// message parameters.
+///
+/// If `repeat` is `None`, the clip is played indefinitely. If `to` is `None`,
+/// the clip is played until its last frame.
+///
+/// Return type: `SysResult<()>`.
+pub struct Play {
+	pub repeat: Option<u32>,
+	pub from: u16,
+	pub to: Option<u16>,
+}
+
+impl MsgSend for Play {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::PLAY.into(),
+			wparam: self.repeat.unwrap_or(u32::MAX) as i32 as _, // -1 means indefinitely
+			lparam: MAKEDWORD(self.from, self.to.unwrap_or(u16::MAX)) as _, // -1 means last frame
+		}
+	}
+}
+
+/// [`ACM_STOP`](https://learn.microsoft.com/en-us/windows/win32/controls/acm-stop)
+/// message, which has no parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct Stop {}
+
+impl MsgSend for Stop {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::ACM::STOP.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
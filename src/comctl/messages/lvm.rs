@@ -1734,6 +1734,34 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> MsgSend for InsertGroup<'a, 'b, 'c, 'd, 'e,
 	}
 }
 
+/// [`LVM_INSERTGROUP`](https://learn.microsoft.com/en-us/windows/win32/controls/lvm-insertgroup)
+/// message parameters, with an explicit position.
+///
+/// Unlike [`InsertGroup`](crate::msg::lvm::InsertGroup), which inserts at the
+/// top, `None` appends the group at the end.
+///
+/// Return type: `SysResult<u32>`.
+pub struct InsertGroupAt<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+	pub index: Option<u32>,
+	pub group: &'h LVGROUP<'a, 'b, 'c, 'd, 'e, 'f, 'g>,
+}
+
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> MsgSend for InsertGroupAt<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h> {
+	type RetType = SysResult<u32>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_badargs(v).map(|v| v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LVM::INSERTGROUP.into(),
+			wparam: self.index.map_or(-1, |idx| idx as i32) as _,
+			lparam: self.group as *const _ as _,
+		}
+	}
+}
+
 /// [`LVM_INSERTGROUPSORTED`](https://learn.microsoft.com/en-us/windows/win32/controls/lvm-insertgroupsorted)
 /// message parameters.
 ///
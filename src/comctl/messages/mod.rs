@@ -1,11 +1,14 @@
+pub mod acm;
 pub mod bcm;
 pub mod cb;
 pub mod dtm;
 pub mod em;
 pub mod hdm;
+pub mod hkm;
 pub mod lvm;
 pub mod mcm;
 pub mod pbm;
+pub mod rb;
 pub mod sb;
 pub mod stm;
 pub mod tbm;
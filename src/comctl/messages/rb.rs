@@ -0,0 +1,265 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`RB_DELETEBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-deleteband)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct DeleteBand {
+	pub index: u32,
+}
+
+impl MsgSend for DeleteBand {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::DELETEBAND.into(),
+			wparam: self.index as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`RB_GETBANDCOUNT`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-getbandcount)
+/// message, which has no parameters.
+///
+/// Return type: `u32`.
+pub struct GetBandCount {}
+
+impl MsgSend for GetBandCount {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::GETBANDCOUNT.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`RB_GETBANDINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-getbandinfo)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct GetBandInfo<'a, 'b> {
+	pub index: u32,
+	pub info: &'a mut REBARBANDINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for GetBandInfo<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::GETBANDINFO.into(),
+			wparam: self.index as _,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`RB_GETBARHEIGHT`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-getbarheight)
+/// message, which has no parameters.
+///
+/// Return type: `u32`.
+pub struct GetBarHeight {}
+
+impl MsgSend for GetBarHeight {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::GETBARHEIGHT.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`RB_GETROWCOUNT`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-getrowcount)
+/// message, which has no parameters.
+///
+/// Return type: `u32`.
+pub struct GetRowCount {}
+
+impl MsgSend for GetRowCount {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::GETROWCOUNT.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`RB_IDTOINDEX`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-idtoindex)
+/// message parameters.
+///
+/// Return type: `Option<u32>`.
+pub struct IdToIndex {
+	pub band_id: u32,
+}
+
+impl MsgSend for IdToIndex {
+	type RetType = Option<u32>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_none(v).map(|v| v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::IDTOINDEX.into(),
+			wparam: self.band_id as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`RB_INSERTBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-insertband)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct InsertBand<'a, 'b> {
+	/// If `None`, the band is added at the last position.
+	pub index: Option<u32>,
+	pub info: &'a REBARBANDINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for InsertBand<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::INSERTBAND.into(),
+			wparam: self.index.map_or(-1, |idx| idx as i32) as _,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`RB_MAXIMIZEBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-maximizeband)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MaximizeBand {
+	pub index: u32,
+	pub use_ideal_width: bool,
+}
+
+impl MsgSend for MaximizeBand {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::MAXIMIZEBAND.into(),
+			wparam: self.index as _,
+			lparam: self.use_ideal_width as _,
+		}
+	}
+}
+
+/// [`RB_MINIMIZEBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-minimizeband)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MinimizeBand {
+	pub index: u32,
+}
+
+impl MsgSend for MinimizeBand {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::MINIMIZEBAND.into(),
+			wparam: self.index as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`RB_SETBANDINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-setbandinfo)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetBandInfo<'a, 'b> {
+	pub index: u32,
+	pub info: &'a REBARBANDINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for SetBandInfo<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::SETBANDINFO.into(),
+			wparam: self.index as _,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`RB_SHOWBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rb-showband)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct ShowBand {
+	pub index: u32,
+	pub show: bool,
+}
+
+impl MsgSend for ShowBand {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::RB::SHOWBAND.into(),
+			wparam: self.index as _,
+			lparam: self.show as _,
+		}
+	}
+}
@@ -1,5 +1,52 @@
+use std::rc::Rc;
+
 use crate::co;
+use crate::comctl::ffi;
 use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Closure stored, boxed, as the reference data of
+/// [`HWND::set_window_subclass`](crate::prelude::comctl_Hwnd::set_window_subclass).
+/// It's reference-counted so it can be kept alive during a call, even if the
+/// subclass is removed or replaced from within the closure itself.
+pub(in crate::comctl) type SubclassClosure =
+	Rc<dyn Fn(HWND, WndMsg) -> Option<isize>>;
+
+pub(in crate::comctl) extern "system" fn func_subclass_closure(
+	hwnd: HWND,
+	msg: co::WM,
+	wparam: usize,
+	lparam: isize,
+	subclass_id: usize,
+	ref_data: usize,
+) -> isize
+{
+	let wm_any = WndMsg::new(msg, wparam, lparam);
+	let ret = {
+		// Clone the Rc, so the closure outlives the call even if the stored box
+		// is freed meanwhile; re-entrant calls just take another clone.
+		let func = unsafe { &*(ref_data as *const SubclassClosure) }.clone();
+		func(unsafe { hwnd.raw_copy() }, wm_any)
+	};
+
+	if msg == co::WM::NCDESTROY { // window is gone, free the closure
+		let mut cur_ref_data = usize::default(); // may have been replaced during the call
+		if unsafe {
+			ffi::GetWindowSubclass(
+				hwnd.ptr(),
+				func_subclass_closure as _,
+				subclass_id,
+				&mut cur_ref_data,
+			)
+		} != 0 {
+			hwnd.RemoveWindowSubclass(func_subclass_closure, subclass_id).ok(); // ignore errors
+			let _ = unsafe { Box::from_raw(cur_ref_data as *mut SubclassClosure) };
+		}
+	}
+
+	ret.unwrap_or_else(|| unsafe { hwnd.DefSubclassProc(wm_any) })
+}
 
 pub(in crate::comctl) extern "system" fn func_task_dialog_callback(
 	hwnd: HWND,
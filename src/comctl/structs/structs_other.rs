@@ -1064,6 +1064,33 @@ pub struct NMMOUSE {
 	pub dwHitInfo: isize,
 }
 
+/// [`NMREBAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmrebar)
+/// struct.
+#[repr(C)]
+pub struct NMREBAR {
+	pub hdr: NMHDR,
+	pub dwMask: co::RBNM,
+	pub uBand: u32,
+	pub fStyle: co::RBBS,
+	pub wID: u32,
+	pub lParam: isize,
+}
+
+impl_default!(NMREBAR);
+
+/// [`NMREBARCHILDSIZE`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmrebarchildsize)
+/// struct.
+#[repr(C)]
+pub struct NMREBARCHILDSIZE {
+	pub hdr: NMHDR,
+	pub uBand: u32,
+	pub wID: u32,
+	pub rcChild: RECT,
+	pub rcBand: RECT,
+}
+
+impl_default!(NMREBARCHILDSIZE);
+
 /// [`NMTRBTHUMBPOSCHANGING`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtrbthumbposchanging)
 /// struct.
 #[repr(C)]
@@ -1093,6 +1120,26 @@ pub struct NMTCKEYDOWN {
 
 impl_default!(NMTCKEYDOWN);
 
+/// [`NMTOOLBAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtoolbarw)
+/// struct.
+#[repr(C)]
+pub struct NMTOOLBAR<'a> {
+	pub hdr: NMHDR,
+	pub iItem: i32,
+	pub tbButton: TBBUTTON<'a>,
+	cchText: i32,
+	pszText: *mut u16,
+	pub rcButton: RECT,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTOOLBAR, 'a);
+
+impl<'a> NMTOOLBAR<'a> {
+	pub_fn_string_ptrlen_get_set!('a, pszText, set_pszText, cchText);
+}
+
 /// [`NMTREEVIEW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtreevieww)
 /// struct.
 #[repr(C)]
@@ -1331,6 +1378,42 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> PROPSHEETPAGE<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
 	}
 }
 
+/// [`REBARBANDINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-rebarbandinfow)
+/// struct.
+#[repr(C)]
+pub struct REBARBANDINFO<'a> {
+	cbSize: u32,
+	pub fMask: co::RBBIM,
+	pub fStyle: co::RBBS,
+	pub clrFore: COLORREF,
+	pub clrBack: COLORREF,
+	lpText: *mut u16,
+	cch: u32,
+	pub iImage: i32,
+	pub hwndChild: HWND,
+	pub cxMinChild: u32,
+	pub cyMinChild: u32,
+	pub cx: u32,
+	pub hbmBack: HBITMAP,
+	pub wID: u32,
+	pub cyChild: u32,
+	pub cyMaxChild: u32,
+	pub cyIntegral: u32,
+	pub cxIdeal: u32,
+	pub lParam: isize,
+	pub cxHeader: u32,
+	pub rcChevronLocation: RECT,
+	pub uChevronState: u32,
+
+	_lpText: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(REBARBANDINFO, cbSize, 'a);
+
+impl<'a> REBARBANDINFO<'a> {
+	pub_fn_string_buf_get_set!('a, lpText, set_lpText, raw_lpText, cch);
+}
+
 /// [`TBADDBITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tbaddbitmap)
 /// struct.
 #[repr(C)]
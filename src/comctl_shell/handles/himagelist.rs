@@ -54,4 +54,50 @@ pub trait comctl_shell_Himagelist: comctl_Himagelist {
 		}
 		Ok(())
 	}
+
+	/// Calls [`SHGetFileInfo`](crate::SHGetFileInfo) with
+	/// [`SHGFI::SYSICONINDEX`](crate::co::SHGFI::SYSICONINDEX) to retrieve the
+	/// system image list, either with small (16x16) or large (32x32) icons.
+	///
+	/// The indexes of this image list are the `iIcon` values returned by
+	/// [`SHGetFileInfo`](crate::SHGetFileInfo), so a single image list can be
+	/// shared by all the items of a list view, without loading any icon.
+	///
+	/// The system image list is owned by the system, so it must not be
+	/// destroyed. When setting it to a list view, the control must have the
+	/// [`LVS::SHAREIMAGELISTS`](crate::co::LVS::SHAREIMAGELISTS) style.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, msg};
+	///
+	/// let hlist: w::HWND; // list view, with LVS_SHAREIMAGELISTS style
+	/// # let hlist = w::HWND::NULL;
+	///
+	/// let himgl = w::HIMAGELIST::system_image_list(true)?;
+	/// unsafe {
+	///     hlist.SendMessage(msg::lvm::SetImageList {
+	///         kind: co::LVSIL::SMALL,
+	///         himagelist: Some(himgl),
+	///     });
+	/// }
+	///
+	/// let (_, shfi) = w::SHGetFileInfo(
+	///     "C:\\Temp\\report.pdf",
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     co::SHGFI::SYSICONINDEX | co::SHGFI::SMALLICON,
+	/// )?;
+	/// println!("Item image index: {}", shfi.iIcon);
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn system_image_list(small_icons: bool) -> HrResult<HIMAGELIST> {
+		let (hil, _) = SHGetFileInfo(
+			"*",
+			co::FILE_ATTRIBUTE::NORMAL,
+			co::SHGFI::USEFILEATTRIBUTES | co::SHGFI::SYSICONINDEX |
+			if small_icons { co::SHGFI::SMALLICON } else { co::SHGFI::LARGEICON },
+		)?;
+		Ok(unsafe { HIMAGELIST::from_ptr(hil as _) })
+	}
 }
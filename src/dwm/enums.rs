@@ -0,0 +1,77 @@
+use crate::co;
+use crate::decl::*;
+
+/// Variant parameter for:
+///
+/// * [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute).
+///
+/// The enum values match those in [`co::DWMWA`](crate::co::DWMWA) constant
+/// type.
+#[derive(Clone, Copy)]
+pub enum DwmAttr {
+	AllowNcPaint(bool),
+	BorderColor(COLORREF),
+	CaptionColor(COLORREF),
+	Cloak(bool),
+	DisallowPeek(bool),
+	ExcludedFromPeek(bool),
+	ForceIconicRepresentation(bool),
+	FreezeRepresentation(bool),
+	HasIconicBitmap(bool),
+	NcRenderingPolicy(co::DWMNCRP),
+	NonClientRtlLayout(bool),
+	SystemBackdropType(co::DWMSBT),
+	TextColor(COLORREF),
+	TransitionsForceDisabled(bool),
+	UseImmersiveDarkMode(bool),
+	WindowCornerPreference(co::DWMWCP),
+}
+
+impl DwmAttr {
+	/// Returns the [`co::DWMWA`](crate::co::DWMWA) constant of the attribute.
+	#[must_use]
+	pub const fn attr(&self) -> co::DWMWA {
+		match self {
+			Self::AllowNcPaint(_) => co::DWMWA::ALLOW_NCPAINT,
+			Self::BorderColor(_) => co::DWMWA::BORDER_COLOR,
+			Self::CaptionColor(_) => co::DWMWA::CAPTION_COLOR,
+			Self::Cloak(_) => co::DWMWA::CLOAK,
+			Self::DisallowPeek(_) => co::DWMWA::DISALLOW_PEEK,
+			Self::ExcludedFromPeek(_) => co::DWMWA::EXCLUDED_FROM_PEEK,
+			Self::ForceIconicRepresentation(_) => co::DWMWA::FORCE_ICONIC_REPRESENTATION,
+			Self::FreezeRepresentation(_) => co::DWMWA::FREEZE_REPRESENTATION,
+			Self::HasIconicBitmap(_) => co::DWMWA::HAS_ICONIC_BITMAP,
+			Self::NcRenderingPolicy(_) => co::DWMWA::NCRENDERING_POLICY,
+			Self::NonClientRtlLayout(_) => co::DWMWA::NONCLIENT_RTL_LAYOUT,
+			Self::SystemBackdropType(_) => co::DWMWA::SYSTEMBACKDROP_TYPE,
+			Self::TextColor(_) => co::DWMWA::TEXT_COLOR,
+			Self::TransitionsForceDisabled(_) => co::DWMWA::TRANSITIONS_FORCEDISABLED,
+			Self::UseImmersiveDarkMode(_) => co::DWMWA::USE_IMMERSIVE_DARK_MODE,
+			Self::WindowCornerPreference(_) => co::DWMWA::WINDOW_CORNER_PREFERENCE,
+		}
+	}
+
+	/// Returns the attribute value as a 32-bit integer, which is the size of
+	/// all the supported attributes.
+	#[must_use]
+	pub const fn value(&self) -> u32 {
+		match self {
+			Self::AllowNcPaint(b)
+				| Self::Cloak(b)
+				| Self::DisallowPeek(b)
+				| Self::ExcludedFromPeek(b)
+				| Self::ForceIconicRepresentation(b)
+				| Self::FreezeRepresentation(b)
+				| Self::HasIconicBitmap(b)
+				| Self::NonClientRtlLayout(b)
+				| Self::TransitionsForceDisabled(b)
+				| Self::UseImmersiveDarkMode(b) => *b as _,
+			Self::BorderColor(c)
+				| Self::CaptionColor(c)
+				| Self::TextColor(c) => c.raw(),
+			Self::NcRenderingPolicy(p) => p.raw(),
+			Self::SystemBackdropType(t) => t.raw(),
+			Self::WindowCornerPreference(p) => p.raw(),
+		}
+	}
+}
@@ -3,6 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::dwm::ffi;
+use crate::kernel::ffi_types::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
 
@@ -17,6 +18,83 @@ impl dwm_Hwnd for HWND {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait dwm_Hwnd: uxtheme_Hwnd {
+	/// Sets the system-drawn backdrop material of the window, like Mica or
+	/// acrylic, using
+	/// [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute)
+	/// with [`DwmAttr::SystemBackdropType`](crate::DwmAttr::SystemBackdropType).
+	///
+	/// This is supported only on Windows 11 build 22621 and later; on earlier
+	/// versions, the call fails with
+	/// [`HRESULT::E_INVALIDARG`](crate::co::HRESULT::E_INVALIDARG), which can
+	/// be safely ignored.
+	///
+	/// # Examples
+	///
+	/// Applying Mica to the whole window, which requires the frame to be
+	/// extended into the client area:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.DwmExtendFrameIntoClientArea(&w::MARGINS {
+	///     cxLeftWidth: -1,
+	///     cxRightWidth: -1,
+	///     cyTopHeight: -1,
+	///     cyBottomHeight: -1,
+	/// })?;
+	///
+	/// match hwnd.set_backdrop(co::DWMSBT::MAINWINDOW) {
+	///     Err(co::HRESULT::E_INVALIDARG) => {}, // not supported, keep default
+	///     res => res?,
+	/// }
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn set_backdrop(&self, backdrop: co::DWMSBT) -> HrResult<()> {
+		self.DwmSetWindowAttribute(DwmAttr::SystemBackdropType(backdrop))
+	}
+
+	/// Turns the dark mode of the window title bar on or off, using
+	/// [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute)
+	/// with [`DwmAttr::UseImmersiveDarkMode`](crate::DwmAttr::UseImmersiveDarkMode).
+	///
+	/// Windows 10 builds prior to 20H1 use an undocumented attribute number,
+	/// which is tried if the documented one is rejected. On Windows versions
+	/// which don't support dark title bars at all, this method does nothing.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.set_dark_mode_titlebar(true)?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn set_dark_mode_titlebar(&self, dark: bool) -> HrResult<()> {
+		const DWMWA_USE_IMMERSIVE_DARK_MODE_BEFORE_20H1: u32 = 19;
+
+		let val: BOOL = dark as _;
+		[co::DWMWA::USE_IMMERSIVE_DARK_MODE.raw(), DWMWA_USE_IMMERSIVE_DARK_MODE_BEFORE_20H1]
+			.iter()
+			.map(|attr| ok_to_hrresult(
+				unsafe {
+					ffi::DwmSetWindowAttribute(
+						self.ptr(),
+						*attr,
+						&val as *const _ as _,
+						std::mem::size_of::<BOOL>() as _,
+					)
+				},
+			))
+			.find(|res| *res != Err(co::HRESULT::E_INVALIDARG))
+			.unwrap_or(Ok(())) // attribute not supported at all
+	}
+
 	/// [`DwmExtendFrameIntoClientArea`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmextendframeintoclientarea)
 	/// function.
 	fn DwmExtendFrameIntoClientArea(&self,
@@ -78,4 +156,36 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 			},
 		)
 	}
+
+	/// [`DwmSetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmsetwindowattribute)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Disabling the rounded corners of a window on Windows 11:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.DwmSetWindowAttribute(
+	///     w::DwmAttr::WindowCornerPreference(co::DWMWCP::DONOTROUND),
+	/// )?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn DwmSetWindowAttribute(&self, attr: DwmAttr) -> HrResult<()> {
+		let val = attr.value();
+		ok_to_hrresult(
+			unsafe {
+				ffi::DwmSetWindowAttribute(
+					self.ptr(),
+					attr.attr().raw(),
+					&val as *const _ as _,
+					std::mem::size_of::<u32>() as _,
+				)
+			},
+		)
+	}
 }
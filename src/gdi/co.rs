@@ -44,6 +44,39 @@ const_ordinary! { BSS: u32;
 	MONOPATTERN 9
 }
 
+const_bitflag! { CF_FONT: u32;
+	/// [`CHOOSEFONT`](crate::CHOOSEFONT) `Flags` (`u32`).
+	///
+	/// Originally has `CF` prefix.
+	=>
+	SCREENFONTS 0x0000_0001
+	PRINTERFONTS 0x0000_0002
+	BOTH 0x0000_0003
+	SHOWHELP 0x0000_0004
+	ENABLEHOOK 0x0000_0008
+	ENABLETEMPLATE 0x0000_0010
+	ENABLETEMPLATEHANDLE 0x0000_0020
+	INITTOLOGFONTSTRUCT 0x0000_0040
+	USESTYLE 0x0000_0080
+	EFFECTS 0x0000_0100
+	APPLY 0x0000_0200
+	SCRIPTSONLY 0x0000_0400
+	NOVECTORFONTS 0x0000_0800
+	NOSIMULATIONS 0x0000_1000
+	LIMITSIZE 0x0000_2000
+	FIXEDPITCHONLY 0x0000_4000
+	FORCEFONTEXIST 0x0001_0000
+	SCALABLEONLY 0x0002_0000
+	TTONLY 0x0004_0000
+	NOFACESEL 0x0008_0000
+	NOSTYLESEL 0x0010_0000
+	NOSIZESEL 0x0020_0000
+	SELECTSCRIPT 0x0040_0000
+	NOSCRIPTSEL 0x0080_0000
+	NOVERTFONTS 0x0100_0000
+	INACTIVEFONTS 0x0200_0000
+}
+
 const_ordinary! { CHARSET: u8;
 	/// [`HFONT::CreateFont`](crate::prelude::gdi_Hfont::CreateFont) `char_set`
 	/// and [`LOGFONT`](crate::LOGFONT) `lfCharset` (`u8`).
@@ -120,6 +153,15 @@ const_ordinary! { FF: u8;
 	DECORATIVE 5 << 4
 }
 
+const_bitflag! { FONTTYPE: u32;
+	/// [`HDC::EnumFontFamiliesEx`](crate::prelude::gdi_Hdc::EnumFontFamiliesEx)
+	/// callback `font_type` (`u32`).
+	=>
+	RASTER 0x0001
+	DEVICE 0x0002
+	TRUETYPE 0x0004
+}
+
 const_ordinary! { FW: u32;
 	/// [`HFONT::CreateFont`](crate::prelude::gdi_Hfont::CreateFont) `weight`
 	/// and [`LOGFONT`](crate::LOGFONT) `lfWeight` (`u32`).
@@ -281,6 +323,14 @@ impl PITCH {
 	}
 }
 
+const_ordinary! { POLYF: i32;
+	/// [`HDC::SetPolyFillMode`](crate::prelude::gdi_Hdc::SetPolyFillMode)
+	/// `mode` (`i32`).
+	=>
+	ALTERNATE 1
+	WINDING 2
+}
+
 const_ordinary! { PS: i32;
 	/// [`HPEN::CreatePen`](crate::prelude::gdi_Hpen::CreatePen) `style`
 	/// (`i32`).
@@ -1,5 +1,9 @@
 use crate::kernel::ffi_types::*;
 
+extern_sys! { "comdlg32";
+	ChooseFontW(PVOID) -> BOOL
+}
+
 extern_sys! { "gdi32";
 	AbortPath(HANDLE) -> BOOL
 	AngleArc(HANDLE, i32, i32, u32, f32, f32) -> BOOL
@@ -14,6 +18,7 @@ extern_sys! { "gdi32";
 	CreateBrushIndirect(PCVOID) -> HANDLE
 	CreateCompatibleBitmap(HANDLE, i32, i32) -> HANDLE
 	CreateCompatibleDC(HANDLE) -> HANDLE
+	CreateDIBSection(HANDLE, PCVOID, u32, *mut PVOID, HANDLE, u32) -> HANDLE
 	CreateFontIndirectW(PCVOID) -> HANDLE
 	CreateFontW(i32, i32, i32, i32, i32, u32, u32, u32, u32, u32, u32, u32, u32, PCSTR) -> HANDLE
 	CreateHalftonePalette(HANDLE) -> HANDLE
@@ -30,6 +35,7 @@ extern_sys! { "gdi32";
 	DeleteObject(HANDLE) -> BOOL
 	Ellipse(HANDLE, i32, i32, i32, i32) -> BOOL
 	EndPath(HANDLE) -> BOOL
+	EnumFontFamiliesExW(HANDLE, PCVOID, PFUNC, isize, u32) -> i32
 	FillPath(HANDLE) -> BOOL
 	FillRect(HANDLE, PCVOID, HANDLE) -> i32
 	FillRgn(HANDLE, HANDLE, HANDLE) -> BOOL
@@ -46,6 +52,7 @@ extern_sys! { "gdi32";
 	GetDeviceCaps(HANDLE, i32) -> i32
 	GetDIBits(HANDLE, HANDLE, u32, u32, PVOID, PVOID, u32) -> i32
 	GetObjectW(HANDLE, i32, PVOID) -> i32
+	GetPolyFillMode(HANDLE) -> i32
 	GetStockObject(i32) -> HANDLE
 	GetStretchBltMode(HANDLE) -> i32
 	GetSysColorBrush(i32) -> HANDLE
@@ -66,6 +73,7 @@ extern_sys! { "gdi32";
 	Pie(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
 	PolyBezier(HANDLE, PCVOID, u32) -> BOOL
 	PolyBezierTo(HANDLE, PCVOID, u32) -> BOOL
+	Polygon(HANDLE, PCVOID, i32) -> BOOL
 	Polyline(HANDLE, PCVOID, u32) -> BOOL
 	PolylineTo(HANDLE, PCVOID, u32) -> BOOL
 	PtInRegion(HANDLE, i32, i32) -> BOOL
@@ -88,6 +96,7 @@ extern_sys! { "gdi32";
 	SetDCPenColor(HANDLE, u32) -> u32
 	SetDIBits(HANDLE, HANDLE, u32, u32, PCVOID, PCVOID, u32) -> i32
 	SetGraphicsMode(HANDLE, i32) -> i32
+	SetPolyFillMode(HANDLE, i32) -> i32
 	SetStretchBltMode(HANDLE, i32) -> i32
 	SetTextAlign(HANDLE, u32) -> u32
 	SetTextColor(HANDLE, u32) -> u32
@@ -106,7 +115,7 @@ extern_sys! { "gdi32";
 }
 
 extern_sys! { "msimg32";
-	AlphaBlend(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, PCVOID) -> BOOL
+	AlphaBlend(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 	TransparentBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 }
 
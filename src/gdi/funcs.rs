@@ -1,9 +1,46 @@
 #![allow(non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::gdi::ffi;
 use crate::kernel::privs::*;
 
+/// [`ChooseFont`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-choosefontw)
+/// function.
+///
+/// Consider using the simpler
+/// [`HWND::choose_font`](crate::prelude::gdi_Hwnd::choose_font).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let parent_hwnd: w::HWND; // initialized somewhere
+/// # let parent_hwnd = w::HWND::NULL;
+///
+/// let mut lf = w::LOGFONT::default();
+/// let mut cf = w::CHOOSEFONT::default();
+///
+/// cf.hwndOwner = parent_hwnd;
+/// cf.Flags = co::CF_FONT::SCREENFONTS | co::CF_FONT::EFFECTS;
+/// cf.set_lpLogFont(Some(&mut lf));
+///
+/// if w::ChooseFont(&mut cf)? {
+///     println!("The font: {}", cf.lpLogFont().unwrap().lfFaceName());
+/// }
+/// # Ok::<_, co::CDERR>(())
+/// ```
+pub fn ChooseFont(cf: &mut CHOOSEFONT) -> Result<bool, co::CDERR> {
+	match unsafe { ffi::ChooseFontW(cf as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(false),
+			err => Err(err),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`GdiFlush`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gdiflush)
 /// function.
 pub fn GdiFlush() -> SysResult<()> {
@@ -33,3 +70,42 @@ pub fn GdiSetBatchLimit(limit: u32) -> SysResult<u32> {
 		n => Ok(n),
 	}
 }
+
+/// [`SystemParametersInfo`](crate::SystemParametersInfo) function with
+/// [`co::SPI::GETICONMETRICS`](crate::co::SPI::GETICONMETRICS) action, which
+/// retrieves the metrics associated with icons.
+pub fn SystemParametersInfo_get_iconmetrics() -> SysResult<ICONMETRICS> {
+	let mut im = ICONMETRICS::default();
+	let cb_size = std::mem::size_of::<ICONMETRICS>();
+	unsafe {
+		SystemParametersInfo(
+			co::SPI::GETICONMETRICS, cb_size as _, &mut im, co::SPIF::NoValue)
+	}.map(|_| im)
+}
+
+/// [`SystemParametersInfo`](crate::SystemParametersInfo) function with
+/// [`co::SPI::GETNONCLIENTMETRICS`](crate::co::SPI::GETNONCLIENTMETRICS)
+/// action, which retrieves the metrics associated with the nonclient area of
+/// windows, including the caption, menu, status bar and message box fonts.
+///
+/// The `cbSize` field is set according to the current Windows version.
+///
+/// # Examples
+///
+/// Creating a font which matches the message boxes:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let ncm = w::SystemParametersInfo_get_nonclientmetrics()?;
+/// let hfont = w::HFONT::CreateFontIndirect(&ncm.lfMessageFont)?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn SystemParametersInfo_get_nonclientmetrics() -> SysResult<NONCLIENTMETRICS> {
+	let mut ncm = NONCLIENTMETRICS::default();
+	let cb_size = ncm.cbSize; // smaller on Windows XP
+	unsafe {
+		SystemParametersInfo(
+			co::SPI::GETNONCLIENTMETRICS, cb_size, &mut ncm, co::SPIF::NoValue)
+	}.map(|_| ncm)
+}
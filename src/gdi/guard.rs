@@ -6,6 +6,75 @@ use crate::gdi::ffi;
 use crate::guard::*;
 use crate::prelude::*;
 
+/// RAII implementation for a memory [`HDC`](crate::HDC) created by
+/// [`HDC::create_back_buffer`](crate::prelude::gdi_Hdc::create_back_buffer),
+/// which automatically calls
+/// [`BitBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-bitblt)
+/// to copy its contents into the target `HDC`, then releases the memory `HDC`
+/// and its bitmap, when the object goes out of scope.
+pub struct BitBltGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	hdc_dest: &'a H,
+	hdc_mem: DeleteDCGuard,
+	_hbmp: DeleteObjectGuard<HBITMAP>, // deleted after hdc_mem
+	hbmp_prev: HBITMAP,
+	rc: RECT,
+}
+
+impl<'a, H> Drop for BitBltGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	fn drop(&mut self) {
+		unsafe {
+			ffi::BitBlt( // ignore errors
+				self.hdc_dest.ptr(),
+				self.rc.left, self.rc.top,
+				self.rc.right - self.rc.left, self.rc.bottom - self.rc.top,
+				self.hdc_mem.ptr(),
+				self.rc.left, self.rc.top, // logical coordinates, due to viewport origin
+				co::ROP::SRCCOPY.raw(),
+			);
+			ffi::SelectObject(self.hdc_mem.ptr(), self.hbmp_prev.ptr());
+		}
+	}
+}
+
+impl<'a, H> Deref for BitBltGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	type Target = HDC;
+
+	fn deref(&self) -> &Self::Target {
+		&self.hdc_mem
+	}
+}
+
+impl<'a, H> BitBltGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	#[must_use]
+	pub(in crate::gdi) fn new(hdc_dest: &'a H, rc: RECT) -> SysResult<Self> {
+		let hdc_dest_tmp = unsafe { HDC::from_ptr(hdc_dest.ptr()) };
+		let hdc_mem = hdc_dest_tmp.CreateCompatibleDC()?;
+		hdc_mem.SetViewportOrgEx(-rc.left, -rc.top)?; // so the target coordinates can be used
+		let hbmp = hdc_dest_tmp.CreateCompatibleBitmap(
+			rc.right - rc.left, rc.bottom - rc.top)?;
+		let hbmp_prev = unsafe {
+			HBITMAP::from_ptr(ffi::SelectObject(hdc_mem.ptr(), hbmp.ptr()))
+		};
+		Ok(Self { hdc_dest, hdc_mem, _hbmp: hbmp, hbmp_prev, rc })
+	}
+
+	/// Returns the rectangle, in the target `HDC`, covered by the buffer.
+	#[must_use]
+	pub const fn rect(&self) -> RECT {
+		self.rc
+	}
+}
+
+//------------------------------------------------------------------------------
+
 handle_guard! { DeleteDCGuard: HDC;
 	ffi::DeleteDC;
 	/// RAII implementation for [`HDC`](crate::HDC) which automatically calls
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::gdi::ffi;
 use crate::guard::*;
@@ -18,6 +19,100 @@ impl gdi_Hbitmap for HBITMAP {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hbitmap: Handle {
+	/// Retrieves the pixels of the bitmap as a top-down, tightly-packed RGBA
+	/// buffer, 4 bytes per pixel, by calling
+	/// [`HBITMAP::GetDIBits`](crate::prelude::gdi_Hbitmap::GetDIBits).
+	///
+	/// Returns the width and height of the bitmap, along with the buffer. If the
+	/// bitmap has no alpha channel – that is, all alpha values are zero – the
+	/// pixels are made opaque.
+	///
+	/// The bitmap must not be selected into a device context.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hbmp: w::HBITMAP; // initialized somewhere
+	/// # let hbmp = w::HBITMAP::NULL;
+	///
+	/// let hdc_screen = w::HWND::DESKTOP.GetDC()?;
+	/// let (sz, rgba) = hbmp.to_rgba_vec(&hdc_screen)?;
+	/// println!("{}x{}, {} bytes", sz.cx, sz.cy, rgba.len());
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn to_rgba_vec(&self, hdc: &HDC) -> SysResult<(SIZE, Vec<u8>)> {
+		let mut bmp = BITMAP::default();
+		self.GetObject(&mut bmp)?;
+
+		let mut bmi = BITMAPINFO::default();
+		bmi.bmiHeader.biWidth = bmp.bmWidth;
+		bmi.bmiHeader.biHeight = -bmp.bmHeight; // negative height means top-down
+		bmi.bmiHeader.biPlanes = 1;
+		bmi.bmiHeader.biBitCount = 32;
+		bmi.bmiHeader.biCompression = co::BI::RGB;
+
+		let mut buf = vec![0u8; bmp.bmWidth as usize * bmp.bmHeight as usize * 4];
+		self.GetDIBits(hdc, 0, bmp.bmHeight as _, &mut buf, &mut bmi, co::DIB::RGB_COLORS)?;
+
+		let has_alpha = buf.chunks_exact(4).any(|px| px[3] != 0);
+		for px in buf.chunks_exact_mut(4) {
+			px.swap(0, 2); // BGRA to RGBA
+			if !has_alpha {
+				px[3] = 0xff;
+			}
+		}
+
+		Ok((SIZE::new(bmp.bmWidth, bmp.bmHeight), buf))
+	}
+
+	/// Creates an icon from the bitmap, which is used as the color bitmap, by
+	/// calling
+	/// [`HICON::CreateIconIndirect`](crate::prelude::user_Hicon::CreateIconIndirect).
+	///
+	/// If `mask` is `None`, an all-black mask is used, so the whole icon is
+	/// opaque – unless the bitmap is 32-bit with an alpha channel, in which
+	/// case the alpha channel defines the transparency.
+	///
+	/// The bitmaps are copied, so they can be deleted right after the call.
+	///
+	/// # Examples
+	///
+	/// Creating an icon to be shown in the system tray:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hbmp: w::HBITMAP; // initialized somewhere
+	/// # let hbmp = w::HBITMAP::NULL;
+	///
+	/// let hicon = hbmp.to_icon(None)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn to_icon(&self, mask: Option<&HBITMAP>) -> SysResult<DestroyIconGuard> {
+		let mut bmp = BITMAP::default();
+		self.GetObject(&mut bmp)?;
+
+		let _mask_guard; // keeps the created mask alive until the icon is made
+		let hbm_mask = match mask {
+			Some(mask) => unsafe { mask.raw_copy() },
+			None => {
+				let stride = (bmp.bmWidth as usize).div_ceil(16) * 2; // rows are WORD-aligned
+				let mut bits = vec![0u8; stride * bmp.bmHeight as usize];
+				_mask_guard = HBITMAP::CreateBitmap(
+					SIZE::new(bmp.bmWidth, bmp.bmHeight), 1, 1, bits.as_mut_ptr())?;
+				unsafe { _mask_guard.raw_copy() }
+			},
+		};
+
+		let mut ii = ICONINFO::default();
+		ii.set_fIcon(true);
+		ii.hbmMask = hbm_mask;
+		ii.hbmColor = unsafe { HBITMAP::from_ptr(self.ptr()) };
+		HICON::CreateIconIndirect(&ii)
+	}
+
 	/// [`CreateBitmap`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createbitmap)
 	/// function.
 	#[must_use]
@@ -35,6 +130,58 @@ pub trait gdi_Hbitmap: Handle {
 		}
 	}
 
+	/// [`GetDIBits`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getdibits)
+	/// function.
+	///
+	/// This is a safe version of
+	/// [`HDC::GetDIBits`](crate::prelude::gdi_Hdc::GetDIBits): the header of
+	/// `bmi` must be filled, and the size of `bmp_data_buf` is validated against
+	/// it, failing with
+	/// [`co::ERROR::INSUFFICIENT_BUFFER`](crate::co::ERROR::INSUFFICIENT_BUFFER)
+	/// if too small. Since [`BITMAPINFO`](crate::BITMAPINFO) has room for a
+	/// single color entry, only 16, 24 and 32 bits per pixel with
+	/// [`co::BI::RGB`](crate::co::BI::RGB) are accepted, because they don't use
+	/// a color table; other formats fail with
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+	///
+	/// The bitmap must not be selected into a device context.
+	///
+	/// For a simpler way to retrieve the pixels, see
+	/// [`HBITMAP::to_rgba_vec`](crate::prelude::gdi_Hbitmap::to_rgba_vec).
+	fn GetDIBits(&self,
+		hdc: &HDC,
+		first_scan_line: u32,
+		num_scan_lines: u32,
+		bmp_data_buf: &mut [u8],
+		bmi: &mut BITMAPINFO,
+		usage: co::DIB,
+	) -> SysResult<i32>
+	{
+		let hdr = &bmi.bmiHeader;
+		if hdr.biCompression != co::BI::RGB
+			|| !matches!(hdr.biBitCount, 16 | 24 | 32)
+		{
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+
+		let stride = (hdr.biWidth.unsigned_abs() as usize * hdr.biBitCount as usize).div_ceil(32) * 4;
+		let num_lines = (num_scan_lines as usize).min(hdr.biHeight.unsigned_abs() as _);
+		if bmp_data_buf.len() < stride * num_lines {
+			return Err(co::ERROR::INSUFFICIENT_BUFFER);
+		}
+
+		unsafe {
+			hdc.GetDIBits(
+				&HBITMAP::from_ptr(self.ptr()),
+				first_scan_line,
+				num_lines as _,
+				Some(bmp_data_buf),
+				bmi,
+				usage,
+			)
+		}
+	}
+
 	/// [`GetObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getobjectw)
 	/// function.
 	///
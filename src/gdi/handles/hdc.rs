@@ -4,7 +4,7 @@ use std::any::TypeId;
 
 use crate::co;
 use crate::decl::*;
-use crate::gdi::{ffi, privs::*};
+use crate::gdi::{ffi, privs::*, proc};
 use crate::guard::*;
 use crate::kernel::privs::*;
 use crate::prelude::*;
@@ -20,6 +20,48 @@ impl gdi_Hdc for HDC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hdc: user_Hdc {
+	/// Creates a memory `HDC` compatible with this one, with a bitmap covering
+	/// the given rectangle. When the returned guard goes out of scope, the
+	/// bitmap is copied into this `HDC` with
+	/// [`HDC::BitBlt`](crate::prelude::gdi_Hdc::BitBlt), and the resources are
+	/// released.
+	///
+	/// Drawing to the memory `HDC` and copying it at once eliminates the
+	/// flicker of painting directly to the window. The memory `HDC` uses the
+	/// same coordinates of this `HDC`, so existing drawing code doesn't need to
+	/// change.
+	///
+	/// Note that the bitmap contents are initially undefined, so the whole
+	/// background must be painted.
+	///
+	/// # Examples
+	///
+	/// Painting a window without flicker:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let wnd2 = wnd.clone();
+	/// wnd.on().wm_paint(move || {
+	///     let hdc = wnd2.hwnd().BeginPaint()?;
+	///     let rc = hdc.paintstruct().rcPaint;
+	///     let hdc_mem = hdc.create_back_buffer(rc)?;
+	///
+	///     let hbr = w::HBRUSH::GetSysColorBrush(co::COLOR::WINDOW)?;
+	///     hdc_mem.FillRect(rc, &hbr)?;
+	///     hdc_mem.TextOut(10, 10, "No flicker")?;
+	///
+	///     Ok(()) // contents are copied when hdc_mem goes out of scope
+	/// });
+	/// ```
+	#[must_use]
+	fn create_back_buffer(&self, rc: RECT) -> SysResult<BitBltGuard<'_, Self>> {
+		BitBltGuard::new(self, rc)
+	}
+
 	/// [`AborthPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-abortpath)
 	/// function.
 	fn AbortPath(&self) -> SysResult<()> {
@@ -28,10 +70,31 @@ pub trait gdi_Hdc: user_Hdc {
 
 	/// [`AlphaBlend`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-alphablend)
 	/// function.
+	///
+	/// The source rectangle is stretched to fill the destination rectangle, if
+	/// their sizes differ.
+	///
+	/// # Examples
+	///
+	/// Blending a bitmap at 50% opacity:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdc_dest: w::HDC; // initialized somewhere
+	/// let hdc_src: w::HDC;
+	/// # let hdc_dest = w::HDC::NULL;
+	/// # let hdc_src = w::HDC::NULL;
+	///
+	/// let rc = w::RECT { left: 0, top: 0, right: 32, bottom: 32 };
+	/// hdc_dest.AlphaBlend(rc, &hdc_src, rc,
+	///     &w::BLENDFUNCTION::new(128, false))?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
 	fn AlphaBlend(&self,
-		origin_dest: RECT,
+		rc_dest: RECT,
 		hdc_src: &HDC,
-		origin_src: RECT,
+		rc_src: RECT,
 		ftn: &BLENDFUNCTION,
 	) -> SysResult<()>
 	{
@@ -39,12 +102,12 @@ pub trait gdi_Hdc: user_Hdc {
 			unsafe {
 				ffi::AlphaBlend(
 					self.ptr(),
-					origin_dest.left, origin_dest.top,
-					origin_dest.right, origin_dest.bottom,
+					rc_dest.left, rc_dest.top,
+					rc_dest.right - rc_dest.left, rc_dest.bottom - rc_dest.top,
 					hdc_src.ptr(),
-					origin_src.left, origin_src.top,
-					origin_src.right, origin_src.bottom,
-					ftn as *const _ as _,
+					rc_src.left, rc_src.top,
+					rc_src.right - rc_src.left, rc_src.bottom - rc_src.top,
+					std::mem::transmute::<_, u32>(*ftn), // struct is passed by value
 				)
 			},
 		)
@@ -199,6 +262,32 @@ pub trait gdi_Hdc: user_Hdc {
 		}
 	}
 
+	/// [`CreateDIBSection`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createdibsection)
+	/// function.
+	///
+	/// Returns the bitmap and a pointer to its bits, which remains valid until
+	/// the bitmap is deleted. Call [`GdiFlush`](crate::GdiFlush) before reading
+	/// the bits after any GDI drawing.
+	fn CreateDIBSection(&self,
+		bmi: &BITMAPINFO,
+		usage: co::DIB,
+	) -> SysResult<(DeleteObjectGuard<HBITMAP>, *mut u8)>
+	{
+		let mut pbits = std::ptr::null_mut();
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateDIBSection(
+					self.ptr(),
+					bmi as *const _ as _,
+					usage.raw(),
+					&mut pbits,
+					std::ptr::null_mut(),
+					0,
+				),
+			).map(|h| (DeleteObjectGuard::new(h), pbits as _))
+		}
+	}
+
 	/// [`CreateHalftonePalette`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createhalftonepalette)
 	/// function.
 	#[must_use]
@@ -229,6 +318,48 @@ pub trait gdi_Hdc: user_Hdc {
 		bool_to_sysresult(unsafe { ffi::EndPath(self.ptr()) })
 	}
 
+	/// [`EnumFontFamiliesEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enumfontfamiliesexw)
+	/// function.
+	///
+	/// The fonts are filtered by the `lfCharSet`, `lfFaceName` and
+	/// `lfPitchAndFamily` fields of `lf`. The enumeration stops when the
+	/// closure returns `false`.
+	///
+	/// # Examples
+	///
+	/// Listing the names of all installed fonts:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hdc: w::HDC; // initialized somewhere
+	/// # let hdc = w::HDC::NULL;
+	///
+	/// let mut lf = w::LOGFONT::default();
+	/// lf.lfCharSet = co::CHARSET::DEFAULT;
+	///
+	/// hdc.EnumFontFamiliesEx(
+	///     &lf,
+	///     |lf: &w::LOGFONT, tm: &w::TEXTMETRIC, font_type: co::FONTTYPE| -> bool {
+	///         println!("{} {}", lf.lfFaceName(), tm.tmHeight);
+	///         true
+	///     },
+	/// );
+	/// ```
+	fn EnumFontFamiliesEx<F>(&self, lf: &LOGFONT, func: F)
+		where F: FnMut(&LOGFONT, &TEXTMETRIC, co::FONTTYPE) -> bool,
+	{
+		unsafe {
+			ffi::EnumFontFamiliesExW(
+				self.ptr(),
+				lf as *const _ as _,
+				proc::hdc_enum_font_families_ex::<F> as _,
+				&func as *const _ as _,
+				0,
+			);
+		}
+	}
+
 	/// [`FillPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-fillpath)
 	/// function.
 	fn FillPath(&self) -> SysResult<()> {
@@ -441,6 +572,16 @@ pub trait gdi_Hdc: user_Hdc {
 		unsafe { ffi::GetDeviceCaps(self.ptr(), index.raw()) }
 	}
 
+	/// [`GetPolyFillMode`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getpolyfillmode)
+	/// function.
+	#[must_use]
+	fn GetPolyFillMode(&self) -> SysResult<co::POLYF> {
+		match unsafe { ffi::GetPolyFillMode(self.ptr()) } {
+			0 => Err(GetLastError()),
+			v => Ok(unsafe { co::POLYF::from_raw(v) }),
+		}
+	}
+
 	/// [`GetStretchBltMode`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getstretchbltmode)
 	/// function.
 	#[must_use]
@@ -497,6 +638,23 @@ pub trait gdi_Hdc: user_Hdc {
 
 	/// [`GetTextMetrics`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gettextmetricsw)
 	/// function.
+	///
+	/// # Examples
+	///
+	/// Drawing text with its baseline at a given vertical position, using the
+	/// metrics of the currently selected font:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdc: w::HDC; // initialized somewhere
+	/// # let hdc = w::HDC::NULL;
+	///
+	/// let baseline_y = 100;
+	/// let tm = hdc.GetTextMetrics()?;
+	/// hdc.TextOut(10, baseline_y - tm.tmAscent, "Hello")?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn GetTextMetrics(&self) -> SysResult<TEXTMETRIC> {
 		let mut tm = TEXTMETRIC::default();
 		bool_to_sysresult(
@@ -636,7 +794,16 @@ pub trait gdi_Hdc: user_Hdc {
 
 	/// [`PolyBezier`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-polybezier)
 	/// function.
+	///
+	/// The number of points must be one more than a multiple of 3: a starting
+	/// point, followed by two control points and an ending point for each
+	/// curve. Otherwise
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) is
+	/// returned.
 	fn PolyBezier(&self, pts: &[POINT]) -> SysResult<()> {
+		if pts.len() % 3 != 1 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
 		bool_to_sysresult(
 			unsafe {
 				ffi::PolyBezier(self.ptr(), vec_ptr(pts) as _, pts.len() as _)
@@ -646,7 +813,17 @@ pub trait gdi_Hdc: user_Hdc {
 
 	/// [`PolyBezierTo`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-polybezierto)
 	/// function.
+	///
+	/// The number of points must be a multiple of 3: two control points and
+	/// an ending point for each curve, starting at the current position.
+	/// Otherwise
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) is
+	/// returned.
+	#[allow(clippy::manual_is_multiple_of)] // is_multiple_of() requires Rust 1.87
 	fn PolyBezierTo(&self, pts: &[POINT]) -> SysResult<()> {
+		if pts.is_empty() || pts.len() % 3 != 0 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
 		bool_to_sysresult(
 			unsafe {
 				ffi::PolyBezierTo(self.ptr(), vec_ptr(pts) as _, pts.len() as _)
@@ -654,6 +831,43 @@ pub trait gdi_Hdc: user_Hdc {
 		)
 	}
 
+	/// [`Polygon`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-polygon)
+	/// function.
+	///
+	/// The polygon is closed automatically, outlined with the current pen and
+	/// filled with the current brush, according to the current
+	/// [polygon fill mode](crate::prelude::gdi_Hdc::SetPolyFillMode).
+	///
+	/// # Examples
+	///
+	/// Drawing a triangle as a path:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hdc: w::HDC; // initialized somewhere
+	/// # let hdc = w::HDC::NULL;
+	///
+	/// hdc.BeginPath()?;
+	/// hdc.Polygon(&[
+	///     w::POINT::new(10, 50),
+	///     w::POINT::new(30, 10),
+	///     w::POINT::new(50, 50),
+	/// ])?;
+	/// hdc.EndPath()?;
+	///
+	/// hdc.SetPolyFillMode(co::POLYF::WINDING)?;
+	/// hdc.StrokeAndFillPath()?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn Polygon(&self, pts: &[POINT]) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::Polygon(self.ptr(), vec_ptr(pts) as _, pts.len() as _)
+			},
+		)
+	}
+
 	/// [`Polyline`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-polyline)
 	/// function.
 	fn Polyline(&self, pts: &[POINT]) -> SysResult<()> {
@@ -925,6 +1139,17 @@ pub trait gdi_Hdc: user_Hdc {
 		}
 	}
 
+	/// [`SetPolyFillMode`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setpolyfillmode)
+	/// function.
+	///
+	/// Returns the previous mode.
+	fn SetPolyFillMode(&self, mode: co::POLYF) -> SysResult<co::POLYF> {
+		match unsafe { ffi::SetPolyFillMode(self.ptr(), mode.raw()) } {
+			0 => Err(GetLastError()),
+			v => Ok(unsafe { co::POLYF::from_raw(v) }),
+		}
+	}
+
 	/// [`SetStretchBltMode`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setstretchbltmode)
 	/// function.
 	fn SetStretchBltMode(&self,
@@ -1067,6 +1292,32 @@ pub trait gdi_Hdc: user_Hdc {
 
 	/// [`TransparentBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-transparentblt)
 	/// function.
+	///
+	/// Pixels of the source with `color_transparent` are not copied. The source
+	/// area is stretched to fill the destination area, if their sizes differ.
+	///
+	/// # Examples
+	///
+	/// Drawing a 16x16 bitmap with a magenta color key:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdc_dest: w::HDC; // initialized somewhere
+	/// let hdc_src: w::HDC;
+	/// # let hdc_dest = w::HDC::NULL;
+	/// # let hdc_src = w::HDC::NULL;
+	///
+	/// hdc_dest.TransparentBlt(
+	///     w::POINT::new(10, 10),
+	///     w::SIZE::new(16, 16),
+	///     hdc_src,
+	///     w::POINT::new(0, 0),
+	///     w::SIZE::new(16, 16),
+	///     w::COLORREF::new(0xff, 0x00, 0xff),
+	/// )?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
 	fn TransparentBlt(&self,
 		dest_top_left: POINT,
 		dest_sz: SIZE,
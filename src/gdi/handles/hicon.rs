@@ -0,0 +1,84 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+impl gdi_Hicon for HICON {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HICON`](crate::HICON).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hicon: user_Hicon {
+	/// Draws the icon onto a new 32-bit top-down DIB section, created with
+	/// [`HDC::CreateDIBSection`](crate::prelude::gdi_Hdc::CreateDIBSection),
+	/// by calling [`HDC::DrawIconEx`](crate::prelude::user_Hdc::DrawIconEx).
+	///
+	/// The alpha channel of the icon is preserved, and the pixels are
+	/// premultiplied, so the bitmap is ready to be used with
+	/// [`AlphaBlend`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-alphablend)
+	/// or added to an image list. If the icon has no alpha channel, its mask is
+	/// used to make the transparent pixels fully transparent, and the others
+	/// fully opaque.
+	///
+	/// Returns [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if `sz` is not positive in both dimensions.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hicon = w::HINSTANCE::NULL.LoadIcon(w::IdIdiStr::Idi(co::IDI::APPLICATION))?;
+	/// let hbmp = hicon.to_bitmap(w::SIZE::new(32, 32))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn to_bitmap(&self, sz: SIZE) -> SysResult<DeleteObjectGuard<HBITMAP>> {
+		if sz.cx <= 0 || sz.cy <= 0 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let buf_sz = (sz.cx as usize).checked_mul(sz.cy as usize)
+			.and_then(|n| n.checked_mul(4)) // 32-bit pixels
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+
+		let hicon = unsafe { HICON::from_ptr(self.ptr()) };
+		let hdc_screen = HWND::DESKTOP.GetDC()?;
+		let hdc_mem = hdc_screen.CreateCompatibleDC()?;
+
+		let mut bmi = BITMAPINFO::default();
+		bmi.bmiHeader.biWidth = sz.cx;
+		bmi.bmiHeader.biHeight = -sz.cy; // negative height means top-down
+		bmi.bmiHeader.biPlanes = 1;
+		bmi.bmiHeader.biBitCount = 32;
+		bmi.bmiHeader.biCompression = co::BI::RGB;
+
+		let draw = |flags: co::DI| -> SysResult<(DeleteObjectGuard<HBITMAP>, &mut [u8])> {
+			let (hbmp, pbits) = hdc_mem.CreateDIBSection(&bmi, co::DIB::RGB_COLORS)?;
+			{
+				let _prev = hdc_mem.SelectObject(&*hbmp)?;
+				hdc_mem.DrawIconEx(POINT::default(), &hicon, sz, 0, None, flags)?;
+			}
+			GdiFlush()?; // make sure the bits were written
+			Ok((hbmp, unsafe { std::slice::from_raw_parts_mut(pbits, buf_sz) }))
+		};
+
+		let (hbmp, pixels) = draw(co::DI::NORMAL)?;
+		if pixels.chunks_exact(4).all(|px| px[3] == 0) { // no alpha channel
+			let (_hbmp_mask, mask) = draw(co::DI::MASK)?;
+			for (px, m) in pixels.chunks_exact_mut(4).zip(mask.chunks_exact(4)) {
+				if m[0] == 0 {
+					px[3] = 0xff; // black in the mask means opaque
+				} else {
+					px.fill(0);
+				}
+			}
+		}
+		Ok(hbmp)
+	}
+}
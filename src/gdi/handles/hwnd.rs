@@ -0,0 +1,69 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+impl gdi_Hwnd for HWND {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HWND`](crate::HWND).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hwnd: user_Hwnd {
+	/// Shows the font picker dialog, owned by this window, through
+	/// [`ChooseFont`](crate::ChooseFont).
+	///
+	/// If `initial` is given, the
+	/// [`co::CF_FONT::INITTOLOGFONTSTRUCT`](crate::co::CF_FONT::INITTOLOGFONTSTRUCT)
+	/// flag is added, and the dialog starts with this font selected. If `flags`
+	/// contains [`co::CF_FONT::EFFECTS`](crate::co::CF_FONT::EFFECTS), the user
+	/// can also choose the color, underline and strikeout.
+	///
+	/// Returns the chosen font and color, or `None` if the user cancelled the
+	/// dialog.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// if let Some((lf, color)) = hwnd.choose_font(
+	///     None,
+	///     co::CF_FONT::SCREENFONTS | co::CF_FONT::EFFECTS,
+	/// )? {
+	///     let hfont = w::HFONT::CreateFontIndirect(&lf).unwrap();
+	///     println!("{} {}", lf.lfFaceName(), color);
+	/// }
+	/// # Ok::<_, co::CDERR>(())
+	/// ```
+	fn choose_font(&self,
+		initial: Option<&LOGFONT>,
+		flags: co::CF_FONT,
+	) -> Result<Option<(LOGFONT, COLORREF)>, co::CDERR>
+	{
+		let mut lf = initial.cloned().unwrap_or_default();
+		let mut cf = CHOOSEFONT::default();
+		cf.hwndOwner = unsafe { HWND::from_ptr(self.ptr()) };
+		cf.Flags = if initial.is_some() {
+			flags | co::CF_FONT::INITTOLOGFONTSTRUCT
+		} else {
+			flags
+		};
+		cf.set_lpLogFont(Some(&mut lf));
+
+		if ChooseFont(&mut cf)? {
+			let color = cf.rgbColors;
+			Ok(Some((lf, color)))
+		} else {
+			Ok(None)
+		}
+	}
+}
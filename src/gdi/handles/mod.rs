@@ -3,10 +3,12 @@ mod hbitmap;
 mod hbrush;
 mod hdc;
 mod hfont;
+mod hicon;
 mod hinstance;
 mod hpalette;
 mod hpen;
 mod hrgn;
+mod hwnd;
 
 pub mod decl {
 	pub use super::hfont::HFONT;
@@ -19,8 +21,10 @@ pub mod traits {
 	pub use super::hbrush::gdi_Hbrush;
 	pub use super::hdc::gdi_Hdc;
 	pub use super::hfont::gdi_Hfont;
+	pub use super::hicon::gdi_Hicon;
 	pub use super::hinstance::gdi_Hinstance;
 	pub use super::hpalette::gdi_Hpalette;
 	pub use super::hpen::gdi_Hpen;
 	pub use super::hrgn::gdi_Hrgn;
+	pub use super::hwnd::gdi_Hwnd;
 }
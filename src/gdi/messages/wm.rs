@@ -4,6 +4,50 @@ use crate::msg::*;
 use crate::prelude::*;
 use crate::user::privs::*;
 
+/// Parameters of any of the
+/// [`WM_CTLCOLORBTN`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorbtn),
+/// [`WM_CTLCOLORDLG`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/wm-ctlcolordlg),
+/// [`WM_CTLCOLOREDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcoloredit),
+/// [`WM_CTLCOLORLISTBOX`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorlistbox),
+/// [`WM_CTLCOLORSCROLLBAR`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorscrollbar)
+/// or
+/// [`WM_CTLCOLORSTATIC`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorstatic)
+/// messages, which share the same layout. The actual message is identified by
+/// the `msg_id` field.
+///
+/// Return type: `HBRUSH`.
+pub struct CtlColor {
+	pub msg_id: co::WM,
+	pub hdc: HDC,
+	pub hwnd: HWND,
+}
+
+impl MsgSend for CtlColor {
+	type RetType = HBRUSH;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		HBRUSH::from_ptr(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: self.msg_id,
+			wparam: self.hdc.ptr() as _,
+			lparam: self.hwnd.ptr() as _,
+		}
+	}
+}
+
+impl MsgSendRecv for CtlColor {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			msg_id: p.msg_id,
+			hdc: HDC::from_ptr(p.wparam as _),
+			hwnd: HWND::from_ptr(p.lparam as _),
+		}
+	}
+}
+
 pub_struct_msg_ctlcolor! { CtlColorBtn: co::WM::CTLCOLORBTN;
 	/// [`WM_CTLCOLORBTN`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorbtn)
 }
@@ -3,6 +3,7 @@
 mod enums;
 mod funcs;
 mod handles;
+mod proc;
 mod structs;
 
 pub(in crate::gdi) mod ffi;
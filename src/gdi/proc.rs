@@ -0,0 +1,19 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+
+pub(in crate::gdi) extern "system" fn hdc_enum_font_families_ex<F>(
+	lf: *const LOGFONT,
+	tm: *const TEXTMETRIC,
+	font_type: u32,
+	lparam: isize,
+) -> BOOL
+	where F: FnMut(&LOGFONT, &TEXTMETRIC, co::FONTTYPE) -> bool,
+{
+	let func = unsafe { &mut *(lparam as *mut F) };
+	func(
+		unsafe { &*lf },
+		unsafe { &*tm },
+		unsafe { co::FONTTYPE::from_raw(font_type) },
+	) as _
+}
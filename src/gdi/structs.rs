@@ -1,5 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::marker::PhantomData;
+
 use crate::co;
 use crate::decl::*;
 use crate::gdi::privs::*;
@@ -104,6 +106,56 @@ impl BITMAPINFOHEADER {
 	}
 }
 
+/// [`CHOOSEFONT`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosefontw)
+/// struct.
+#[repr(C)]
+pub struct CHOOSEFONT<'a, 'b> {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hDC: HDC,
+	lpLogFont: *mut LOGFONT,
+	pub iPointSize: i32,
+	pub Flags: co::CF_FONT,
+	pub rgbColors: COLORREF,
+	pub lCustData: isize,
+	pub lpfnHook: Option<CFHOOKPROC>,
+	lpTemplateName: *mut u16, // u16 resource ID
+	pub hInstance: HINSTANCE,
+	lpszStyle: *mut u16,
+	pub nFontType: u16,
+	___MISSING_ALIGNMENT__: u16,
+	pub nSizeMin: i32,
+	pub nSizeMax: i32,
+
+	_lpLogFont: PhantomData<&'a mut LOGFONT>,
+	_lpszStyle: PhantomData<&'b mut u16>,
+}
+
+impl_default_with_size!(CHOOSEFONT, lStructSize, 'a, 'b);
+
+impl<'a, 'b> CHOOSEFONT<'a, 'b> {
+	pub_fn_ptr_get_set!('a, lpLogFont, set_lpLogFont, LOGFONT);
+	pub_fn_resource_id_get_set!(lpTemplateName, set_lpTemplateName);
+	pub_fn_string_ptr_get_set!('b, lpszStyle, set_lpszStyle);
+}
+
+/// [`ICONMETRICS`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-iconmetricsw)
+/// struct.
+#[repr(C)]
+pub struct ICONMETRICS {
+	cbSize: u32,
+	pub iHorzSpacing: i32,
+	pub iVertSpacing: i32,
+	iTitleWrap: i32,
+	pub lfFont: LOGFONT,
+}
+
+impl_default_with_size!(ICONMETRICS, cbSize);
+
+impl ICONMETRICS {
+	pub_fn_bool_get_set!(iTitleWrap, set_iTitleWrap);
+}
+
 /// [`LOGBRUSH`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-logbrush)
 /// struct.
 #[repr(C)]
@@ -230,7 +282,7 @@ impl_default!(LOGPEN);
 /// struct.
 #[repr(C)]
 pub struct NONCLIENTMETRICS {
-	cbSize: u32,
+	pub(in crate::gdi) cbSize: u32,
 	pub iBorderWidth: i32,
 	pub iScrollWidth: i32,
 	pub iScrollHeight: i32,
@@ -0,0 +1,29 @@
+/// Image format to be saved by
+/// [`GpBitmap::save`](crate::prelude::gdiplus_GpBitmap::save).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+	/// BMP format.
+	Bmp,
+	/// GIF format.
+	Gif,
+	/// JPEG format, with the given quality, from 0 to 100.
+	Jpeg(u8),
+	/// PNG format.
+	Png,
+	/// TIFF format.
+	Tiff,
+}
+
+impl ImageFormat {
+	/// Returns the MIME type of the format, used to find the encoder.
+	#[must_use]
+	pub(in crate::gdiplus) const fn mime_type(&self) -> &'static str {
+		match self {
+			Self::Bmp => "image/bmp",
+			Self::Gif => "image/gif",
+			Self::Jpeg(_) => "image/jpeg",
+			Self::Png => "image/png",
+			Self::Tiff => "image/tiff",
+		}
+	}
+}
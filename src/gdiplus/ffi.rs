@@ -0,0 +1,11 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "gdiplus";
+	GdipCreateBitmapFromHBITMAP(HANDLE, HANDLE, *mut HANDLE) -> u32
+	GdipDisposeImage(HANDLE) -> u32
+	GdipGetImageEncoders(u32, u32, PVOID) -> u32
+	GdipGetImageEncodersSize(*mut u32, *mut u32) -> u32
+	GdiplusShutdown(usize)
+	GdiplusStartup(*mut usize, PCVOID, PVOID) -> u32
+	GdipSaveImageToFile(HANDLE, PCSTR, PCVOID, PCVOID) -> u32
+}
@@ -0,0 +1,45 @@
+#![allow(non_snake_case)]
+
+use crate::decl::*;
+use crate::gdiplus::{ffi, privs::*};
+use crate::guard::*;
+
+/// `GdiplusStartupInput` struct, requesting GDI+ version 1.
+#[repr(C)]
+struct GdiplusStartupInput {
+	version: u32,
+	debug_event_callback: *const std::ffi::c_void,
+	suppress_background_thread: i32,
+	suppress_external_codecs: i32,
+}
+
+/// [`GdiplusStartup`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusinit/nf-gdiplusinit-gdiplusstartup)
+/// function.
+///
+/// GDI+ must be initialized before any other GDI+ call, and the returned guard
+/// must be kept alive until all GDI+ objects are disposed of.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let _gdiplus = w::GdiplusStartup()?;
+///
+/// // GDI+ calls...
+/// # w::SysResult::Ok(())
+/// ```
+pub fn GdiplusStartup() -> SysResult<GdiplusShutdownGuard> {
+	let input = GdiplusStartupInput {
+		version: 1,
+		debug_event_callback: std::ptr::null(),
+		suppress_background_thread: 0,
+		suppress_external_codecs: 0,
+	};
+	let mut token = usize::default();
+	unsafe {
+		status_to_sysresult(
+			ffi::GdiplusStartup(&mut token, &input as *const _ as _, std::ptr::null_mut()),
+		).map(|_| GdiplusShutdownGuard::new(token))
+	}
+}
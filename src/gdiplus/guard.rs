@@ -0,0 +1,47 @@
+use crate::decl::*;
+use crate::gdiplus::ffi;
+use crate::prelude::*;
+
+handle_guard! { GdipDisposeImageGuard: GpBitmap;
+	ffi::GdipDisposeImage;
+	/// RAII implementation for [`GpBitmap`](crate::GpBitmap) which
+	/// automatically calls
+	/// [`GdipDisposeImage`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// when the object goes out of scope.
+}
+
+/// RAII implementation which automatically calls
+/// [`GdiplusShutdown`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusinit/nf-gdiplusinit-gdiplusshutdown)
+/// when the object goes out of scope.
+///
+/// Returned by [`GdiplusStartup`](crate::GdiplusStartup).
+pub struct GdiplusShutdownGuard {
+	token: usize,
+}
+
+impl Drop for GdiplusShutdownGuard {
+	fn drop(&mut self) {
+		unsafe { ffi::GdiplusShutdown(self.token); }
+	}
+}
+
+impl GdiplusShutdownGuard {
+	/// Constructs the guard by taking ownership of the token.
+	///
+	/// # Safety
+	///
+	/// Be sure you need to call
+	/// [`GdiplusShutdown`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusinit/nf-gdiplusinit-gdiplusshutdown)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(token: usize) -> Self {
+		Self { token }
+	}
+
+	/// Returns the token returned by
+	/// [`GdiplusStartup`](crate::GdiplusStartup).
+	#[must_use]
+	pub const fn token(&self) -> usize {
+		self.token
+	}
+}
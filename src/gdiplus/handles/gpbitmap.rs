@@ -0,0 +1,123 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::gdiplus::{ffi, privs::*};
+use crate::guard::*;
+use crate::prelude::*;
+
+impl_handle! { GpBitmap;
+	/// Handle to a GDI+
+	/// [bitmap](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusheaders/nl-gdiplusheaders-bitmap)
+	/// object.
+	///
+	/// GDI+ must be initialized with [`GdiplusStartup`](crate::GdiplusStartup)
+	/// before any bitmap is created.
+}
+
+impl gdiplus_GpBitmap for GpBitmap {}
+
+/// This trait is enabled with the `gdiplus` feature, and provides methods for
+/// [`GpBitmap`](crate::GpBitmap).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdiplus_GpBitmap: Handle {
+	/// [`GdipCreateBitmapFromHBITMAP`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-bitmap-flat)
+	/// function.
+	///
+	/// The palette is used only if the source is a device-dependent bitmap
+	/// with 8 bits per pixel or less; pass `None` for 16, 24 and 32 bpp
+	/// bitmaps, which are the case of screen captures. Note that the alpha
+	/// channel of a 32 bpp bitmap is ignored.
+	///
+	/// The `HBITMAP` is copied, so it can be deleted right after this call.
+	///
+	/// # Examples
+	///
+	/// Saving a screen capture as PNG:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hbmp: w::HBITMAP; // initialized somewhere
+	/// # let hbmp = w::HBITMAP::NULL;
+	///
+	/// let _gdiplus = w::GdiplusStartup()?; // keep alive until the end
+	///
+	/// let bmp = w::GpBitmap::GdipCreateBitmapFromHBITMAP(&hbmp, None)?;
+	/// bmp.save("C:\\Temp\\capture.png", w::ImageFormat::Png)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn GdipCreateBitmapFromHBITMAP(
+		hbmp: &HBITMAP,
+		hpal: Option<&HPALETTE>,
+	) -> SysResult<GdipDisposeImageGuard>
+	{
+		let mut handle = GpBitmap::NULL;
+		unsafe {
+			status_to_sysresult(
+				ffi::GdipCreateBitmapFromHBITMAP(
+					hbmp.ptr(),
+					hpal.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					handle.as_mut(),
+				),
+			).map(|_| GdipDisposeImageGuard::new(handle))
+		}
+	}
+
+	/// [`GdipSaveImageToFile`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-image-flat)
+	/// function.
+	///
+	/// The encoder is chosen by the MIME type of the given format. The
+	/// installed encoders are enumerated only once, in the first call, and
+	/// cached for the subsequent ones. For
+	/// [`ImageFormat::Jpeg`](crate::ImageFormat::Jpeg), the quality is passed
+	/// to the encoder, being clamped to 100.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let bmp: w::GpBitmap; // initialized somewhere
+	/// # let bmp = w::GpBitmap::NULL;
+	///
+	/// bmp.save("C:\\Temp\\capture.jpg", w::ImageFormat::Jpeg(85))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn save(&self, path: &str, format: ImageFormat) -> SysResult<()> {
+		let clsid = encoder_clsid(format.mime_type())?;
+
+		let mut quality = match format {
+			ImageFormat::Jpeg(q) => q.min(100) as u32,
+			_ => 0,
+		};
+		let params = EncoderParameters {
+			count: 1,
+			parameter: EncoderParameter {
+				guid: unsafe { std::mem::transmute::<GUID, [u32; 4]>(ENCODER_QUALITY) },
+				number_of_values: 1,
+				value_type: ENCODER_PARAMETER_VALUE_TYPE_LONG,
+				value: &mut quality as *mut _ as _,
+			},
+		};
+		let clsid_raw: [u32; 4] = unsafe { std::mem::transmute::<GUID, [u32; 4]>(clsid) };
+
+		status_to_sysresult(
+			unsafe {
+				ffi::GdipSaveImageToFile(
+					self.ptr(),
+					WString::from_str(path).as_ptr(),
+					clsid_raw.as_ptr() as _,
+					match format {
+						ImageFormat::Jpeg(_) => &params as *const _ as _,
+						_ => std::ptr::null(),
+					},
+				)
+			},
+		)
+	}
+}
@@ -0,0 +1,9 @@
+mod gpbitmap;
+
+pub mod decl {
+	pub use super::gpbitmap::GpBitmap;
+}
+
+pub mod traits {
+	pub use super::gpbitmap::gdiplus_GpBitmap;
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "gdiplus")]
+
+mod enums;
+mod funcs;
+mod handles;
+mod privs;
+
+pub(in crate::gdiplus) mod ffi;
+pub mod guard;
+
+pub mod decl {
+	pub use super::enums::*;
+	pub use super::funcs::*;
+	pub use super::handles::decl::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
@@ -0,0 +1,109 @@
+use std::sync::Mutex;
+
+use crate::co;
+use crate::decl::*;
+use crate::gdiplus::ffi;
+
+/// [`EncoderQuality`](https://learn.microsoft.com/en-us/windows/win32/gdiplus/-gdiplus-constant-image-encoder-constants)
+/// parameter `GUID`.
+pub(in crate::gdiplus) const ENCODER_QUALITY: GUID =
+	GUID::new("1d5be4b5-fa4a-452d-9cdd-5db35105e7eb");
+
+/// `EncoderParameterValueTypeLong`.
+pub(in crate::gdiplus) const ENCODER_PARAMETER_VALUE_TYPE_LONG: u32 = 4;
+
+/// [`EncoderParameters`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusimaging/nl-gdiplusimaging-encoderparameters)
+/// class, with a single parameter.
+#[repr(C)]
+pub(in crate::gdiplus) struct EncoderParameters {
+	pub count: u32,
+	pub parameter: EncoderParameter,
+}
+
+/// [`EncoderParameter`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusimaging/nl-gdiplusimaging-encoderparameter)
+/// class.
+#[repr(C)]
+pub(in crate::gdiplus) struct EncoderParameter {
+	pub guid: [u32; 4], // GUID with its native 4-byte alignment
+	pub number_of_values: u32,
+	pub value_type: u32,
+	pub value: *mut std::ffi::c_void,
+}
+
+/// [`ImageCodecInfo`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplusimaging/nl-gdiplusimaging-imagecodecinfo)
+/// class.
+#[repr(C)]
+struct ImageCodecInfo {
+	clsid: [u32; 4], // GUID with its native 4-byte alignment
+	format_id: [u32; 4],
+	codec_name: *const u16,
+	dll_name: *const u16,
+	format_description: *const u16,
+	filename_extension: *const u16,
+	mime_type: *const u16,
+	flags: u32,
+	version: u32,
+	sig_count: u32,
+	sig_size: u32,
+	sig_pattern: *const u8,
+	sig_mask: *const u8,
+}
+
+/// Installed encoders, as MIME type and CLSID pairs. Loaded on first use.
+static ENCODERS: Mutex<Vec<(String, GUID)>> = Mutex::new(Vec::new());
+
+/// Returns the CLSID of the encoder for the given MIME type, enumerating the
+/// installed encoders only in the first call.
+pub(in crate::gdiplus) fn encoder_clsid(mime_type: &str) -> SysResult<GUID> {
+	let mut encoders = ENCODERS.lock().unwrap();
+	if encoders.is_empty() {
+		let (mut num, mut sz) = (0u32, 0u32);
+		status_to_sysresult(unsafe { ffi::GdipGetImageEncodersSize(&mut num, &mut sz) })?;
+
+		let mut buf = vec![0u64; (sz as usize).div_ceil(8)]; // 8-byte aligned buffer
+		status_to_sysresult(
+			unsafe { ffi::GdipGetImageEncoders(num, sz, buf.as_mut_ptr() as _) },
+		)?;
+
+		let infos = unsafe {
+			std::slice::from_raw_parts(buf.as_ptr() as *const ImageCodecInfo, num as _)
+		};
+		*encoders = infos.iter()
+			.map(|info| (
+				unsafe { WString::from_wchars_nullt(info.mime_type) }.to_string(),
+				unsafe { std::mem::transmute::<[u32; 4], GUID>(info.clsid) },
+			))
+			.collect();
+	}
+
+	encoders.iter()
+		.find(|(mime, _)| mime == mime_type)
+		.map(|(_, clsid)| *clsid)
+		.ok_or(co::ERROR::NOT_FOUND)
+}
+
+/// Converts a GDI+
+/// [`Status`](https://learn.microsoft.com/en-us/windows/win32/api/gdiplustypes/ne-gdiplustypes-status)
+/// value into the closest [`co::ERROR`](crate::co::ERROR).
+pub(in crate::gdiplus) fn status_to_sysresult(status: u32) -> SysResult<()> {
+	match status {
+		0 => Ok(()), // Ok
+		2 => Err(co::ERROR::INVALID_PARAMETER), // InvalidParameter
+		3 => Err(co::ERROR::OUTOFMEMORY), // OutOfMemory
+		4 => Err(co::ERROR::BUSY), // ObjectBusy
+		5 => Err(co::ERROR::INSUFFICIENT_BUFFER), // InsufficientBuffer
+		6 => Err(co::ERROR::CALL_NOT_IMPLEMENTED), // NotImplemented
+		7 => Err(GetLastError()), // Win32Error
+		8 => Err(co::ERROR::INVALID_STATE), // WrongState
+		9 => Err(co::ERROR::CANCELLED), // Aborted
+		10 => Err(co::ERROR::FILE_NOT_FOUND), // FileNotFound
+		11 => Err(co::ERROR::ARITHMETIC_OVERFLOW), // ValueOverflow
+		12 => Err(co::ERROR::ACCESS_DENIED), // AccessDenied
+		13 => Err(co::ERROR::BAD_FORMAT), // UnknownImageFormat
+		17 => Err(co::ERROR::OLD_WIN_VERSION), // UnsupportedGdiplusVersion
+		18 => Err(co::ERROR::NOT_READY), // GdiplusNotInitialized
+		19 | 21 => Err(co::ERROR::NOT_FOUND), // PropertyNotFound, ProfileNotFound
+		14..=16 | 20 => Err(co::ERROR::NOT_SUPPORTED), // font errors, PropertyNotSupported
+		_ => Err(co::ERROR::GEN_FAILURE), // GenericError
+	}
+}
@@ -0,0 +1,180 @@
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::privs::*;
+use crate::prelude::*;
+
+struct Obj { // shared between the scope and its guards
+	counter: AtomicU32, // number of running tasks
+	applied_busy: AtomicBool, // last state applied in the UI thread
+	on_change: Mutex<Box<dyn Fn(bool) -> AnyResult<()> + Send>>,
+}
+
+impl Obj {
+	fn sync_ui(self: &Arc<Self>, hparent: &HWND) {
+		let self2 = self.clone();
+		Base::run_ui_thread_of(hparent, move || {
+			// Starts and finishes from different threads may arrive in any
+			// order, so we always apply the current state, once.
+			let busy = self2.counter.load(Ordering::SeqCst) > 0;
+			if self2.applied_busy.swap(busy, Ordering::SeqCst) != busy {
+				SetThreadExecutionState(if busy {
+					co::EXECUTION_STATE::CONTINUOUS | co::EXECUTION_STATE::SYSTEM_REQUIRED
+				} else {
+					co::EXECUTION_STATE::CONTINUOUS
+				});
+				let on_change = self2.on_change.lock().unwrap();
+				on_change(busy)?;
+			}
+			Ok(())
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Tracks background tasks, showing a busy indicator and preventing the system
+/// from sleeping while at least one of them is running.
+///
+/// When the first task starts, the given closure is called with `true`, and
+/// [`SetThreadExecutionState`](crate::SetThreadExecutionState) is called to
+/// keep the system awake. When the last task finishes, the closure is called
+/// with `false`, and the system is allowed to sleep again. The closure always
+/// runs in the UI thread, so it can update the UI freely – for example,
+/// turning a [`ProgressBar`](crate::gui::ProgressBar) marquee on and off.
+///
+/// Tasks are started in the UI thread, and can finish concurrently from any
+/// thread. Each task is tracked by an
+/// [`ActivityGuard`](crate::gui::ActivityGuard), which is released even if the
+/// task thread panics.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// let btn: gui::Button;
+/// let progress: gui::ProgressBar;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+/// # let progress = gui::ProgressBar::new(&wnd, gui::ProgressBarOpts::default());
+///
+/// let activity = gui::ActivityScope::new(&wnd, {
+///     let progress = progress.clone();
+///     move |busy| {
+///         progress.set_marquee(busy);
+///         Ok(())
+///     }
+/// });
+///
+/// btn.on().bn_clicked(move || {
+///     activity.spawn(|| {
+///         w::Sleep(5000); // long task
+///         Ok(())
+///     });
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ActivityScope {
+	parent_ptr: NonNull<Base>, // only used in the UI thread
+	obj: Arc<Obj>,
+}
+
+impl ActivityScope {
+	/// Instantiates a new `ActivityScope` object. The closure will be called
+	/// in the UI thread of the parent window, with `true` when the first task
+	/// starts, and `false` when the last task finishes.
+	///
+	/// Tasks must not be tracked before the parent window is created.
+	#[must_use]
+	pub fn new<F>(parent: &impl GuiParent, on_change: F) -> Self
+		where F: Fn(bool) -> AnyResult<()> + Send + 'static,
+	{
+		Self {
+			parent_ptr: NonNull::from(parent.as_ref()),
+			obj: Arc::new(
+				Obj {
+					counter: AtomicU32::new(0),
+					applied_busy: AtomicBool::new(false),
+					on_change: Mutex::new(Box::new(on_change)),
+				},
+			),
+		}
+	}
+
+	/// Returns `true` if at least one task is running.
+	#[must_use]
+	pub fn is_busy(&self) -> bool {
+		self.obj.counter.load(Ordering::SeqCst) > 0
+	}
+
+	/// Spawns a new thread to run the given closure, which is tracked until it
+	/// returns or panics.
+	///
+	/// If the closure returns an error, it is forwarded to the UI thread, like
+	/// in [`GuiParent::spawn_new_thread`](crate::prelude::GuiParent::spawn_new_thread).
+	pub fn spawn<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		let guard = self.track(); // count it right away, so the indicator shows immediately
+		self.base().spawn_new_thread(move || {
+			let _guard = guard; // released even on panic
+			func()
+		});
+	}
+
+	/// Starts tracking a task, which will be considered running until the
+	/// returned guard goes out of scope. The guard can be moved to another
+	/// thread, and it only keeps the parent `HWND` to reach the UI thread.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let activity: gui::ActivityScope; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let activity = gui::ActivityScope::new(&wnd, |_| Ok(()));
+	///
+	/// let guard = activity.track();
+	/// std::thread::spawn(move || {
+	///     let _guard = guard;
+	///     w::Sleep(5000); // long task
+	/// });
+	/// ```
+	#[must_use]
+	pub fn track(&self) -> ActivityGuard {
+		let hparent = unsafe { self.base().hwnd().raw_copy() };
+		if self.obj.counter.fetch_add(1, Ordering::SeqCst) == 0 {
+			self.obj.sync_ui(&hparent); // first task
+		}
+		ActivityGuard { obj: self.obj.clone(), hparent }
+	}
+
+	#[must_use]
+	fn base(&self) -> &Base {
+		unsafe { self.parent_ptr.as_ref() }
+	}
+}
+
+/// Keeps a task tracked by an [`ActivityScope`](crate::gui::ActivityScope)
+/// until it goes out of scope, including during a panic unwind.
+///
+/// Returned by [`ActivityScope::track`](crate::gui::ActivityScope::track).
+pub struct ActivityGuard {
+	obj: Arc<Obj>,
+	hparent: HWND,
+}
+
+impl Drop for ActivityGuard {
+	fn drop(&mut self) {
+		if self.obj.counter.fetch_sub(1, Ordering::SeqCst) == 1 {
+			self.obj.sync_ui(&self.hparent); // last task
+		}
+	}
+}
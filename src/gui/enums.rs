@@ -40,6 +40,17 @@ pub enum CheckState {
 	Unchecked,
 }
 
+/// Contents of the clipboard, read and written by
+/// [`ClipboardMonitor`](crate::gui::ClipboardMonitor).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum ClipboardContent {
+	/// An image in [`co::CF::DIB`](crate::co::CF::DIB) format: a
+	/// [`BITMAPINFO`](crate::BITMAPINFO) header followed by the bitmap bits.
+	Dib(Vec<u8>),
+	/// Text in [`co::CF::UNICODETEXT`](crate::co::CF::UNICODETEXT) format.
+	Text(String),
+}
+
 /// The class cursor to be loaded for
 /// [`WindowMainOpts`](crate::gui::WindowMainOpts),
 /// [`WindowModalOpts`](crate::gui::WindowModalOpts) or
@@ -185,6 +196,18 @@ pub enum SbPart {
 	Proportional(u8),
 }
 
+/// How the panes are laid out around a [`Splitter`](crate::gui::Splitter),
+/// used in [`SplitterOpts`](crate::gui::SplitterOpts).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SplitterOrientation {
+	/// The panes are side by side, at left and right of the splitter, which is
+	/// dragged horizontally.
+	Horizontal,
+	/// The panes are stacked, at top and bottom of the splitter, which is
+	/// dragged vertically.
+	Vertical,
+}
+
 /// Specifies the vertical behavior of the control when the parent window is
 /// resized.
 ///
@@ -224,5 +247,9 @@ pub enum WmRet {
 	HandledOk,
 	/// The message was handled, and the specific value must be returned by the
 	/// window procedure.
+	///
+	/// In dialog windows, the value is set with `DWLP_MSGRESULT` and the
+	/// procedure returns `TRUE`, except for the messages whose result is
+	/// returned directly, like `WM_CTLCOLOR*` and `WM_INITDIALOG`.
 	HandledWithRet(isize),
 }
@@ -4,6 +4,7 @@ use crate::co;
 use crate::decl::*;
 use crate::gui::{*, privs::*};
 use crate::msg::*;
+use crate::prelude::*;
 
 /// Base to all native control events. This is actually a proxy to the events of
 /// the parent window; events added to a native control are actually added as
@@ -30,6 +31,23 @@ impl BaseCtrlEventsProxy {
 		parent_base_ref.on().wm_command(self.ctrl_id, code, func);
 	}
 
+	/// Adds a `WM_DRAWITEM` event to the parent window, narrowed to the
+	/// control ID.
+	pub(in crate::gui) fn wm_draw_item<F>(&self, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		let parent_base_ref = unsafe { self.parent_ptr.as_ref() };
+		let ctrl_id = self.ctrl_id;
+		parent_base_ref.on().wm(co::WM::DRAWITEM, move |p| {
+			let p = unsafe { wm::DrawItem::from_generic_wm(p) };
+			if p.control_id != ctrl_id {
+				return Ok(WmRet::NotHandled); // another control, keep searching
+			}
+			func(p)?;
+			Ok(WmRet::HandledOk)
+		});
+	}
+
 	/// Adds a `WM_NOTIFY` event to the parent window.
 	pub(in crate::gui) fn wm_notify<F>(&self,
 		code: impl Into<NmhdrCode>,
@@ -0,0 +1,42 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::msg::*;
+
+/// Exposes the notifications of a
+/// [`CustomControl`](crate::gui::CustomControl).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct CustomControlEvents(BaseCtrlEventsProxy);
+
+impl CustomControlEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<Base>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEventsProxy::new(parent, ctrl_id))
+	}
+
+	/// [`WM_COMMAND`](crate::msg::wm::Command) message sent by the control
+	/// to its parent, for the given
+	/// [command code](crate::co::CMD).
+	pub fn wm_command<F>(&self, code: impl Into<co::CMD>, func: F)
+		where F: Fn() -> AnyResult<WmRet> + 'static,
+	{
+		self.0.wm_command(code, func);
+	}
+
+	/// [`WM_NOTIFY`](crate::msg::wm::Notify) message sent by the control to
+	/// its parent, for the given notification code.
+	///
+	/// The notification struct must be retrieved with
+	/// [`wm::Notify::cast_nmhdr`](crate::msg::wm::Notify::cast_nmhdr).
+	pub fn wm_notify<F>(&self, code: impl Into<NmhdrCode>, func: F)
+		where F: Fn(wm::Notify) -> AnyResult<WmRet> + 'static,
+	{
+		self.0.wm_notify(code, func);
+	}
+}
@@ -250,6 +250,72 @@ impl ListViewEvents {
 
 	/// [`NM_CUSTOMDRAW`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-customdraw-list-view)
 	/// notification.
+	///
+	/// Custom draw is a multi-stage protocol, where the stage is given by
+	/// `mcd.dwDrawStage`, and the returned [`co::CDRF`](crate::co::CDRF)
+	/// tells the control which further notifications you want:
+	///
+	/// * [`CDDS::PREPAINT`](crate::co::CDDS::PREPAINT) – before the whole
+	///   control is painted; return
+	///   [`CDRF::NOTIFYITEMDRAW`](crate::co::CDRF::NOTIFYITEMDRAW) to be notified
+	///   for each item;
+	/// * [`CDDS::ITEMPREPAINT`](crate::co::CDDS::ITEMPREPAINT) – before each
+	///   item is painted, with its index in `mcd.dwItemSpec`; set `clrText` and
+	///   `clrTextBk` and return [`CDRF::NEWFONT`](crate::co::CDRF::NEWFONT) to
+	///   color the whole row, or return
+	///   [`CDRF::NOTIFYSUBITEMDRAW`](crate::co::CDRF::NOTIFYSUBITEMDRAW) to be
+	///   notified for each subitem;
+	/// * `CDDS::ITEMPREPAINT | CDDS::SUBITEM` – before each subitem is painted,
+	///   with its column index in `iSubItem`; set `clrText` and `clrTextBk` and
+	///   return `CDRF::NEWFONT`.
+	///
+	/// Any other stage should return
+	/// [`CDRF::DODEFAULT`](crate::co::CDRF::DODEFAULT). The `HDC` being painted
+	/// is available in `mcd.hdc`.
+	///
+	/// # Examples
+	///
+	/// Alternating row colors, with the negative values of the second column
+	/// painted in red:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// list.on().nm_custom_draw({
+	///     let list = list.clone();
+	///     move |cd| {
+	///         let stage = cd.mcd.dwDrawStage;
+	///         Ok(if stage == co::CDDS::PREPAINT {
+	///             co::CDRF::NOTIFYITEMDRAW
+	///         } else if stage == co::CDDS::ITEMPREPAINT {
+	///             co::CDRF::NOTIFYSUBITEMDRAW
+	///         } else if stage == co::CDDS::ITEMPREPAINT | co::CDDS::SUBITEM {
+	///             let item = list.items().get(cd.mcd.dwItemSpec as _);
+	///             let text = item.text(cd.iSubItem as _);
+	///
+	///             // Colors must be set for every subitem, otherwise the
+	///             // previous ones are kept.
+	///             cd.clrTextBk = if cd.mcd.dwItemSpec % 2 == 1 {
+	///                 w::COLORREF::new(0xf0, 0xf0, 0xf8)
+	///             } else {
+	///                 w::COLORREF::new(0xff, 0xff, 0xff)
+	///             };
+	///             cd.clrText = if cd.iSubItem == 1 && text.starts_with('-') {
+	///                 w::COLORREF::new(0xc0, 0x00, 0x00)
+	///             } else {
+	///                 w::COLORREF::new(0x00, 0x00, 0x00)
+	///             };
+	///             co::CDRF::NEWFONT
+	///         } else {
+	///             co::CDRF::DODEFAULT
+	///         })
+	///     }
+	/// });
+	/// ```
 	pub fn nm_custom_draw<F>(&self, func: F)
 		where F: Fn(&mut NMLVCUSTOMDRAW) -> AnyResult<co::CDRF> + 'static,
 	{
@@ -1,9 +1,11 @@
 //! Events exposed by windows and controls, which allow the handling of native
 //! window messages.
 
+mod animation_events;
 mod base_ctrl_events_proxy;
 mod button_events;
 mod combo_box_events;
+mod custom_control_events;
 mod date_time_picker_events;
 mod edit_events;
 mod func_store;
@@ -13,8 +15,11 @@ mod list_box_events;
 mod list_view_events;
 mod month_calendar_events;
 mod radio_group_events;
+mod rebar_events;
 mod status_bar_events;
+mod sys_link_events;
 mod tab_events;
+mod toolbar_events;
 mod trackbar_events;
 mod tree_view_events;
 mod up_down_events;
@@ -25,8 +30,10 @@ pub(in crate::gui) mod privs {
 	pub(in crate::gui) use super::func_store::FuncStore;
 }
 
+pub use animation_events::AnimationEvents;
 pub use button_events::ButtonEvents;
 pub use combo_box_events::ComboBoxEvents;
+pub use custom_control_events::CustomControlEvents;
 pub use date_time_picker_events::DateTimePickerEvents;
 pub use edit_events::EditEvents;
 pub use header_events::HeaderEvents;
@@ -35,8 +42,11 @@ pub use list_box_events::ListBoxEvents;
 pub use list_view_events::ListViewEvents;
 pub use month_calendar_events::MonthCalendarEvents;
 pub use radio_group_events::RadioGroupEvents;
+pub use rebar_events::RebarEvents;
 pub use status_bar_events::StatusBarEvents;
+pub use sys_link_events::SysLinkEvents;
 pub use tab_events::TabEvents;
+pub use toolbar_events::ToolbarEvents;
 pub use trackbar_events::TrackbarEvents;
 pub use tree_view_events::TreeViewEvents;
 pub use up_down_events::UpDownEvents;
@@ -22,12 +22,21 @@ impl MonthCalendarEvents {
 	pub_fn_nfy_withparm_noret! { mcn_get_day_state, co::MCN::GETDAYSTATE, NMDAYSTATE;
 		/// [`MCN_GETDAYSTATE`](https://learn.microsoft.com/en-us/windows/win32/controls/mcn-getdaystate)
 		/// notification.
+		///
+		/// This notification is already handled to supply the days set with
+		/// [`MonthCalendar::set_bold_days`](crate::gui::MonthCalendar::set_bold_days);
+		/// a handler added here runs afterwards, and may further change the
+		/// day states.
 	}
 
 	pub_fn_nfy_withparm_noret! { mcn_sel_change, co::MCN::SELCHANGE, NMSELCHANGE;
 		/// [`MCN_SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/mcn-selchange)
 		/// notification.
 		///
+		/// With the [`co::MCS::MULTISELECT`](crate::co::MCS::MULTISELECT)
+		/// style, `stSelStart` and `stSelEnd` hold the first and last dates of
+		/// the selected range; otherwise both hold the selected date.
+		///
 		/// # Examples
 		///
 		/// ```no_run
@@ -0,0 +1,61 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::privs::*;
+
+/// Exposes rebar control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct RebarEvents(BaseCtrlEventsProxy);
+
+impl RebarEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<Base>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEventsProxy::new(parent, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_boolret! { rbn_begin_drag, co::RBN::BEGINDRAG, NMREBAR;
+		/// [`RBN_BEGINDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-begindrag)
+		/// notification.
+		///
+		/// Return `true` to prevent the band from being dragged.
+	}
+
+	pub_fn_nfy_withmutparm_noret! { rbn_child_size, co::RBN::CHILDSIZE, NMREBARCHILDSIZE;
+		/// [`RBN_CHILDSIZE`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-childsize)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_deleted_band, co::RBN::DELETEDBAND, NMREBAR;
+		/// [`RBN_DELETEDBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-deletedband)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_deleting_band, co::RBN::DELETINGBAND, NMREBAR;
+		/// [`RBN_DELETINGBAND`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-deletingband)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { rbn_end_drag, co::RBN::ENDDRAG, NMREBAR;
+		/// [`RBN_ENDDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-enddrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { rbn_height_change, co::RBN::HEIGHTCHANGE;
+		/// [`RBN_HEIGHTCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-heightchange)
+		/// notification.
+		///
+		/// Sent when the rebar height changes, so the other child controls of
+		/// the parent window can be repositioned.
+	}
+
+	pub_fn_nfy_noparm_noret! { rbn_layout_changed, co::RBN::LAYOUTCHANGED;
+		/// [`RBN_LAYOUTCHANGED`](https://learn.microsoft.com/en-us/windows/win32/controls/rbn-layoutchanged)
+		/// notification.
+	}
+}
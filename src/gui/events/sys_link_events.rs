@@ -0,0 +1,56 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::privs::*;
+
+/// Exposes SysLink control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-syslink-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct SysLinkEvents(BaseCtrlEventsProxy);
+
+impl SysLinkEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<Base>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEventsProxy::new(parent, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_noret! { nm_click, co::NM::CLICK, NMLINK;
+		/// [`NM_CLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-click-syslink)
+		/// notification.
+		///
+		/// The clicked link is described by the `item` member: `iLink` is its
+		/// zero-based index, while `szID` and `szUrl` hold the values of its
+		/// `id` and `href` attributes.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// let link: gui::SysLink;
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let link = gui::SysLink::new(&wnd, gui::SysLinkOpts::default());
+		///
+		/// link.on().nm_click(
+		///     move |p: &w::NMLINK| -> w::AnyResult<()> {
+		///         println!("Link {} clicked: {}", p.item.iLink, p.item.szUrl());
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
+	pub_fn_nfy_withparm_noret! { nm_return, co::NM::RETURN, NMLINK;
+		/// [`NM_RETURN`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-return-syslink-)
+		/// notification.
+		///
+		/// Sent when the user presses Enter on a focused link, which is
+		/// described by the `item` member.
+	}
+}
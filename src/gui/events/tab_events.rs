@@ -1,6 +1,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::gui::privs::*;
+use crate::msg::*;
 
 /// Exposes tab control
 /// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tab-control-reference-notifications).
@@ -86,4 +87,46 @@ impl TabEvents {
 		/// [`TCN_SELCHANGING`](https://learn.microsoft.com/en-us/windows/win32/controls/tcn-selchanging)
 		/// notification.
 	}
+
+	/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+	/// message, sent to the parent window for each tab when the control has
+	/// the [`co::TCS::OWNERDRAWFIXED`](crate::co::TCS::OWNERDRAWFIXED) style.
+	///
+	/// The `itemID` member of the
+	/// [`DRAWITEMSTRUCT`](crate::DRAWITEMSTRUCT) is the zero-based index of
+	/// the tab being drawn, which can be passed to
+	/// [`TabItems::get`](crate::gui::TabItems::get).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let tab = gui::Tab::new(
+	///     &wnd,
+	///     gui::TabOpts {
+	///         tab_style: co::TCS::OWNERDRAWFIXED,
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// tab.on().wm_draw_item({
+	///     let tab = tab.clone();
+	///     move |p| -> w::AnyResult<()> {
+	///         let di = p.drawitemstruct;
+	///         let item = tab.items().get(di.itemID);
+	///         di.hDC.DrawText(&item.text(), di.rcItem,
+	///             co::DT::SINGLELINE | co::DT::VCENTER | co::DT::CENTER)?;
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	pub fn wm_draw_item<F>(&self, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		self.0.wm_draw_item(func);
+	}
 }
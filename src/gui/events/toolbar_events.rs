@@ -0,0 +1,71 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+
+/// Exposes toolbar control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-toolbar-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct ToolbarEvents(BaseCtrlEventsProxy);
+
+impl ToolbarEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<Base>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEventsProxy::new(parent, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_click, co::NM::CLICK, NMMOUSE;
+		/// [`NM_CLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-click-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_rclick, co::NM::RCLICK, NMMOUSE;
+		/// [`NM_RCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-rclick-toolbar)
+		/// notification.
+	}
+
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// notification.
+	///
+	/// Sent when the arrow of a [`co::BTNS::DROPDOWN`](crate::co::BTNS::DROPDOWN)
+	/// button is clicked; the command ID of the button is in `iItem`.
+	///
+	/// # Examples
+	///
+	/// Showing a popup menu below the button:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let toolbar: gui::Toolbar;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	/// let hmenu: w::HMENU; // initialized somewhere
+	/// # let hmenu = w::HMENU::NULL;
+	///
+	/// toolbar.on().tbn_drop_down({
+	///     let (wnd, toolbar) = (wnd.clone(), toolbar.clone());
+	///     move |nmtb| {
+	///         let pt = toolbar.hwnd().ClientToScreen(
+	///             w::POINT::new(nmtb.rcButton.left, nmtb.rcButton.bottom))?;
+	///         hmenu.TrackPopupMenu(co::TPM::LEFTALIGN, pt, wnd.hwnd())?;
+	///         Ok(co::TBDDRET::DEFAULT)
+	///     }
+	/// });
+	/// # w::AnyResult::Ok(())
+	/// ```
+	pub fn tbn_drop_down<F>(&self, func: F)
+		where F: Fn(&NMTOOLBAR) -> AnyResult<co::TBDDRET> + 'static,
+	{
+		self.0.wm_notify(co::TBN::DROPDOWN, move |p| {
+			let ret_val = func(unsafe { p.cast_nmhdr::<NMTOOLBAR>() })?.raw() as isize;
+			Ok(WmRet::HandledWithRet(ret_val))
+		});
+	}
+}
@@ -1,6 +1,6 @@
 use crate::co;
 use crate::decl::*;
-use crate::gui::privs::*;
+use crate::gui::{*, privs::*};
 
 /// Exposes up-down control
 /// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-up-down-control-reference-notifications).
@@ -24,8 +24,34 @@ impl UpDownEvents {
 		/// notification.
 	}
 
-	pub_fn_nfy_withparm_i32ret! { udn_delta_pos, co::UDN::DELTAPOS, NMUPDOWN;
-		/// [`UDN_DELTAPOS`](https://learn.microsoft.com/en-us/windows/win32/controls/udn-deltapos)
-		/// notification.
+	/// [`UDN_DELTAPOS`](https://learn.microsoft.com/en-us/windows/win32/controls/udn-deltapos)
+	/// notification.
+	///
+	/// Sent before the position changes. The `iDelta` field can be modified to
+	/// change the increment; return a nonzero value to prevent the change.
+	///
+	/// # Examples
+	///
+	/// Stepping by 5:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let updn: gui::UpDown; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let updn = gui::UpDown::new(&wnd, gui::UpDownOpts::default());
+	///
+	/// updn.on().udn_delta_pos(|nmud| {
+	///     nmud.iDelta *= 5;
+	///     Ok(0) // allow the change
+	/// });
+	/// ```
+	pub fn udn_delta_pos<F>(&self, func: F)
+		where F: Fn(&mut NMUPDOWN) -> AnyResult<i32> + 'static,
+	{
+		self.0.wm_notify(co::UDN::DELTAPOS, move |p| {
+			let ret_val = func(unsafe { p.cast_nmhdr_mut::<NMUPDOWN>() })? as isize;
+			Ok(WmRet::HandledWithRet(ret_val))
+		});
 	}
 }
@@ -490,6 +490,47 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_noparm_noret! { wm_clipboard_update, co::WM::CLIPBOARDUPDATE;
+		/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+		/// message.
+		///
+		/// Sent only if the window was registered with
+		/// [`HWND::AddClipboardFormatListener`](crate::prelude::user_Hwnd::AddClipboardFormatListener).
+		///
+		/// For a ready-to-use listener, which also reads the clipboard contents,
+		/// see [`ClipboardMonitor`](crate::gui::ClipboardMonitor).
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_create({
+		///     let wnd = wnd.clone();
+		///     move |_| {
+		///         wnd.hwnd().AddClipboardFormatListener()?;
+		///         Ok(0)
+		///     }
+		/// });
+		///
+		/// wnd.on().wm_clipboard_update(move || {
+		///     println!("Clipboard changed, sequence {}", w::GetClipboardSequenceNumber());
+		///     Ok(())
+		/// });
+		///
+		/// wnd.on().wm_destroy({
+		///     let wnd = wnd.clone();
+		///     move || {
+		///         wnd.hwnd().RemoveClipboardFormatListener()?;
+		///         Ok(())
+		///     }
+		/// });
+		/// ```
+	}
+
 	pub_fn_wm_noparm_noret! { wm_close, co::WM::CLOSE;
 		/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 		/// message.
@@ -508,6 +549,65 @@ impl WindowEvents {
 		/// message.
 	}
 
+	/// [`WM_CTLCOLORBTN`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorbtn),
+	/// [`WM_CTLCOLORDLG`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/wm-ctlcolordlg),
+	/// [`WM_CTLCOLOREDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcoloredit),
+	/// [`WM_CTLCOLORLISTBOX`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorlistbox),
+	/// [`WM_CTLCOLORSCROLLBAR`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorscrollbar)
+	/// and
+	/// [`WM_CTLCOLORSTATIC`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorstatic)
+	/// messages, handled by a single closure.
+	///
+	/// These messages are sent by child controls to the parent window right
+	/// before they're painted – `WM_CTLCOLORDLG` is sent by a dialog box to
+	/// itself, to paint its own background. Set the text and background colors on the
+	/// received `HDC`, and return the brush used to paint the control
+	/// background. The brush is not deleted by the system, so it must be kept
+	/// alive while the window exists – create it once, not at each message.
+	///
+	/// Note that read-only and disabled edit controls send `WM_CTLCOLORSTATIC`
+	/// instead of `WM_CTLCOLOREDIT`. The `msg_id` field tells which message was
+	/// received.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui, msg};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let bg_color = w::COLORREF::new(0x20, 0x20, 0x20);
+	/// let hbrush = w::HBRUSH::CreateSolidBrush(bg_color)?; // kept alive by the closure
+	///
+	/// wnd.on().wm_ctl_color(move |p: msg::wm::CtlColor| -> w::AnyResult<w::HBRUSH> {
+	///     p.hdc.SetTextColor(w::COLORREF::new(0xe0, 0xe0, 0xe0))?;
+	///     p.hdc.SetBkColor(bg_color)?;
+	///     p.hdc.SetBkMode(co::BKMODE::OPAQUE)?;
+	///     Ok(unsafe { hbrush.raw_copy() })
+	/// });
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn wm_ctl_color<F>(&self, func: F)
+		where F: Fn(wm::CtlColor) -> AnyResult<HBRUSH> + 'static,
+	{
+		let func = Rc::new(func);
+		for msg_id in [
+			co::WM::CTLCOLORBTN,
+			co::WM::CTLCOLORDLG,
+			co::WM::CTLCOLOREDIT,
+			co::WM::CTLCOLORLISTBOX,
+			co::WM::CTLCOLORSCROLLBAR,
+			co::WM::CTLCOLORSTATIC,
+		] {
+			let func = func.clone();
+			self.wm(msg_id, move |p| {
+				let ret_val = func(unsafe { wm::CtlColor::from_generic_wm(p) })?.ptr() as isize;
+				Ok(WmRet::HandledWithRet(ret_val))
+			});
+		}
+	}
+
 	pub_fn_wm_ctlcolor! { wm_ctl_color_btn, co::WM::CTLCOLORBTN, wm::CtlColorBtn;
 		/// [`WM_CTLCOLORBTN`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorbtn)
 		/// message.
@@ -578,6 +678,15 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_noret! { wm_draw_item, co::WM::DRAWITEM, wm::DrawItem;
+		/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+		/// message.
+		///
+		/// This message is sent for all owner-drawn child controls; to handle
+		/// it for a single control, prefer the `wm_draw_item` event of the
+		/// control itself, if available.
+	}
+
 	#[cfg(feature = "shell")]
 	pub_fn_wm_withparm_noret! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles;
 		/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
@@ -682,6 +791,26 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_get_min_max_info, co::WM::GETMINMAXINFO, wm::GetMinMaxInfo;
 		/// [`WM_GETMINMAXINFO`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-getminmaxinfo)
 		/// message.
+		///
+		/// To enforce a fixed aspect ratio during a live resize, handle
+		/// [`wm_sizing`](crate::gui::events::WindowEvents::wm_sizing) instead.
+		///
+		/// # Examples
+		///
+		/// Limiting the window size when the user drags the border:
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_get_min_max_info(move |p: msg::wm::GetMinMaxInfo| -> w::AnyResult<()> {
+		///     p.info.ptMinTrackSize = w::POINT::new(400, 300);
+		///     p.info.ptMaxTrackSize = w::POINT::new(1200, 900);
+		///     Ok(())
+		/// });
+		/// ```
 	}
 
 	/// [`WM_GETTEXT`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-gettext)
@@ -721,6 +850,50 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_noret! { wm_hot_key, co::WM::HOTKEY, wm::HotKey;
+		/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+		/// message.
+		///
+		/// Sent when a hotkey registered with
+		/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey)
+		/// is pressed.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// const ID_HOTKEY: i32 = 1;
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_create({
+		///     let wnd = wnd.clone();
+		///     move |_| {
+		///         wnd.hwnd().RegisterHotKey(
+		///             ID_HOTKEY, co::MOD::CONTROL | co::MOD::ALT, co::VK::CHAR_K)?;
+		///         Ok(0)
+		///     }
+		/// });
+		///
+		/// wnd.on().wm_hot_key(move |p: msg::wm::HotKey| {
+		///     if p.hotkey_id == ID_HOTKEY {
+		///         println!("Ctrl+Alt+K pressed.");
+		///     }
+		///     Ok(())
+		/// });
+		///
+		/// wnd.on().wm_destroy({
+		///     let wnd = wnd.clone();
+		///     move || {
+		///         wnd.hwnd().UnregisterHotKey(ID_HOTKEY)?;
+		///         Ok(())
+		///     }
+		/// });
+		/// ```
+	}
+
 	pub_fn_wm_withparm_noret! { wm_init_menu_popup, co::WM::INITMENUPOPUP, wm::InitMenuPopup;
 		/// [`WM_INITMENUPOPUP`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-initmenupopup)
 		/// message.
@@ -744,6 +917,57 @@ impl WindowEvents {
 		/// ```
 	}
 
+	/// [`WM_INPUT`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-input)
+	/// message.
+	///
+	/// Raw input must be enabled with
+	/// [`RegisterRawInputDevices`](crate::RegisterRawInputDevices), and the
+	/// data is read with
+	/// [`HRAWINPUT::GetRawInputData`](crate::prelude::user_Hrawinput::GetRawInputData).
+	///
+	/// After the closure runs,
+	/// [`DefWindowProc`](crate::prelude::user_Hwnd::DefWindowProc) is called,
+	/// so the system can perform the cleanup of the raw input data.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui, msg};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_create({
+	///     let wnd = wnd.clone();
+	///     move |_| -> w::AnyResult<i32> {
+	///         w::RegisterRawInputDevices(&[
+	///             w::RAWINPUTDEVICE {
+	///                 usUsagePage: 0x01, // generic desktop controls
+	///                 usUsage: 0x02, // mouse
+	///                 dwFlags: co::RIDEV::default(),
+	///                 hwndTarget: unsafe { wnd.hwnd().raw_copy() },
+	///             },
+	///         ])?;
+	///         Ok(0)
+	///     }
+	/// });
+	///
+	/// wnd.on().wm_input(move |p: msg::wm::Input| -> w::AnyResult<()> {
+	///     if let (_, w::HidKbMouse::Mouse(mouse)) = p.hrawinput.GetRawInputData()? {
+	///         println!("Delta: {}, {}", mouse.lLastX, mouse.lLastY);
+	///     }
+	///     Ok(())
+	/// });
+	/// ```
+	pub fn wm_input<F>(&self, func: F)
+		where F: Fn(wm::Input) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::INPUT, move |p| {
+			func(unsafe { wm::Input::from_generic_wm(p) })?;
+			Ok(WmRet::NotHandled) // DefWindowProc must be called for cleanup
+		});
+	}
+
 	pub_fn_wm_withparm_noret! { wm_key_down, co::WM::KEYDOWN, wm::KeyDown;
 		/// [`WM_KEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-keydown)
 		/// message.
@@ -824,6 +1048,36 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_menu_command, co::WM::MENUCOMMAND, wm::MenuCommand;
 		/// [`WM_MENUCOMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-menucommand)
 		/// message.
+		///
+		/// This message is sent instead of
+		/// [`wm_command`](crate::gui::events::WindowEvents::wm_command) only
+		/// for menus with the [`MNS::NOTIFYBYPOS`](crate::co::MNS::NOTIFYBYPOS)
+		/// style.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// let hmenu: w::HMENU; // initialized somewhere
+		/// # let hmenu = w::HMENU::NULL;
+		///
+		/// let mut mi = w::MENUINFO::default();
+		/// mi.fMask = co::MIM::STYLE | co::MIM::APPLYTOSUBMENUS;
+		/// mi.dwStyle = co::MNS::NOTIFYBYPOS;
+		/// hmenu.SetMenuInfo(&mi)?;
+		///
+		/// wnd.on().wm_menu_command(
+		///     move |p: msg::wm::MenuCommand| -> w::AnyResult<()> {
+		///         let text = p.hmenu.GetMenuString(w::IdPos::Pos(p.item_index))?;
+		///         println!("Clicked item {}: {}", p.item_index, text);
+		///         Ok(())
+		///     },
+		/// );
+		/// # w::SysResult::Ok(())
+		/// ```
 	}
 
 	pub_fn_wm_withparm_coret! { wm_menu_drag, co::WM::MENUDRAG, wm::MenuDrag, co::MND;
@@ -839,6 +1093,8 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_mouse_h_wheel, co::WM::MOUSEHWHEEL, wm::MouseHWheel;
 		/// [`WM_MOUSEHWHEEL`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousehwheel)
 		/// message.
+		///
+		/// A positive `wheel_distance` means the wheel was tilted to the right.
 	}
 
 	pub_fn_wm_withparm_noret! { wm_mouse_hover, co::WM::MOUSEHOVER, wm::MouseHover;
@@ -864,11 +1120,45 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_mouse_wheel, co::WM::MOUSEWHEEL, wm::MouseWheel;
 		/// [`WM_MOUSEWHEEL`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel)
 		/// message.
+		///
+		/// A positive `wheel_distance` means the wheel was rotated forward, away
+		/// from the user. Note that this message is sent to the focused window,
+		/// not to the window under the cursor.
+		///
+		/// # Examples
+		///
+		/// Zooming with Ctrl+wheel, and panning with Shift+wheel:
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_mouse_wheel(move |p: msg::wm::MouseWheel| -> w::AnyResult<()> {
+		///     let notches = p.wheel_distance as f32 / 120.0; // WHEEL_DELTA
+		///     if p.keys.has(co::MK::CONTROL) {
+		///         println!("Zoom by {} at {}", notches, p.coords);
+		///     } else if p.keys.has(co::MK::SHIFT) {
+		///         println!("Pan horizontally by {}", notches);
+		///     }
+		///     Ok(())
+		/// });
+		/// ```
 	}
 
-	pub_fn_wm_withparm_noret! { wm_moving, co::WM::MOVING, wm::Moving;
-		/// [`WM_MOVING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-moving)
-		/// message.
+	/// [`WM_MOVING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-moving)
+	/// message.
+	///
+	/// The `window_pos` rectangle can be modified to change the position of the
+	/// drag rectangle, in screen coordinates; `TRUE` is returned to the system.
+	pub fn wm_moving<F>(&self, func: F)
+		where F: Fn(wm::Moving) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::MOVING, move |p| {
+			func(unsafe { wm::Moving::from_generic_wm(p) })?;
+			Ok(WmRet::HandledWithRet(1)) // TRUE
+		});
 	}
 
 	pub_fn_wm_withparm_coret! { wm_nc_calc_size, co::WM::NCCALCSIZE, wm::NcCalcSize, co::WVR;
@@ -951,6 +1241,73 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_power_broadcast, co::WM::POWERBROADCAST, wm::PowerBroadcast;
 		/// [`WM_POWERBROADCAST`](https://learn.microsoft.com/en-us/windows/win32/power/wm-powerbroadcast)
 		/// message.
+		///
+		/// To receive power setting changes, the window must be registered with
+		/// [`HWND::RegisterPowerSettingNotification`](crate::prelude::user_Hwnd::RegisterPowerSettingNotification).
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_power_broadcast(move |p: msg::wm::PowerBroadcast| -> w::AnyResult<()> {
+		///     match p.event {
+		///         co::PBT::APMPOWERSTATUSCHANGE => {
+		///             let sps = w::GetSystemPowerStatus()?;
+		///             println!("AC: {}", sps.ACLineStatus == co::AC_LINE::ONLINE);
+		///         },
+		///         co::PBT::APMSUSPEND => println!("Suspending."),
+		///         co::PBT::APMRESUMEAUTOMATIC => println!("Resumed."),
+		///         _ => {},
+		///     }
+		///     Ok(())
+		/// });
+		/// ```
+	}
+
+	pub_fn_wm_withparm_boolret! { wm_query_end_session, co::WM::QUERYENDSESSION, wm::QueryEndSession;
+		/// [`WM_QUERYENDSESSION`](https://learn.microsoft.com/en-us/windows/win32/shutdown/wm-queryendsession)
+		/// message.
+		///
+		/// Return `true` to allow the session to end, or `false` to block it;
+		/// this works for dialog windows too, since the result is passed
+		/// through `DWLP_MSGRESULT`. When blocking, you should also call
+		/// [`HWND::ShutdownBlockReasonCreate`](crate::prelude::user_Hwnd::ShutdownBlockReasonCreate)
+		/// so the user knows why.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_query_end_session({
+		///     let wnd = wnd.clone();
+		///     move |_: msg::wm::QueryEndSession| -> w::AnyResult<bool> {
+		///         let has_unsaved_work = true;
+		///         if has_unsaved_work {
+		///             wnd.hwnd().ShutdownBlockReasonCreate("Saving your document...")?;
+		///             return Ok(false); // block the shutdown
+		///         }
+		///         Ok(true)
+		///     }
+		/// });
+		///
+		/// wnd.on().wm_end_session({
+		///     let wnd = wnd.clone();
+		///     move |p: msg::wm::EndSession| -> w::AnyResult<()> {
+		///         if !p.is_session_being_ended {
+		///             wnd.hwnd().ShutdownBlockReasonDestroy()?; // shutdown cancelled
+		///         }
+		///         Ok(())
+		///     }
+		/// });
+		/// ```
 	}
 
 	pub_fn_wm_noparm_boolret! { wm_query_open, co::WM::QUERYOPEN;
@@ -1038,9 +1395,50 @@ impl WindowEvents {
 		/// ```
 	}
 
-	pub_fn_wm_withparm_noret! { wm_sizing, co::WM::SIZING, wm::Sizing;
-		/// [`WM_SIZING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-sizing)
-		/// message.
+	/// [`WM_SIZING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-sizing)
+	/// message.
+	///
+	/// The `coords` rectangle can be modified to change the size and position
+	/// of the drag rectangle, in screen coordinates; `TRUE` is returned to the
+	/// system. Unlike
+	/// [`wm_get_min_max_info`](crate::gui::events::WindowEvents::wm_get_min_max_info),
+	/// this allows snapping or locking the aspect ratio during a live resize.
+	///
+	/// # Examples
+	///
+	/// Keeping a 16:9 aspect ratio, adjusting the edge being dragged:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui, msg};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_sizing(move |p: msg::wm::Sizing| -> w::AnyResult<()> {
+	///     let cx = p.coords.right - p.coords.left;
+	///     let cy = p.coords.bottom - p.coords.top;
+	///
+	///     match p.window_edge {
+	///         co::WMSZ::TOP | co::WMSZ::BOTTOM => {
+	///             p.coords.right = p.coords.left + cy * 16 / 9;
+	///         },
+	///         co::WMSZ::TOPLEFT | co::WMSZ::TOPRIGHT => {
+	///             p.coords.top = p.coords.bottom - cx * 9 / 16;
+	///         },
+	///         _ => {
+	///             p.coords.bottom = p.coords.top + cx * 9 / 16;
+	///         },
+	///     }
+	///     Ok(())
+	/// });
+	/// ```
+	pub fn wm_sizing<F>(&self, func: F)
+		where F: Fn(wm::Sizing) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::SIZING, move |p| {
+			func(unsafe { wm::Sizing::from_generic_wm(p) })?;
+			Ok(WmRet::HandledWithRet(1)) // TRUE
+		});
 	}
 
 	pub_fn_wm_withparm_noret! { wm_style_changed, co::WM::STYLECHANGED, wm::StyleChanged;
@@ -1106,11 +1504,70 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_window_pos_changed, co::WM::WINDOWPOSCHANGED, wm::WindowPosChanged;
 		/// [`WM_WINDOWPOSCHANGED`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-windowposchanged)
 		/// message.
+		///
+		/// Fired once for each change in the window position, size or Z order,
+		/// so it can be used instead of handling both
+		/// [`wm_move`](crate::gui::events::WindowEvents::wm_move) and
+		/// [`wm_size`](crate::gui::events::WindowEvents::wm_size).
+		///
+		/// Note that the `x` and `y` fields of [`WINDOWPOS`](crate::WINDOWPOS)
+		/// are meaningless if `flags` has
+		/// [`co::SWP::NOMOVE`](crate::co::SWP::NOMOVE), and so are `cx` and `cy`
+		/// if it has [`co::SWP::NOSIZE`](crate::co::SWP::NOSIZE).
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_window_pos_changed(
+		///     move |p: msg::wm::WindowPosChanged| -> w::AnyResult<()> {
+		///         let wp = p.windowpos;
+		///         if !wp.flags.has(co::SWP::NOMOVE) {
+		///             println!("Moved to {}x{}", wp.x, wp.y);
+		///         }
+		///         if !wp.flags.has(co::SWP::NOSIZE) {
+		///             println!("Resized to {}x{}", wp.cx, wp.cy);
+		///         }
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
 	}
 
 	pub_fn_wm_withparm_noret! { wm_window_pos_changing, co::WM::WINDOWPOSCHANGING, wm::WindowPosChanging;
 		/// [`WM_WINDOWPOSCHANGING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-windowposchanging)
 		/// message.
+		///
+		/// The proposed [`WINDOWPOS`](crate::WINDOWPOS) can be modified, what
+		/// is useful to snap the window to a given position. Like in
+		/// [`wm_window_pos_changed`](crate::gui::events::WindowEvents::wm_window_pos_changed),
+		/// check [`co::SWP::NOMOVE`](crate::co::SWP::NOMOVE) and
+		/// [`co::SWP::NOSIZE`](crate::co::SWP::NOSIZE) flags before using the
+		/// fields.
+		///
+		/// # Examples
+		///
+		/// Snapping the window to the left edge of the screen:
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_window_pos_changing(
+		///     move |p: msg::wm::WindowPosChanging| -> w::AnyResult<()> {
+		///         if !p.windowpos.flags.has(co::SWP::NOMOVE) && p.windowpos.x < 20 {
+		///             p.windowpos.x = 0;
+		///         }
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
 	}
 
 	pub_fn_wm_withparm_noret! { wm_wts_session_change, co::WM::WTSSESSION_CHANGE, wm::WtsSessionChange;
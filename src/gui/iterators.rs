@@ -222,30 +222,9 @@ impl<'a> Iterator for ListBoxSelItemIter<'a> {
 impl<'a> ListBoxSelItemIter<'a> {
 	#[must_use]
 	pub(in crate::gui) fn new(owner: &'a ListBox) -> Self {
-		let style: co::LBS = owner.hwnd().style().into();
-		let indexes = if style.has(co::LBS::EXTENDEDSEL) { // multiple selection?
-			let num_indexes = unsafe {
-				owner.hwnd()
-					.SendMessage(lb::GetSelCount {})
-			}.unwrap();
-
-			let mut indexes = vec![0; num_indexes as _];
-			unsafe {
-				owner.hwnd()
-					.SendMessage(lb::GetSelItems { buffer: &mut indexes })
-			}.unwrap();
-			indexes
-
-		} else {
-			match unsafe { owner.hwnd().SendMessage(lb::GetCurSel {}) } {
-				Some(index) => vec![index], // single selection: at max 1
-				None => Vec::<u32>::new(),
-			}
-		};
-
 		Self {
 			owner,
-			indexes,
+			indexes: owner.items().selected_indices(),
 			current: 0,
 			buffer: WString::new(),
 		}
@@ -419,3 +398,68 @@ impl<'a, T> TreeViewChildItemIter<'a, T> {
 		}
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// Walks all the items of the tree, in depth-first order.
+pub(in crate::gui) struct TreeViewAllItemIter<'a, T: 'static> {
+	owner: &'a TreeView<T>,
+	current: Option<TreeViewItem<'a, T>>,
+	first_call: bool,
+}
+
+impl<'a, T> Iterator for TreeViewAllItemIter<'a, T> {
+	type Item = TreeViewItem<'a, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let next_of = |relationship: co::TVGN, cur: Option<&TreeViewItem<'a, T>>| unsafe {
+			self.owner.hwnd()
+				.SendMessage(tvm::GetNextItem {
+					relationship,
+					hitem: cur.map(|tvi| tvi.htreeitem()),
+				})
+		}.map(|hitem| self.owner.items().get(&hitem));
+
+		self.current = if self.first_call {
+			self.first_call = false;
+			next_of(co::TVGN::ROOT, None)
+		} else {
+			match self.current.as_ref() {
+				None => None,
+				Some(cur) => match next_of(co::TVGN::CHILD, Some(cur)) {
+					Some(child) => Some(child),
+					None => { // no children, climb up until a next sibling is found
+						let mut climb = Some(TreeViewItem::new(
+							self.owner, unsafe { cur.htreeitem().raw_copy() }));
+						loop {
+							match climb {
+								None => break None,
+								Some(ref c) => match next_of(co::TVGN::NEXT, Some(c)) {
+									Some(sibling) => break Some(sibling),
+									None => climb = c.parent(),
+								},
+							}
+						}
+					},
+				},
+			}
+		};
+
+		self.current.as_ref()
+			.map(|tvi| TreeViewItem::new(
+				self.owner,
+				unsafe { tvi.htreeitem().raw_copy() },
+			))
+	}
+}
+
+impl<'a, T> TreeViewAllItemIter<'a, T> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a TreeView<T>) -> Self {
+		Self {
+			owner,
+			current: None,
+			first_call: true,
+		}
+	}
+}
@@ -8,12 +8,14 @@
 
 #![cfg(feature = "gui")]
 
+mod activity_scope;
 mod enums;
 mod iterators;
 mod native_controls;
 mod privs_gui;
 mod proc;
 mod traits_gui;
+mod wait_cursor;
 mod windows;
 
 pub mod events;
@@ -25,8 +27,10 @@ pub(in crate::gui) mod privs {
 	pub(in crate::gui) use super::windows::privs::*;
 }
 
+pub use activity_scope::{ActivityGuard, ActivityScope};
 pub use enums::*;
 pub use native_controls::decl::*;
+pub use wait_cursor::WaitCursor;
 pub use windows::decl::*;
 
 pub(crate) mod traits {
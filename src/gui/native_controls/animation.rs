@@ -0,0 +1,314 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of Animation
+	base: BaseNativeControl,
+	events: AnimationEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [animation](https://learn.microsoft.com/en-us/windows/win32/controls/animation-control-overview)
+/// control, which plays silent AVI clips – the classic Windows "busy"
+/// animation.
+///
+/// # Examples
+///
+/// Playing an AVI clip embedded in the resources of the executable:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// const IDR_BUSY_AVI: u16 = 101;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// let anim: gui::Animation;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let anim = gui::Animation::new(&wnd, gui::AnimationOpts::default());
+///
+/// anim.open(w::IdStr::Id(IDR_BUSY_AVI))?;
+/// anim.play(0, None, None)?; // play all frames indefinitely
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone)]
+pub struct Animation(Pin<Arc<Obj>>);
+
+unsafe impl Send for Animation {}
+
+impl AsRef<BaseNativeControl> for Animation {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for Animation {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Animation {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiNativeControl for Animation {}
+
+impl GuiNativeControlEvents<AnimationEvents> for Animation {
+	fn on(&self) -> &AnimationEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Animation {
+	/// Instantiates a new `Animation` object, to be created on the parent
+	/// window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create an `Animation` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: AnimationOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: AnimationEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(OptsResz::Wnd(&opts))?;
+			Ok(WmRet::NotHandled)
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `Animation` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create an `Animation` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: AnimationEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_init_dialog(move |_| {
+			self2.create(OptsResz::Dlg(resize_behavior))?;
+			Ok(false) // this return value is discarded
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts_resz: OptsResz<&AnimationOpts>) -> SysResult<()> {
+		match opts_resz {
+			OptsResz::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				self.0.base.create_window(
+					"SysAnimate32", None, pos, sz,
+					opts.window_ex_style,
+					opts.window_style | opts.animation_style.into(),
+				)?;
+			},
+			OptsResz::Dlg(_) => self.0.base.create_dlg()?,
+		}
+
+		self.0.base.parent()
+			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
+	}
+
+	/// Closes the current AVI clip, if any, by sending an
+	/// [`acm::Open`](crate::msg::acm::Open) message.
+	pub fn close(&self) {
+		unsafe {
+			self.hwnd().SendMessage(acm::Open {
+				hinstance: None,
+				avi: None,
+			})
+		}.unwrap(); // closing never fails
+	}
+
+	/// Returns whether the clip is currently playing by sending an
+	/// [`acm::IsPlaying`](crate::msg::acm::IsPlaying) message.
+	#[must_use]
+	pub fn is_playing(&self) -> bool {
+		unsafe { self.hwnd().SendMessage(acm::IsPlaying {}) }
+	}
+
+	/// Opens an AVI clip from the resources of the current executable by
+	/// sending an [`acm::Open`](crate::msg::acm::Open) message.
+	///
+	/// If the control has the [`co::ACS::AUTOPLAY`](crate::co::ACS::AUTOPLAY)
+	/// style, the clip starts playing right away.
+	pub fn open(&self, avi_res: IdStr) -> SysResult<()> {
+		let hinst = HINSTANCE::GetModuleHandle(None)?;
+		unsafe {
+			self.hwnd().SendMessage(acm::Open {
+				hinstance: Some(&hinst),
+				avi: Some(avi_res),
+			})
+		}
+	}
+
+	/// Opens an AVI clip from a file by sending an
+	/// [`acm::Open`](crate::msg::acm::Open) message.
+	///
+	/// If the control has the [`co::ACS::AUTOPLAY`](crate::co::ACS::AUTOPLAY)
+	/// style, the clip starts playing right away.
+	pub fn open_file(&self, avi_path: &str) -> SysResult<()> {
+		unsafe {
+			self.hwnd().SendMessage(acm::Open {
+				hinstance: None,
+				avi: Some(IdStr::from_str(avi_path)),
+			})
+		}
+	}
+
+	/// Plays the clip from frame `from` to frame `to` – or to the last frame,
+	/// if `None` – `repeat` times – or indefinitely, if `None` – by sending an
+	/// [`acm::Play`](crate::msg::acm::Play) message.
+	pub fn play(&self,
+		from: u16,
+		to: Option<u16>,
+		repeat: Option<u32>,
+	) -> SysResult<()>
+	{
+		unsafe {
+			self.hwnd().SendMessage(acm::Play { repeat, from, to })
+		}
+	}
+
+	/// Stops playing the clip by sending an
+	/// [`acm::Stop`](crate::msg::acm::Stop) message.
+	pub fn stop(&self) -> SysResult<()> {
+		unsafe { self.hwnd().SendMessage(acm::Stop {}) }
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create an [`Animation`](crate::gui::Animation)
+/// programmatically with [`Animation::new`](crate::gui::Animation::new).
+pub struct AnimationOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(80, 50)`.
+	pub size: (u32, u32),
+	/// Animation styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `ACS::CENTER | ACS::TRANSPARENT`.
+	pub animation_style: co::ACS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for AnimationOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (80, 50),
+			animation_style: co::ACS::CENTER | co::ACS::TRANSPARENT,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+impl ResizeBehavior for &AnimationOpts {
+	fn resize_behavior(&self) -> (Horz, Vert) {
+		self.resize_behavior
+	}
+}
+
+impl AutoCtrlId for AnimationOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
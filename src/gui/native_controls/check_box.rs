@@ -216,7 +216,7 @@ impl CheckBox {
 	pub fn set_check_state_and_trigger(&self, state: CheckState) {
 		self.set_check_state(state);
 		unsafe {
-			self.hwnd().GetParent().unwrap().SendMessage(
+			parent_of(self.hwnd()).unwrap().SendMessage(
 				wm::Command {
 					event: AccelMenuCtrl::Ctrl(
 						AccelMenuCtrlData {
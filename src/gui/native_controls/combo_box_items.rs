@@ -116,6 +116,51 @@ impl<'a> ComboBoxItems<'a> {
 			.map(|idx| self.text(idx))
 	}
 
+	/// Replaces all the items with the texts from the given iterator, returning
+	/// the number of items inserted.
+	///
+	/// Redrawing is suspended with [`wm::SetRedraw`](crate::msg::wm::SetRedraw)
+	/// during the operation, so the control is painted only once. The current
+	/// selection is reset. If the control has the
+	/// [`CBS::SORT`](crate::co::CBS::SORT) style, the items will be reordered.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_list: gui::ComboBox; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ComboBox::new(&wnd, gui::ComboBoxOpts::default());
+	///
+	/// let names = vec!["John".to_owned(), "Mary".to_owned()];
+	/// let n = my_list.items().set_from_iter(names.iter().filter(|n| n.len() > 3));
+	/// ```
+	pub fn set_from_iter<S, I>(&self, items: I) -> u32
+		where S: AsRef<str>,
+			I: IntoIterator<Item = S>,
+	{
+		let hwnd = self.owner.hwnd();
+		unsafe {
+			hwnd.SendMessage(wm::SetRedraw { can_redraw: false });
+			hwnd.SendMessage(cb::ResetContent {});
+		}
+
+		let mut count = 0;
+		for text in items.into_iter() {
+			unsafe {
+				hwnd.SendMessage(cb::AddString {
+					text: WString::from_str(text.as_ref()),
+				})
+			}.unwrap();
+			count += 1;
+		}
+
+		unsafe { hwnd.SendMessage(wm::SetRedraw { can_redraw: true }); }
+		hwnd.InvalidateRect(None, true).unwrap();
+		count
+	}
+
 	/// Retrieves the text at the given position, if any, by sending a
 	/// [`cb::GetLbText`](crate::msg::cb::GetLbText) message.
 	///
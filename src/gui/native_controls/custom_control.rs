@@ -0,0 +1,294 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of CustomControl
+	base: BaseNativeControl,
+	events: CustomControlEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Arbitrary child control, created from any window class – either a
+/// built-in system class or one registered by the application.
+///
+/// This is an escape hatch for native controls which are not wrapped by this
+/// library: the control is created along with the parent window, takes part
+/// in the parent layout, and its `WM_COMMAND` and `WM_NOTIFY` notifications
+/// can be handled through [`on`](crate::prelude::GuiNativeControlEvents::on).
+/// Anything else must be done through its [`HWND`](crate::HWND).
+///
+/// # Examples
+///
+/// Creating an
+/// [IP address control](https://learn.microsoft.com/en-us/windows/win32/controls/ip-address-controls):
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let mut icce = w::INITCOMMONCONTROLSEX::default();
+/// icce.icc = co::ICC::INTERNET_CLASSES;
+/// w::InitCommonControlsEx(&icce)?; // register the window class
+///
+/// let ip_addr = gui::CustomControl::new(
+///     &wnd,
+///     gui::CustomControlOpts {
+///         class_name: "SysIPAddress32".to_owned(),
+///         position: (10, 10),
+///         size: (150, 23),
+///         ..Default::default()
+///     },
+/// );
+///
+/// ip_addr.on().wm_notify(
+///     co::IPN::FIELDCHANGED,
+///     move |_| -> w::AnyResult<gui::WmRet> {
+///         println!("IP address changed.");
+///         Ok(gui::WmRet::HandledOk)
+///     },
+/// );
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone)]
+pub struct CustomControl(Pin<Arc<Obj>>);
+
+unsafe impl Send for CustomControl {}
+
+impl AsRef<BaseNativeControl> for CustomControl {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for CustomControl {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for CustomControl {}
+
+impl GuiChild for CustomControl {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiChildFocus for CustomControl {}
+
+impl GuiNativeControl for CustomControl {}
+
+impl GuiNativeControlEvents<CustomControlEvents> for CustomControl {
+	fn on(&self) -> &CustomControlEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl CustomControl {
+	/// Instantiates a new `CustomControl` object, to be created on the parent
+	/// window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `CustomControl` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: CustomControlOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: CustomControlEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(OptsResz::Wnd(&opts))?;
+			Ok(WmRet::NotHandled)
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `CustomControl` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `CustomControl` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: CustomControlEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_init_dialog(move |_| {
+			self2.create(OptsResz::Dlg(resize_behavior))?;
+			Ok(false) // return value is discarded
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts_resz: OptsResz<&CustomControlOpts>) -> SysResult<()> {
+		match opts_resz {
+			OptsResz::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				self.0.base.create_window(
+					&opts.class_name,
+					Some(&opts.text), pos, sz,
+					opts.window_ex_style,
+					opts.window_style,
+				)?;
+
+				unsafe {
+					self.hwnd().SendMessage(wm::SetFont {
+						hfont: ui_font(),
+						redraw: true,
+					});
+				}
+			},
+			OptsResz::Dlg(_) => self.0.base.create_dlg()?,
+		}
+
+		self.0.base.parent()
+			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`CustomControl`](crate::gui::CustomControl)
+/// programmatically with
+/// [`CustomControl::new`](crate::gui::CustomControl::new).
+pub struct CustomControlOpts {
+	/// Window class name of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// The class must be registered before the parent window is created –
+	/// for common controls, this means
+	/// [`InitCommonControlsEx`](crate::InitCommonControlsEx) must have been
+	/// called with the appropriate flags.
+	///
+	/// Defaults to an empty string, which will fail; you must always set it.
+	pub class_name: String,
+	/// Text of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub text: String,
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(100, 23)`.
+	pub size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Control-specific styles must be added here, converted to
+	/// [`co::WS`](crate::co::WS).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for CustomControlOpts {
+	fn default() -> Self {
+		Self {
+			class_name: "".to_owned(),
+			text: "".to_owned(),
+			position: (0, 0),
+			size: (100, 23),
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+impl ResizeBehavior for &CustomControlOpts {
+	fn resize_behavior(&self) -> (Horz, Vert) {
+		self.resize_behavior
+	}
+}
+
+impl AutoCtrlId for CustomControlOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
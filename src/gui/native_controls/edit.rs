@@ -176,6 +176,13 @@ impl Edit {
 			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
 	}
 
+	/// Tells whether the last edit operation can be undone by sending an
+	/// [`em::CanUndo`](crate::msg::em::CanUndo) message.
+	#[must_use]
+	pub fn can_undo(&self) -> bool {
+		unsafe { self.hwnd().SendMessage(em::CanUndo {}) }
+	}
+
 	/// Hides any balloon tip by sending an
 	/// [`em::HideBalloonTip`](crate::msg::em::HideBalloonTip) message.
 	pub fn hide_balloon_tip(&self) {
@@ -209,6 +216,45 @@ impl Edit {
 		unsafe { self.hwnd().SendMessage(em::SetLimitText { max_chars }); }
 	}
 
+	/// Returns the zero-based index of the line which contains the given
+	/// character index by sending an
+	/// [`em::LineFromChar`](crate::msg::em::LineFromChar) message.
+	#[must_use]
+	pub fn line_from_char(&self, char_index: u32) -> u32 {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::LineFromChar { char_index: Some(char_index) })
+		}
+	}
+
+	/// Returns the character index of the first character of the given line by
+	/// sending an [`em::LineIndex`](crate::msg::em::LineIndex) message.
+	///
+	/// Returns `None` if the line index is greater than the number of lines.
+	///
+	/// # Examples
+	///
+	/// Retrieving the line and column of the caret:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_edit: gui::Edit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_edit = gui::Edit::new(&wnd, gui::EditOpts::default());
+	///
+	/// let (_, caret) = my_edit.selection();
+	/// let line = my_edit.line_from_char(caret as _);
+	/// let col = caret as u32 - my_edit.line_index(line).unwrap();
+	/// ```
+	#[must_use]
+	pub fn line_index(&self, line: u32) -> Option<u32> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::LineIndex { line_index: Some(line) })
+		}
+	}
+
 	/// Returns the number of lines by sending an
 	/// [`em::GetLineCount`](crate::msg::em::GetLineCount) message.
 	#[must_use]
@@ -216,6 +262,66 @@ impl Edit {
 		unsafe { self.hwnd().SendMessage(em::GetLineCount {}) }
 	}
 
+	/// Replaces the selected text – or inserts at the caret position, if there
+	/// is no selection – by sending an
+	/// [`em::ReplaceSel`](crate::msg::em::ReplaceSel) message. The operation
+	/// can be undone.
+	pub fn replace_selection(&self, text: &str) {
+		unsafe {
+			self.hwnd().SendMessage(em::ReplaceSel {
+				can_be_undone: true,
+				replacement_text: WString::from_str(text),
+			});
+		}
+	}
+
+	/// Scrolls the caret into view by sending an
+	/// [`em::ScrollCaret`](crate::msg::em::ScrollCaret) message.
+	pub fn scroll_caret(&self) {
+		unsafe { self.hwnd().SendMessage(em::ScrollCaret {}); }
+	}
+
+	/// Returns the zero-based indexes of the first selected character and the
+	/// first character after the selection by sending an
+	/// [`em::GetSel`](crate::msg::em::GetSel) message.
+	///
+	/// If there is no selection, both values are the caret position.
+	///
+	/// # Examples
+	///
+	/// Highlighting the next occurrence of a word:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_edit: gui::Edit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_edit = gui::Edit::new(&wnd, gui::EditOpts::default());
+	///
+	/// let text: Vec<u16> = my_edit.text().encode_utf16().collect();
+	/// let word: Vec<u16> = "foo".encode_utf16().collect();
+	/// let (_, from) = my_edit.selection();
+	///
+	/// if let Some(pos) = text[from as usize..].windows(word.len())
+	///     .position(|chunk| chunk == word)
+	/// {
+	///     let start = from + pos as i32;
+	///     my_edit.set_selection(start, start + word.len() as i32);
+	///     my_edit.scroll_caret();
+	/// }
+	/// ```
+	#[must_use]
+	pub fn selection(&self) -> (i32, i32) {
+		let (mut start, mut end) = (0u32, 0u32);
+		unsafe {
+			self.hwnd().SendMessage(em::GetSel {
+				first_index: Some(&mut start),
+				past_last_index: Some(&mut end),
+			});
+		}
+		(start as _, end as _)
+	}
+
 	/// Sets the font to the `Edit` by sending an
 	/// [`wm::SetFont`](crate::msg::wm::SetFont) message.
 	///
@@ -277,6 +383,50 @@ impl Edit {
 				.SendMessage(em::ShowBalloonTip { info: &info })
 		}.unwrap();
 	}
+
+	/// Retrieves the text into the given buffer by sending
+	/// [`wm::GetTextLength`](crate::msg::wm::GetTextLength) and
+	/// [`wm::GetText`](crate::msg::wm::GetText) messages, returning the number
+	/// of chars copied, not counting the terminating null.
+	///
+	/// The buffer is reallocated only if it's too small, so it can be reused
+	/// across calls, avoiding allocations when reading large texts repeatedly.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let log_view: gui::Edit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let log_view = gui::Edit::new(&wnd, gui::EditOpts::default());
+	///
+	/// let mut buf = w::WString::new();
+	///
+	/// let num_chars = log_view.text_into(&mut buf);
+	/// let last_lines = &buf.as_slice()[num_chars.saturating_sub(1000)..num_chars];
+	/// ```
+	pub fn text_into(&self, buf: &mut WString) -> usize {
+		let len = unsafe { self.hwnd().SendMessage(wm::GetTextLength {}) } as usize;
+		if buf.buf_len() < len + 1 { // plus terminating null
+			*buf = WString::new_alloc_buf(
+				std::cmp::max(len + 1, buf.buf_len() + buf.buf_len() / 2)); // room to grow
+		}
+
+		let num_chars = unsafe {
+			self.hwnd().SendMessage(wm::GetText { buffer: buf.as_mut_slice() }) // also writes the terminating null
+		};
+		num_chars as _
+	}
+
+	/// Undoes the last edit operation by sending an
+	/// [`em::Undo`](crate::msg::em::Undo) message.
+	///
+	/// The undo buffer of the native edit control has a single level, so
+	/// calling this method again redoes the operation.
+	pub fn undo(&self) -> SysResult<()> {
+		unsafe { self.hwnd().SendMessage(em::Undo {}) }
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -0,0 +1,304 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of HotKey
+	base: BaseNativeControl,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [hot key](https://learn.microsoft.com/en-us/windows/win32/controls/hot-key-controls)
+/// control, which lets the user enter a key combination.
+///
+/// The chosen combination can be registered as a global hotkey with
+/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey), which
+/// will then fire the
+/// [`wm_hot_key`](crate::gui::events::WindowEvents::wm_hot_key) event.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// const ID_HOTKEY: i32 = 1;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// let hot_key: gui::HotKey;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let hot_key = gui::HotKey::new(&wnd, gui::HotKeyOpts::default());
+///
+/// let (vkey, modifiers) = hot_key.hotkey_mod();
+/// wnd.hwnd().UnregisterHotKey(ID_HOTKEY).ok(); // previous one, if any
+/// wnd.hwnd().RegisterHotKey(ID_HOTKEY, modifiers, vkey)?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone)]
+pub struct HotKey(Pin<Arc<Obj>>);
+
+unsafe impl Send for HotKey {}
+
+impl AsRef<BaseNativeControl> for HotKey {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for HotKey {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for HotKey {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiNativeControl for HotKey {}
+
+impl HotKey {
+	/// Instantiates a new `HotKey` object, to be created on the parent window
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `HotKey` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: HotKeyOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(OptsResz::Wnd(&opts))?;
+			Ok(WmRet::NotHandled)
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `HotKey` object, to be loaded from a dialog resource
+	/// with [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `HotKey` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_init_dialog(move |_| {
+			self2.create(OptsResz::Dlg(resize_behavior))?;
+			Ok(false) // this return value is discarded
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts_resz: OptsResz<&HotKeyOpts>) -> SysResult<()> {
+		match opts_resz {
+			OptsResz::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				self.0.base.create_window(
+					"msctls_hotkey32", None, pos, sz,
+					opts.window_ex_style,
+					opts.window_style,
+				)?;
+
+				unsafe {
+					self.hwnd().SendMessage(wm::SetFont {
+						hfont: ui_font(),
+						redraw: true,
+					});
+				}
+			},
+			OptsResz::Dlg(_) => self.0.base.create_dlg()?,
+		}
+
+		self.0.base.parent()
+			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
+	}
+
+	/// Retrieves the current key combination by sending an
+	/// [`hkm::GetHotKey`](crate::msg::hkm::GetHotKey) message.
+	#[must_use]
+	pub fn hotkey(&self) -> (co::VK, co::HOTKEYF) {
+		unsafe { self.hwnd().SendMessage(hkm::GetHotKey {}) }
+	}
+
+	/// Retrieves the current key combination, just like
+	/// [`hotkey`](crate::gui::HotKey::hotkey), but with the modifiers converted
+	/// to [`co::MOD`](crate::co::MOD), ready to be passed to
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey).
+	#[must_use]
+	pub fn hotkey_mod(&self) -> (co::VK, co::MOD) {
+		let (vkey, hotkeyf) = self.hotkey();
+		let mut modifiers = co::MOD::default();
+		if hotkeyf.has(co::HOTKEYF::ALT) {
+			modifiers |= co::MOD::ALT;
+		}
+		if hotkeyf.has(co::HOTKEYF::CONTROL) {
+			modifiers |= co::MOD::CONTROL;
+		}
+		if hotkeyf.has(co::HOTKEYF::SHIFT) {
+			modifiers |= co::MOD::SHIFT;
+		}
+		(vkey, modifiers)
+	}
+
+	/// Sets the current key combination by sending an
+	/// [`hkm::SetHotKey`](crate::msg::hkm::SetHotKey) message.
+	pub fn set_hotkey(&self, vkey_code: co::VK, modifiers: co::HOTKEYF) {
+		unsafe {
+			self.hwnd().SendMessage(hkm::SetHotKey { vkey_code, modifiers });
+		}
+	}
+
+	/// Defines the invalid key combinations, and the modifiers to be used
+	/// instead of them, by sending an
+	/// [`hkm::SetRules`](crate::msg::hkm::SetRules) message.
+	///
+	/// # Examples
+	///
+	/// Forcing at least Ctrl+Alt:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let hot_key: gui::HotKey; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let hot_key = gui::HotKey::new(&wnd, gui::HotKeyOpts::default());
+	///
+	/// hot_key.set_rules(
+	///     co::HKCOMB::NONE | co::HKCOMB::S | co::HKCOMB::C | co::HKCOMB::A
+	///         | co::HKCOMB::SC | co::HKCOMB::SA,
+	///     co::HOTKEYF::CONTROL | co::HOTKEYF::ALT,
+	/// );
+	/// ```
+	pub fn set_rules(&self,
+		invalid_combos: co::HKCOMB,
+		default_modifiers: co::HOTKEYF,
+	)
+	{
+		unsafe {
+			self.hwnd().SendMessage(hkm::SetRules {
+				invalid_combos,
+				default_modifiers,
+			});
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`HotKey`](crate::gui::HotKey) programmatically with
+/// [`HotKey::new`](crate::gui::HotKey::new).
+pub struct HotKeyOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(150, 23)`.
+	pub size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for HotKeyOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (150, 23),
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+impl ResizeBehavior for &HotKeyOpts {
+	fn resize_behavior(&self) -> (Horz, Vert) {
+		self.resize_behavior
+	}
+}
+
+impl AutoCtrlId for HotKeyOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
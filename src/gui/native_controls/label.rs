@@ -219,6 +219,12 @@ pub struct LabelOpts {
 	/// label styles to be
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
+	/// To truncate long texts with an ellipsis, add
+	/// [`SS::ENDELLIPSIS`](crate::co::SS::ENDELLIPSIS),
+	/// [`SS::PATHELLIPSIS`](crate::co::SS::PATHELLIPSIS) or
+	/// [`SS::WORDELLIPSIS`](crate::co::SS::WORDELLIPSIS), and set a fixed
+	/// [`size`](crate::gui::LabelOpts::size).
+	///
 	/// Defaults to `SS::LEFT | SS:NOTIFY`.
 	pub label_style: co::SS,
 	/// Window styles to be
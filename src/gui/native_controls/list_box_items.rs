@@ -1,3 +1,4 @@
+use crate::co;
 use crate::decl::*;
 use crate::gui::{*, iterators::*};
 use crate::msg::*;
@@ -76,6 +77,34 @@ impl<'a> ListBoxItems<'a> {
 		}.unwrap();
 	}
 
+	/// Searches for the first item whose text begins with `prefix`, which is
+	/// case-insensitive, by sending an
+	/// [`lb::FindString`](crate::msg::lb::FindString) message.
+	#[must_use]
+	pub fn find_string(&self, prefix: &str) -> Option<u32> {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::FindString {
+					preceding_index: None,
+					text: WString::from_str(prefix),
+				})
+		}
+	}
+
+	/// Retrieves the user-defined value associated with the item by sending an
+	/// [`lb::GetItemData`](crate::msg::lb::GetItemData) message.
+	///
+	/// The raw value is returned: if `index` is invalid, the result is
+	/// `usize::MAX` (`LB_ERR`), which can't be told apart from an item whose
+	/// value is `usize::MAX` itself.
+	#[must_use]
+	pub fn item_data(&self, index: u32) -> usize {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::GetItemData { index })
+		}.map_or(usize::MAX, |data| data as _) // LB_ERR is -1
+	}
+
 	/// Returns an iterator over the texts.
 	///
 	/// # Examples
@@ -128,6 +157,132 @@ impl<'a> ListBoxItems<'a> {
 		}.unwrap()
 	}
 
+	/// Retrieves the indexes of the selected items.
+	///
+	/// If the control has the [`LBS::MULTIPLESEL`](crate::co::LBS::MULTIPLESEL)
+	/// or [`LBS::EXTENDEDSEL`](crate::co::LBS::EXTENDEDSEL) styles, sends
+	/// [`lb::GetSelCount`](crate::msg::lb::GetSelCount) and
+	/// [`lb::GetSelItems`](crate::msg::lb::GetSelItems) messages; otherwise
+	/// sends an [`lb::GetCurSel`](crate::msg::lb::GetCurSel) message.
+	///
+	/// # Examples
+	///
+	/// Moving the selected items to another list:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let src_list: gui::ListBox; // initialized somewhere
+	/// let dest_list: gui::ListBox;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let src_list = gui::ListBox::new(&wnd, gui::ListBoxOpts::default());
+	/// # let dest_list = gui::ListBox::new(&wnd, gui::ListBoxOpts::default());
+	///
+	/// for idx in src_list.items().selected_indices().iter().rev() {
+	///     let text = src_list.items().text(*idx);
+	///     let record_id = src_list.items().item_data(*idx);
+	///     src_list.items().delete(*idx);
+	///
+	///     dest_list.items().add(&[&text]);
+	///     let new_idx = dest_list.items().count() - 1;
+	///     dest_list.items().set_item_data(new_idx, record_id);
+	/// }
+	/// ```
+	#[must_use]
+	pub fn selected_indices(&self) -> Vec<u32> {
+		let hwnd = self.owner.hwnd();
+		if self.is_multi_sel() {
+			let num_indexes = unsafe { hwnd.SendMessage(lb::GetSelCount {}) }.unwrap();
+			let mut indexes = vec![0; num_indexes as _];
+			unsafe {
+				hwnd.SendMessage(lb::GetSelItems { buffer: &mut indexes })
+			}.unwrap();
+			indexes
+		} else {
+			match unsafe { hwnd.SendMessage(lb::GetCurSel {}) } {
+				Some(index) => vec![index], // single selection: at max 1
+				None => Vec::<u32>::new(),
+			}
+		}
+	}
+
+	/// Replaces all the items with the texts from the given iterator, returning
+	/// the number of items inserted.
+	///
+	/// Redrawing is suspended with [`wm::SetRedraw`](crate::msg::wm::SetRedraw)
+	/// during the operation, so the control is painted only once. The current
+	/// selection is reset. If the control has the
+	/// [`LBS::SORT`](crate::co::LBS::SORT) style, the items will be reordered.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_list: gui::ListBox; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ListBox::new(&wnd, gui::ListBoxOpts::default());
+	///
+	/// let names = vec!["John".to_owned(), "Mary".to_owned()];
+	/// let n = my_list.items().set_from_iter(names.iter().filter(|n| n.len() > 3));
+	/// ```
+	pub fn set_from_iter<S, I>(&self, items: I) -> u32
+		where S: AsRef<str>,
+			I: IntoIterator<Item = S>,
+	{
+		let hwnd = self.owner.hwnd();
+		unsafe {
+			hwnd.SendMessage(wm::SetRedraw { can_redraw: false });
+			hwnd.SendMessage(lb::ResetContent {});
+		}
+
+		let mut count = 0;
+		for text in items.into_iter() {
+			unsafe {
+				hwnd.SendMessage(lb::AddString {
+					text: WString::from_str(text.as_ref()),
+				})
+			}.unwrap();
+			count += 1;
+		}
+
+		unsafe { hwnd.SendMessage(wm::SetRedraw { can_redraw: true }); }
+		hwnd.InvalidateRect(None, true).unwrap();
+		count
+	}
+
+	/// Associates an user-defined value with the item – like the ID of a
+	/// database record – by sending an
+	/// [`lb::SetItemData`](crate::msg::lb::SetItemData) message.
+	pub fn set_item_data(&self, index: u32, data: usize) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::SetItemData { index, data: data as _ })
+		}.unwrap();
+	}
+
+	/// Selects or deselects the item.
+	///
+	/// If the control has the [`LBS::MULTIPLESEL`](crate::co::LBS::MULTIPLESEL)
+	/// or [`LBS::EXTENDEDSEL`](crate::co::LBS::EXTENDEDSEL) styles, sends an
+	/// [`lb::SetSel`](crate::msg::lb::SetSel) message; otherwise sends an
+	/// [`lb::SetCurSel`](crate::msg::lb::SetCurSel) message, which replaces
+	/// the current selection.
+	pub fn set_selected(&self, index: u32, selected: bool) {
+		let hwnd = self.owner.hwnd();
+		unsafe {
+			if self.is_multi_sel() {
+				hwnd.SendMessage(lb::SetSel { select: selected, index: Some(index) })
+			} else if selected {
+				hwnd.SendMessage(lb::SetCurSel { index: Some(index) })
+			} else if hwnd.SendMessage(lb::GetCurSel {}) == Some(index) {
+				hwnd.SendMessage(lb::SetCurSel { index: None })
+			} else {
+				Ok(()) // item is not selected
+			}
+		}.unwrap();
+	}
+
 	/// Retrieves the text at the given position, if any, by sending a
 	/// [`lb::GetText`](crate::msg::lb::GetText) message.
 	#[must_use]
@@ -146,4 +301,9 @@ impl<'a> ListBoxItems<'a> {
 
 		buf.to_string()
 	}
+
+	fn is_multi_sel(&self) -> bool {
+		let style: co::LBS = self.owner.hwnd().style().into();
+		style.has(co::LBS::MULTIPLESEL) || style.has(co::LBS::EXTENDEDSEL)
+	}
 }
@@ -295,6 +295,30 @@ impl<T> ListView<T> {
 			.map(|hmenu| hmenu.GetSubMenu(0).unwrap())
 	}
 
+	/// Enables or disables the group view by sending an
+	/// [`lvm::EnableGroupView`](crate::msg::lvm::EnableGroupView) message.
+	///
+	/// Items will be displayed in groups only after this method is called with
+	/// `true`. Groups are created with
+	/// [`insert_group`](crate::gui::ListView::insert_group).
+	pub fn enable_groups(&self, enable: bool) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(lvm::EnableGroupView { enable })
+		}.unwrap();
+	}
+
+	/// Retrieves the current extended list view styles by sending an
+	/// [`lvm::GetExtendedListViewStyle`](crate::msg::lvm::GetExtendedListViewStyle)
+	/// message.
+	#[must_use]
+	pub fn extended_style(&self) -> co::LVS_EX {
+		unsafe {
+			self.hwnd()
+				.SendMessage(lvm::GetExtendedListViewStyle {})
+		}
+	}
+
 	/// Retrieves a reference to one of the associated image lists by sending an
 	/// [`lvm::GetImageList`](crate::msg::lvm::GetImageList) message.
 	///
@@ -310,6 +334,46 @@ impl<T> ListView<T> {
 		})
 	}
 
+	/// Appends a new group by sending an
+	/// [`lvm::InsertGroupAt`](crate::msg::lvm::InsertGroupAt) message.
+	///
+	/// The `id` is chosen by you, and it's used to assign items to the group
+	/// with [`ListViewItems::add_to_group`](crate::gui::spec::ListViewItems::add_to_group).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let my_list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ListView::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// const TODAY: u32 = 1;
+	/// const YESTERDAY: u32 = 2;
+	///
+	/// my_list.enable_groups(true);
+	/// my_list.insert_group(TODAY, "Today", co::LVGA_HEADER::LEFT);
+	/// my_list.insert_group(YESTERDAY, "Yesterday", co::LVGA_HEADER::LEFT);
+	///
+	/// my_list.items().add_to_group(TODAY, &["report.txt"], None, ());
+	/// my_list.items().add_to_group(YESTERDAY, &["notes.txt"], None, ());
+	/// ```
+	pub fn insert_group(&self, id: u32, header: &str, align: co::LVGA_HEADER) {
+		let mut wheader = WString::from_str(header);
+
+		let mut lvg = LVGROUP::default();
+		lvg.mask = co::LVGF::HEADER | co::LVGF::GROUPID | co::LVGF::ALIGN;
+		lvg.iGroupId = id as _;
+		lvg.uAlign = unsafe { co::LVGA_FH::from_raw(align.raw()) };
+		lvg.set_pszHeader(Some(&mut wheader));
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(lvm::InsertGroupAt { index: None, group: &lvg })
+		}.unwrap();
+	}
+
 	/// Exposes the item methods.
 	#[must_use]
 	pub const fn items(&self) -> ListViewItems<'_, T> {
@@ -332,9 +396,57 @@ impl<T> ListView<T> {
 		}.unwrap();
 	}
 
+	/// Sets the state of a group, which was previously created with
+	/// [`insert_group`](crate::gui::ListView::insert_group), by sending an
+	/// [`lvm::SetGroupInfo`](crate::msg::lvm::SetGroupInfo) message.
+	///
+	/// Only the flags in `mask` are changed. For example, to make a group
+	/// collapsible and initially collapsed:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let my_list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// let both = co::LVGS::COLLAPSIBLE | co::LVGS::COLLAPSED;
+	/// my_list.set_group_state(1, both, both);
+	/// ```
+	pub fn set_group_state(&self, id: u32, mask: co::LVGS, state: co::LVGS) {
+		let mut lvg = LVGROUP::default();
+		lvg.mask = co::LVGF::STATE;
+		lvg.stateMask = mask;
+		lvg.state = state;
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(lvm::SetGroupInfo { id, info: &lvg })
+		}.unwrap();
+	}
+
 	/// Sets or unsets the given extended list view styles by sending an
 	/// [`lvm::SetExtendedListViewStyle`](crate::msg::lvm::SetExtendedListViewStyle)
 	/// message.
+	///
+	/// Only the styles passed in `ex_style` are affected; any other extended
+	/// styles are kept as they are.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// list.set_extended_style(true,
+	///     co::LVS_EX::FULLROWSELECT | co::LVS_EX::GRIDLINES);
+	/// list.set_extended_style(false, co::LVS_EX::GRIDLINES);
+	///
+	/// assert!(list.extended_style().has(co::LVS_EX::FULLROWSELECT));
+	/// ```
 	pub fn set_extended_style(&self, set: bool, ex_style: co::LVS_EX) {
 		unsafe {
 			self.hwnd().SendMessage(lvm::SetExtendedListViewStyle {
@@ -416,7 +528,7 @@ impl<T> ListView<T> {
 		};
 
 		hmenu.track_popup_menu_at_point(
-			menu_pos, &self.hwnd().GetParent().unwrap(), self.hwnd())
+			menu_pos, &parent_of(self.hwnd()).unwrap(), self.hwnd())
 			.unwrap();
 	}
 }
@@ -80,6 +80,28 @@ impl<'a, T> ListViewItem<'a, T> {
 		}
 	}
 
+	/// Checks or unchecks the item by sending an
+	/// [`lvm::SetItemState`](crate::msg::lvm::SetItemState) message.
+	///
+	/// The list view must have the
+	/// [`co::LVS_EX::CHECKBOXES`](crate::co::LVS_EX::CHECKBOXES) extended
+	/// style.
+	pub fn check(&self, set: bool) {
+		let mut lvi = LVITEM::default();
+		lvi.stateMask = co::LVIS::STATEIMAGEMASK;
+		lvi.state = unsafe {
+			co::LVIS::from_raw(if set { 2 } else { 1 } << 12) // INDEXTOSTATEIMAGEMASK
+		};
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lvm::SetItemState {
+					index: Some(self.index),
+					lvitem: &lvi,
+				})
+		}.unwrap();
+	}
+
 	/// Deletes the item by sending an
 	/// [`lvm::DeleteItem`](crate::msg::lvm::DeleteItem) message.
 	pub fn delete(&self) {
@@ -143,6 +165,24 @@ impl<'a, T> ListViewItem<'a, T> {
 		self.index
 	}
 
+	/// Tells if the item is checked by sending an
+	/// [`lvm::GetItemState`](crate::msg::lvm::GetItemState) message.
+	///
+	/// The list view must have the
+	/// [`co::LVS_EX::CHECKBOXES`](crate::co::LVS_EX::CHECKBOXES) extended
+	/// style.
+	#[must_use]
+	pub fn is_checked(&self) -> bool {
+		let state = unsafe {
+			self.owner.hwnd()
+				.SendMessage(lvm::GetItemState {
+					index: self.index,
+					mask: co::LVIS::STATEIMAGEMASK,
+				})
+		};
+		(state.raw() >> 12) == 2 // state image index: 1 unchecked, 2 checked
+	}
+
 	/// Tells if the item is the focused one by sending an
 	/// [`lvm::GetItemState`](crate::msg::lvm::GetItemState) message.
 	#[must_use]
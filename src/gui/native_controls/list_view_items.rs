@@ -57,6 +57,87 @@ impl<'a, T> ListViewItems<'a, T> {
 		icon_index: Option<u32>,
 		data: T,
 	) -> ListViewItem<'a, T>
+	{
+		self.insert(texts, icon_index, data, None)
+	}
+
+	/// Appends many items at once, each one with the texts of its columns, by
+	/// sending [`lvm::InsertItem`](crate::msg::lvm::InsertItem) messages.
+	/// Returns the first newly added item, or `None` if `rows` is empty.
+	///
+	/// Redrawing is suspended with [`wm::SetRedraw`](crate::msg::wm::SetRedraw)
+	/// during the operation, so the control is painted only once. The items
+	/// have no icon, and their object data is `T::default()`.
+	///
+	/// # Panics
+	///
+	/// Panics if any row is empty, or if the number of texts of any row is
+	/// greater than the number of columns.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// let first = my_list.items().add_many(&[
+	///     &["John", "42"],
+	///     &["Mary", "37"],
+	/// ]);
+	///
+	/// if let Some(first) = first {
+	///     first.select(true);
+	/// }
+	/// ```
+	pub fn add_many(&self,
+		rows: &[&[impl AsRef<str>]],
+	) -> Option<ListViewItem<'a, T>>
+		where T: Default,
+	{
+		let hwnd = self.owner.hwnd();
+		unsafe { hwnd.SendMessage(wm::SetRedraw { can_redraw: false }); }
+
+		let first = rows.iter()
+			.map(|texts| self.insert(texts, None, T::default(), None))
+			.fold(None, |first, item| first.or(Some(item)));
+
+		unsafe { hwnd.SendMessage(wm::SetRedraw { can_redraw: true }); }
+		hwnd.InvalidateRect(None, true).unwrap();
+		first
+	}
+
+	/// Appends a new item, assigned to the given group, by sending an
+	/// [`lvm::InsertItem`](crate::msg::lvm::InsertItem) message, and returns
+	/// the newly added item.
+	///
+	/// The group must have been previously created with
+	/// [`ListView::insert_group`](crate::gui::ListView::insert_group), and the
+	/// group view must be enabled with
+	/// [`ListView::enable_groups`](crate::gui::ListView::enable_groups).
+	///
+	/// # Panics
+	///
+	/// Panics if `texts` is empty, or if the number of texts is greater than
+	/// the number of columns.
+	pub fn add_to_group(&self,
+		group_id: u32,
+		texts: &[impl AsRef<str>],
+		icon_index: Option<u32>,
+		data: T,
+	) -> ListViewItem<'a, T>
+	{
+		self.insert(texts, icon_index, data, Some(group_id))
+	}
+
+	fn insert(&self,
+		texts: &[impl AsRef<str>],
+		icon_index: Option<u32>,
+		data: T,
+		group_id: Option<u32>,
+	) -> ListViewItem<'a, T>
 	{
 		if texts.is_empty() {
 			panic!("No texts passed when adding a ListView item.");
@@ -77,6 +158,11 @@ impl<'a, T> ListViewItems<'a, T> {
 			lvi.iImage = icon_index as _;
 		}
 
+		if let Some(group_id) = group_id { // will it belong to a group?
+			lvi.mask |= co::LVIF::GROUPID;
+			lvi.iGroupId = unsafe { co::LVI_GROUPID::from_raw(group_id as _) };
+		}
+
 		if TypeId::of::<T>() != TypeId::of::<()>() { // user defined the generic type
 			lvi.mask |= co::LVIF::PARAM;
 			let rc_data = Rc::new(RefCell::new(data));
@@ -215,6 +301,30 @@ impl<'a, T> ListViewItems<'a, T> {
 		ListViewItemIter::new(self.owner, co::LVNI::ALL)
 	}
 
+	/// Returns an iterator over the checked items.
+	///
+	/// The list view must have the
+	/// [`co::LVS_EX::CHECKBOXES`](crate::co::LVS_EX::CHECKBOXES) extended
+	/// style.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_list: gui::ListView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+	///
+	/// for item in my_list.items().iter_checked() {
+	///     println!("Checked item {}, text of the first column: {}",
+	///         item.index(), item.text(0));
+	/// }
+	/// ```
+	pub fn iter_checked(&self) -> impl Iterator<Item = ListViewItem<'a, T>> + 'a {
+		self.iter().filter(|item| item.is_checked())
+	}
+
 	/// Returns an iterator over the selected items.
 	///
 	/// # Examples
@@ -1,15 +1,18 @@
 //! Native Win32 controls.
 
+mod animation;
 mod base_native_control;
 mod button;
 mod check_box;
 mod combo_box_items;
 mod combo_box;
+mod custom_control;
 mod date_time_picker;
 mod edit;
 mod header_item;
 mod header_items;
 mod header;
+mod hot_key;
 mod label;
 mod list_box_items;
 mod list_box;
@@ -22,12 +25,15 @@ mod month_calendar;
 mod progress_bar;
 mod radio_button;
 mod radio_group;
+mod rebar;
 mod status_bar_part;
 mod status_bar_parts;
 mod status_bar;
+mod sys_link;
 mod tab_item;
 mod tab_items;
 mod tab;
+mod toolbar;
 mod trackbar;
 mod tree_view_item;
 mod tree_view_items;
@@ -39,12 +45,15 @@ pub(in crate::gui) mod privs {
 }
 
 pub mod decl {
+	pub use super::animation::{Animation, AnimationOpts};
 	pub use super::button::{Button, ButtonOpts};
 	pub use super::check_box::{CheckBox, CheckBoxOpts};
 	pub use super::combo_box::{ComboBox, ComboBoxOpts};
+	pub use super::custom_control::{CustomControl, CustomControlOpts};
 	pub use super::date_time_picker::{DateTimePicker, DateTimePickerOpts};
 	pub use super::edit::{Edit, EditOpts};
 	pub use super::header::{Header, HeaderOpts};
+	pub use super::hot_key::{HotKey, HotKeyOpts};
 	pub use super::label::{Label, LabelOpts};
 	pub use super::list_box::{ListBox, ListBoxOpts};
 	pub use super::list_view::{ListView, ListViewOpts};
@@ -52,8 +61,11 @@ pub mod decl {
 	pub use super::progress_bar::{ProgressBar, ProgressBarOpts};
 	pub use super::radio_button::{RadioButton, RadioButtonOpts};
 	pub use super::radio_group::RadioGroup;
+	pub use super::rebar::{Rebar, RebarOpts};
 	pub use super::status_bar::StatusBar;
+	pub use super::sys_link::{SysLink, SysLinkOpts};
 	pub use super::tab::{Tab, TabOpts};
+	pub use super::toolbar::{Toolbar, ToolbarOpts};
 	pub use super::trackbar::{Trackbar, TrackbarOpts};
 	pub use super::tree_view::{TreeView, TreeViewOpts};
 	pub use super::up_down::{UpDown, UpDownOpts};
@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::cell::UnsafeCell;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -12,6 +13,7 @@ use crate::prelude::*;
 struct Obj { // actual fields of MonthCalendar
 	base: BaseNativeControl,
 	events: MonthCalendarEvents,
+	bold_days: UnsafeCell<Vec<SYSTEMTIME>>,
 	_pin: PhantomPinned,
 }
 
@@ -81,6 +83,7 @@ impl MonthCalendar {
 				Obj {
 					base: BaseNativeControl::new(parent, ctrl_id),
 					events: MonthCalendarEvents::new(parent, ctrl_id),
+					bold_days: UnsafeCell::new(Vec::new()),
 					_pin: PhantomPinned,
 				},
 			),
@@ -92,6 +95,7 @@ impl MonthCalendar {
 			Ok(WmRet::NotHandled)
 		});
 
+		new_self.default_message_handlers(parent.as_ref(), ctrl_id);
 		new_self
 	}
 
@@ -115,6 +119,7 @@ impl MonthCalendar {
 				Obj {
 					base: BaseNativeControl::new(parent, ctrl_id),
 					events: MonthCalendarEvents::new(parent, ctrl_id),
+					bold_days: UnsafeCell::new(Vec::new()),
 					_pin: PhantomPinned,
 				},
 			),
@@ -126,6 +131,7 @@ impl MonthCalendar {
 			Ok(false) // this return value is discarded
 		});
 
+		new_self.default_message_handlers(parent.as_ref(), ctrl_id);
 		new_self
 	}
 
@@ -164,6 +170,19 @@ impl MonthCalendar {
 			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
 	}
 
+	fn default_message_handlers(&self, parent: &Base, ctrl_id: u16) {
+		let self2 = self.clone();
+		parent.before_user_on().wm_notify(ctrl_id, co::MCN::GETDAYSTATE, move |p| {
+			let nmds = unsafe { p.cast_nmhdr_mut::<NMDAYSTATE>() };
+			let start = nmds.stStart;
+			if let Some(states) = nmds.prgDayState() {
+				let bold_days = unsafe { &*self2.0.bold_days.get() };
+				fill_day_states(bold_days, &start, states);
+			}
+			Ok(WmRet::HandledOk)
+		});
+	}
+
 	/// Retrieves the currently selected date by sending a
 	/// [`mcm::GetCurSel`](crate::msg::mcm::GetCurSel) message.
 	pub fn date(&self) -> SYSTEMTIME {
@@ -175,6 +194,89 @@ impl MonthCalendar {
 		st
 	}
 
+	/// Retrieves the first and last dates of the current selection by sending
+	/// an [`mcm::GetSelRange`](crate::msg::mcm::GetSelRange) message.
+	///
+	/// # Panics
+	///
+	/// Panics if the control doesn't have the
+	/// [`co::MCS::MULTISELECT`](crate::co::MCS::MULTISELECT) style.
+	#[must_use]
+	pub fn selection_range(&self) -> (SYSTEMTIME, SYSTEMTIME) {
+		let mut limits = [SYSTEMTIME::default(), SYSTEMTIME::default()];
+		unsafe {
+			self.hwnd()
+				.SendMessage(mcm::GetSelRange { limits: &mut limits })
+		}.unwrap();
+		(limits[0], limits[1])
+	}
+
+	/// Sets the days to be displayed in bold, usually to indicate days with
+	/// appointments.
+	///
+	/// The days are kept by the control, and automatically supplied when the
+	/// [`MCN_GETDAYSTATE`](https://learn.microsoft.com/en-us/windows/win32/controls/mcn-getdaystate)
+	/// notification is received, as the user navigates through the months. If
+	/// the control is already created, the currently displayed months are
+	/// updated right away with an
+	/// [`mcm::SetDayState`](crate::msg::mcm::SetDayState) message.
+	///
+	/// Only the year, month and day of each date are considered.
+	///
+	/// # Panics
+	///
+	/// Panics if the control is created without the
+	/// [`co::MCS::DAYSTATE`](crate::co::MCS::DAYSTATE) style.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let mcal = gui::MonthCalendar::new(
+	///     &wnd,
+	///     gui::MonthCalendarOpts {
+	///         month_calendar_style: co::MCS::DAYSTATE,
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// let mut appointment = w::SYSTEMTIME::default();
+	/// appointment.wYear = 2024;
+	/// appointment.wMonth = 3;
+	/// appointment.wDay = 15;
+	///
+	/// mcal.set_bold_days(&[appointment]);
+	/// ```
+	pub fn set_bold_days(&self, days: &[SYSTEMTIME]) {
+		*unsafe { &mut *self.0.bold_days.get() } = days.to_vec(); // borrow ends here, before any message is sent
+
+		if *self.hwnd() == HWND::NULL {
+			return; // will be supplied on MCN_GETDAYSTATE
+		} else if !self.hwnd().style().has(co::MCS::DAYSTATE.into()) {
+			panic!("MonthCalendar needs the MCS::DAYSTATE style to display bold days.");
+		}
+
+		let mut limits = [SYSTEMTIME::default(), SYSTEMTIME::default()];
+		let num_months = unsafe {
+			self.hwnd()
+				.SendMessage(mcm::GetMonthRange {
+					scope: co::GMR::DAYSTATE,
+					limits: &mut limits,
+				})
+		};
+
+		let mut states = vec![MONTHDAYSTATE::default(); num_months as _];
+		fill_day_states(unsafe { &*self.0.bold_days.get() }, &limits[0], &mut states);
+		unsafe {
+			self.hwnd()
+				.SendMessage(mcm::SetDayState { months: &states })
+		}.unwrap();
+	}
+
 	/// Sets the currently selected date by sending a
 	/// [`mcm::SetCurSel`](crate::msg::mcm::SetCurSel) message.
 	pub fn set_date(&self, st: &SYSTEMTIME) {
@@ -183,6 +285,55 @@ impl MonthCalendar {
 				.SendMessage(mcm::SetCurSel { info: st })
 		}.unwrap();
 	}
+
+	/// Sets the maximum number of days that can be selected by sending an
+	/// [`mcm::SetMaxSelCount`](crate::msg::mcm::SetMaxSelCount) message.
+	///
+	/// # Panics
+	///
+	/// Panics if the control doesn't have the
+	/// [`co::MCS::MULTISELECT`](crate::co::MCS::MULTISELECT) style.
+	pub fn set_max_select_count(&self, max_days: u8) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(mcm::SetMaxSelCount { max_days })
+		}.unwrap();
+	}
+
+	/// Sets the first and last dates of the current selection by sending an
+	/// [`mcm::SetSelRange`](crate::msg::mcm::SetSelRange) message.
+	///
+	/// # Panics
+	///
+	/// Panics if the control doesn't have the
+	/// [`co::MCS::MULTISELECT`](crate::co::MCS::MULTISELECT) style, or if the
+	/// range exceeds the
+	/// [maximum selection count](crate::gui::MonthCalendar::set_max_select_count).
+	pub fn set_selection_range(&self, first: &SYSTEMTIME, last: &SYSTEMTIME) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(mcm::SetSelRange { limits: &[*first, *last] })
+		}.unwrap();
+	}
+}
+
+/// Marks the bold days in each month, starting from the month of `start`.
+fn fill_day_states(
+	bold_days: &[SYSTEMTIME],
+	start: &SYSTEMTIME,
+	states: &mut [MONTHDAYSTATE],
+)
+{
+	for (idx, state) in states.iter_mut().enumerate() {
+		let months = start.wMonth as u32 - 1 + idx as u32; // zero-based, since January of start year
+		let (year, month) = (start.wYear as u32 + months / 12, months % 12 + 1);
+
+		*state = MONTHDAYSTATE::default();
+		bold_days.iter()
+			.filter(|d| d.wYear as u32 == year && d.wMonth as u32 == month)
+			.filter(|d| (1..=31).contains(&d.wDay))
+			.for_each(|d| state.set_day(d.wDay as u8 - 1, true)); // bit 0 is day 1
+	}
 }
 
 //------------------------------------------------------------------------------
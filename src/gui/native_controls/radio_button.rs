@@ -179,7 +179,7 @@ impl RadioButton {
 	pub fn select_and_trigger(&self, selected: bool) -> SysResult<()> {
 		self.select(selected);
 		unsafe {
-			self.hwnd().GetParent()?.SendMessage(wm::Command {
+			parent_of(self.hwnd())?.SendMessage(wm::Command {
 				event: AccelMenuCtrl::Ctrl(
 					AccelMenuCtrlData {
 						notif_code: co::BN::CLICKED.into(),
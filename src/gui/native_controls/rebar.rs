@@ -0,0 +1,266 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of Rebar
+	base: BaseNativeControl,
+	events: RebarEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [rebar](https://learn.microsoft.com/en-us/windows/win32/controls/rebar-controls)
+/// control, which hosts other controls in movable and resizable bands.
+///
+/// The rebar is docked at the top of the parent window, and it's automatically
+/// resized when the parent is resized.
+///
+/// When a control is added to a band, it becomes a child of the rebar, which
+/// forwards `WM_COMMAND` and `WM_NOTIFY` messages to the parent window – so
+/// the events of the hosted control keep working. A hosted
+/// [`Toolbar`](crate::gui::Toolbar) should be created with
+/// [`co::CCS::NORESIZE`](crate::co::CCS::NORESIZE),
+/// [`co::CCS::NOPARENTALIGN`](crate::co::CCS::NOPARENTALIGN) and
+/// [`co::CCS::NODIVIDER`](crate::co::CCS::NODIVIDER) styles, so it doesn't try
+/// to dock itself.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let rebar = gui::Rebar::new(&wnd, gui::RebarOpts::default());
+/// let search = gui::Edit::new(&wnd, gui::EditOpts::default());
+///
+/// wnd.on().wm_create({
+///     let (rebar, search) = (rebar.clone(), search.clone());
+///     move |_| {
+///         rebar.add_band(search.hwnd(), "Search", 100, co::RBBS::GRIPPERALWAYS)?;
+///         Ok(0)
+///     }
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Rebar(Pin<Arc<Obj>>);
+
+unsafe impl Send for Rebar {}
+
+impl AsRef<BaseNativeControl> for Rebar {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for Rebar {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Rebar {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiNativeControl for Rebar {}
+
+impl GuiNativeControlEvents<RebarEvents> for Rebar {
+	fn on(&self) -> &RebarEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Rebar {
+	/// Instantiates a new `Rebar` object, to be created on the parent window
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Rebar` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: RebarOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: RebarEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.0.base.create_window( // may panic
+				"ReBarWindow32", None,
+				POINT::default(), SIZE::default(),
+				opts.window_ex_style,
+				opts.window_style | opts.rebar_style.into(),
+			)?;
+			Ok(WmRet::NotHandled)
+		});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_size(move |mut p| {
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				unsafe { self2.hwnd().SendMessage(p.as_generic_wm()); } // send WM_SIZE to rebar, so it resizes itself to fit parent
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	/// Adds a new band at the last position, hosting the given child control,
+	/// by sending an [`rb::InsertBand`](crate::msg::rb::InsertBand) message.
+	///
+	/// The child control becomes a child of the rebar. Its current size is used
+	/// as the initial band size, and its height is kept. The `min_width` is in
+	/// pixels, and it will be multiplied to match current system DPI. If `text`
+	/// is empty, the band has no title.
+	///
+	/// Must be called after the control is created.
+	pub fn add_band(&self,
+		hchild: &HWND,
+		text: &str,
+		min_width: u32,
+		style: co::RBBS,
+	) -> SysResult<()>
+	{
+		let rc_child = hchild.GetWindowRect()?;
+		let mut min_sz = SIZE::new(min_width as _, 0);
+		multiply_dpi_or_dtu(self.0.base.parent(), None, Some(&mut min_sz))?;
+
+		let mut wtext = WString::from_str(text);
+
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::STYLE | co::RBBIM::CHILD | co::RBBIM::CHILDSIZE
+			| co::RBBIM::SIZE | co::RBBIM::IDEALSIZE;
+		rbbi.fStyle = style;
+		rbbi.hwndChild = unsafe { hchild.raw_copy() };
+		rbbi.cxMinChild = min_sz.cx as _;
+		rbbi.cyMinChild = (rc_child.bottom - rc_child.top) as _;
+		rbbi.cx = std::cmp::max(rc_child.right - rc_child.left, min_sz.cx) as _;
+		rbbi.cxIdeal = rbbi.cx;
+		if !text.is_empty() {
+			rbbi.fMask |= co::RBBIM::TEXT;
+			rbbi.set_lpText(Some(&mut wtext));
+		}
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(rb::InsertBand { index: None, info: &rbbi })
+		}
+	}
+
+	/// Retrieves the number of bands by sending an
+	/// [`rb::GetBandCount`](crate::msg::rb::GetBandCount) message.
+	#[must_use]
+	pub fn band_count(&self) -> u32 {
+		unsafe { self.hwnd().SendMessage(rb::GetBandCount {}) }
+	}
+
+	/// Retrieves the height of the rebar, in pixels, by sending an
+	/// [`rb::GetBarHeight`](crate::msg::rb::GetBarHeight) message.
+	#[must_use]
+	pub fn bar_height(&self) -> u32 {
+		unsafe { self.hwnd().SendMessage(rb::GetBarHeight {}) }
+	}
+
+	/// Deletes the band by sending an
+	/// [`rb::DeleteBand`](crate::msg::rb::DeleteBand) message.
+	///
+	/// The hosted child control, if any, is not destroyed: it's hidden and
+	/// given back to the parent window of the rebar.
+	pub fn delete_band(&self, index: u32) -> SysResult<()> {
+		let mut rbbi = REBARBANDINFO::default();
+		rbbi.fMask = co::RBBIM::CHILD;
+		unsafe {
+			self.hwnd().SendMessage(rb::GetBandInfo { index, info: &mut rbbi })?;
+			self.hwnd().SendMessage(rb::DeleteBand { index })?;
+		}
+
+		if rbbi.hwndChild != HWND::NULL {
+			rbbi.hwndChild.ShowWindow(co::SW::HIDE);
+			rbbi.hwndChild.SetParent(self.0.base.parent().hwnd())?;
+		}
+		Ok(())
+	}
+
+	/// Shows or hides the band by sending an
+	/// [`rb::ShowBand`](crate::msg::rb::ShowBand) message.
+	pub fn show_band(&self, index: u32, show: bool) -> SysResult<()> {
+		unsafe { self.hwnd().SendMessage(rb::ShowBand { index, show }) }
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Rebar`](crate::gui::Rebar) programmatically with
+/// [`Rebar::new`](crate::gui::Rebar::new).
+pub struct RebarOpts {
+	/// Rebar styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `RBS::VARHEIGHT | RBS::BANDBORDERS`.
+	pub rebar_style: co::RBS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::CLIPSIBLINGS | WS::CLIPCHILDREN | CCS::NODIVIDER`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+}
+
+impl Default for RebarOpts {
+	fn default() -> Self {
+		Self {
+			rebar_style: co::RBS::VARHEIGHT | co::RBS::BANDBORDERS,
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::CLIPSIBLINGS
+				| co::WS::CLIPCHILDREN | co::CCS::NODIVIDER.into(),
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+		}
+	}
+}
+
+impl AutoCtrlId for RebarOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
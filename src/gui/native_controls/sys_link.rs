@@ -0,0 +1,284 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of SysLink
+	base: BaseNativeControl,
+	events: SysLinkEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [SysLink](https://learn.microsoft.com/en-us/windows/win32/controls/syslink-overview)
+/// control, which renders text with clickable hyperlinks written as `<a>`
+/// tags.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let link = gui::SysLink::new(
+///     &wnd,
+///     gui::SysLinkOpts {
+///         text: "<a href=\"https://example.com\">Learn more</a>".to_owned(),
+///         ..Default::default()
+///     },
+/// );
+///
+/// link.on().nm_click(
+///     move |p: &w::NMLINK| -> w::AnyResult<()> {
+///         println!("Clicked: {}", p.item.szUrl());
+///         Ok(())
+///     },
+/// );
+/// ```
+#[derive(Clone)]
+pub struct SysLink(Pin<Arc<Obj>>);
+
+unsafe impl Send for SysLink {}
+
+impl AsRef<BaseNativeControl> for SysLink {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for SysLink {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for SysLink {}
+
+impl GuiChild for SysLink {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiChildFocus for SysLink {}
+
+impl GuiNativeControl for SysLink {}
+
+impl GuiNativeControlEvents<SysLinkEvents> for SysLink {
+	fn on(&self) -> &SysLinkEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl SysLink {
+	/// Instantiates a new `SysLink` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `SysLink` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: SysLinkOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: SysLinkEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(OptsResz::Wnd(&opts))?;
+			Ok(WmRet::NotHandled)
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `SysLink` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `SysLink` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		Self::register_class(); // must happen before the dialog is created
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: SysLinkEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_init_dialog(move |_| {
+			self2.create(OptsResz::Dlg(resize_behavior))?;
+			Ok(false) // this return value is discarded
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts_resz: OptsResz<&SysLinkOpts>) -> SysResult<()> {
+		match opts_resz {
+			OptsResz::Wnd(opts) => {
+				Self::register_class();
+
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				self.0.base.create_window(
+					"SysLink", Some(&opts.text), pos, sz,
+					opts.window_ex_style,
+					opts.window_style | opts.link_style.into(),
+				)?;
+
+				unsafe {
+					self.hwnd().SendMessage(wm::SetFont {
+						hfont: ui_font(),
+						redraw: true,
+					});
+				}
+			},
+			OptsResz::Dlg(_) => self.0.base.create_dlg()?,
+		}
+
+		self.0.base.parent()
+			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
+	}
+
+	fn register_class() {
+		let mut icce = INITCOMMONCONTROLSEX::default();
+		icce.icc = co::ICC::LINK_CLASS;
+		InitCommonControlsEx(&icce)
+			.expect("SysLink window class could not be registered.");
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`SysLink`](crate::gui::SysLink) programmatically with
+/// [`SysLink::new`](crate::gui::SysLink::new).
+pub struct SysLinkOpts {
+	/// Text of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Links are written as `<a>` tags, optionally with `href` and `id`
+	/// attributes, which are reported in the
+	/// [`NMLINK`](crate::NMLINK) notifications.
+	///
+	/// Defaults to empty string.
+	pub text: String,
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(120, 20)`.
+	pub size: (u32, u32),
+	/// SysLink styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `LWS::TRANSPARENT`.
+	pub link_style: co::LWS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for SysLinkOpts {
+	fn default() -> Self {
+		Self {
+			text: "".to_owned(),
+			position: (0, 0),
+			size: (120, 20),
+			link_style: co::LWS::TRANSPARENT,
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+impl ResizeBehavior for &SysLinkOpts {
+	fn resize_behavior(&self) -> (Horz, Vert) {
+		self.resize_behavior
+	}
+}
+
+impl AutoCtrlId for SysLinkOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::cell::UnsafeCell;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -10,10 +11,12 @@ use crate::gui::{*, events::*, privs::*, spec::*};
 use crate::msg::*;
 use crate::prelude::*;
 
+type Page = (String, Box<dyn AsRef<WindowControl>>); // title and window
+
 struct Obj { // actual fields of Tab
 	base: BaseNativeControl,
 	events: TabEvents,
-	children: Vec<(String, Box<dyn AsRef<WindowControl>>)>,
+	children: UnsafeCell<Vec<Page>>,
 	_pin: PhantomPinned,
 }
 
@@ -22,6 +25,12 @@ struct Obj { // actual fields of Tab
 /// Native
 /// [tab](https://learn.microsoft.com/en-us/windows/win32/controls/tab-controls)
 /// control.
+///
+/// Each tab displays a [`WindowControl`](crate::gui::WindowControl), which is
+/// automatically shown when its tab is selected, hidden otherwise, and resized
+/// to fill the display area of the tab control whenever the parent window is
+/// resized. The pages can be passed in [`TabOpts::items`](crate::gui::TabOpts::items),
+/// or added later with [`add_page`](crate::gui::Tab::add_page).
 #[derive(Clone)]
 pub struct Tab(Pin<Arc<Obj>>);
 
@@ -83,7 +92,7 @@ impl Tab {
 				Obj {
 					base: BaseNativeControl::new(parent, ctrl_id),
 					events: TabEvents::new(parent, ctrl_id),
-					children,
+					children: UnsafeCell::new(children),
 					_pin: PhantomPinned,
 				},
 			),
@@ -119,7 +128,7 @@ impl Tab {
 				Obj {
 					base: BaseNativeControl::new(parent, ctrl_id),
 					events: TabEvents::new(parent, ctrl_id),
-					children: items,
+					children: UnsafeCell::new(items),
 					_pin: PhantomPinned,
 				},
 			),
@@ -163,7 +172,7 @@ impl Tab {
 			OptsResz::Dlg(_) => self.0.base.create_dlg()?,
 		}
 
-		self.0.children.iter()
+		self.children().iter()
 			.for_each(|(text, _)| unsafe { self.items().add(text); }); // add the tabs
 		self.display_tab(0)?; // 1st tab selected by default
 
@@ -180,6 +189,16 @@ impl Tab {
 			Ok(WmRet::HandledOk)
 		});
 
+		let self2 = self.clone();
+		parent.before_user_on().wm_size(move |p| { // runs after the layout arranger
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				if let Some(sel_item) = self2.items().selected() {
+					self2.display_tab(sel_item.index())?; // fit the page to the new tab size
+				}
+			}
+			Ok(())
+		});
+
 		let self2 = self.clone();
 		parent.after_user_on().wm_destroy(move || {
 			self2.image_list().map(|hil| {
@@ -189,17 +208,21 @@ impl Tab {
 		});
 	}
 
+	#[must_use]
+	fn children(&self) -> &Vec<Page> {
+		unsafe { &*self.0.children.get() }
+	}
+
 	fn display_tab(&self, index: u32) -> SysResult<()> {
-		self.0.children.iter()
+		self.children().iter()
 			.enumerate()
 			.filter(|(i, _)| *i != index as usize)
 			.for_each(|(_, (_, item))| {
 				item.as_ref().as_ref().hwnd().ShowWindow(co::SW::HIDE); // hide all others
 			});
 
-		if let Some((_, item)) = self.0.children.get(index as usize) {
-			let mut rc = self.hwnd()
-				.GetParent()?
+		if let Some((_, item)) = self.children().get(index as usize) {
+			let mut rc = parent_of(self.hwnd())?
 				.ScreenToClientRc(self.hwnd().GetWindowRect()?)?;
 			unsafe {
 				self.hwnd().SendMessage(tcm::AdjustRect {
@@ -208,16 +231,83 @@ impl Tab {
 				});
 			}
 			item.as_ref().as_ref().hwnd().SetWindowPos(
-				HwndPlace::None,
+				HwndPlace::Place(co::HWND_PLACE::TOP), // page must be above the tab control
 				POINT::new(rc.left, rc.top),
 				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
-				co::SWP::NOZORDER | co::SWP::SHOWWINDOW,
+				co::SWP::SHOWWINDOW,
 			)?;
 		}
 
 		Ok(())
 	}
 
+	/// Appends a new tab which displays the given window, and returns the newly
+	/// added item.
+	///
+	/// If the window isn't a sibling of the tab control, it's reparented to the
+	/// tab control's parent. The window is then managed just like the ones
+	/// passed in [`TabOpts::items`](crate::gui::TabOpts::items): it's shown when
+	/// the tab is selected, and resized along with the tab control.
+	///
+	/// Must be called after the control is created.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// #[derive(Clone)]
+	/// struct SettingsPage {
+	///     wnd: gui::WindowControl,
+	/// }
+	///
+	/// impl AsRef<gui::WindowControl> for SettingsPage {
+	///     fn as_ref(&self) -> &gui::WindowControl {
+	///         &self.wnd
+	///     }
+	/// }
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let tab = gui::Tab::new(&wnd, gui::TabOpts::default());
+	/// let page = SettingsPage {
+	///     wnd: gui::WindowControl::new(&wnd, gui::WindowControlOpts::default()),
+	/// };
+	///
+	/// wnd.on().wm_create({
+	///     let (tab, page) = (tab.clone(), page.clone());
+	///     move |_| {
+	///         tab.add_page("Settings", page.clone())?;
+	///         Ok(0)
+	///     }
+	/// });
+	/// ```
+	pub fn add_page(&self,
+		title: &str,
+		page: impl AsRef<WindowControl> + 'static,
+	) -> SysResult<TabItem<'_>>
+	{
+		let hpage = unsafe { page.as_ref().hwnd().raw_copy() };
+		let hparent = parent_of(self.hwnd())?;
+		if hpage.GetParent()?.as_ref() != Some(&hparent) {
+			hpage.SetParent(&hparent)?;
+		}
+
+		unsafe { &mut *self.0.children.get() }
+			.push((title.to_owned(), Box::new(page)));
+		let new_item = unsafe { self.items().add(title) };
+
+		match self.items().selected() {
+			Some(sel_item) => self.display_tab(sel_item.index())?,
+			None => { // first tab, which is not selected by default
+				unsafe { self.hwnd().SendMessage(tcm::SetCurSel { index: new_item.index() }); }
+				self.display_tab(new_item.index())?;
+			},
+		}
+		Ok(new_item)
+	}
+
 	/// Retrieves a reference to the associated image list by sending a
 	/// [`tcm::GetImageList`](crate::msg::tcm::GetImageList) message.
 	///
@@ -295,6 +385,10 @@ pub struct TabOpts {
 	/// Tab styles to be
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
+	/// With [`TCS::OWNERDRAWFIXED`](crate::co::TCS::OWNERDRAWFIXED), the tabs
+	/// must be painted in the
+	/// [`wm_draw_item`](crate::gui::events::TabEvents::wm_draw_item) event.
+	///
 	/// Defaults to `TCS::NoValue`.
 	pub tab_style: co::TCS,
 	/// Extended tab styles to be
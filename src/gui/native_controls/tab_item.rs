@@ -61,6 +61,22 @@ impl<'a> TabItem<'a> {
 		tci.lParam
 	}
 
+	/// Retrieves the bounding rectangle of the item, relative to the tab
+	/// control, by sending a
+	/// [`tcm::GetItemRect`](crate::msg::tcm::GetItemRect) message.
+	#[must_use]
+	pub fn rect(&self) -> RECT {
+		let mut rc = RECT::default();
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tcm::GetItemRect {
+					index: self.index,
+					rect: &mut rc,
+				})
+		}.unwrap();
+		rc
+	}
+
 	/// Sets the user-defined value by sending an
 	/// [`lvm::SetItem`](crate::msg::lvm::SetItem) message.
 	pub fn set_lparam(&self, lparam: isize) {
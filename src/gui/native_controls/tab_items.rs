@@ -87,6 +87,45 @@ impl<'a> TabItems<'a> {
 		}.map(|i| self.get(i))
 	}
 
+	/// Retrieves the item at the specified position by sending a
+	/// [`tcm::HitTest`](crate::msg::tcm::HitTest) message.
+	///
+	/// `coords` must be relative to the tab control.
+	///
+	/// # Examples
+	///
+	/// Checking whether a click hit the rightmost 16 pixels of a tab, where an
+	/// owner-drawn close button would be:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let tab: gui::Tab; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let tab = gui::Tab::new(&wnd, gui::TabOpts::default());
+	///
+	/// let coords = tab.hwnd().ScreenToClient(w::GetCursorPos()?)?;
+	/// if let Some(item) = tab.items().hit_test(coords) {
+	///     let rc = item.rect();
+	///     if coords.x >= rc.right - 16 {
+	///         println!("Close tab {}", item.index());
+	///     }
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn hit_test(&self, coords: POINT) -> Option<TabItem<'a>> {
+		let mut tchti = TCHITTESTINFO {
+			pt: coords,
+			..Default::default()
+		};
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tcm::HitTest { info: &mut tchti })
+		}.map(|index| self.get(index))
+	}
+
 	/// Returns the selected item by sending a
 	/// [`tcm::GetCurSel`](crate::msg::tcm::GetCurSel) message.
 	#[must_use]
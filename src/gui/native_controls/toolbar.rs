@@ -0,0 +1,357 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::gui::{*, events::*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of Toolbar
+	base: BaseNativeControl,
+	events: ToolbarEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [toolbar](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-control-reference)
+/// control.
+///
+/// The toolbar is docked at the top of the parent window, and it's
+/// automatically resized when the parent is resized.
+///
+/// When a button is clicked, a `WM_COMMAND` message is sent to the parent
+/// window, with the command ID of the button. It can be handled just like a
+/// menu command, with
+/// [`wm_command_accel_menu`](crate::gui::events::WindowEvents::wm_command_accel_menu).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, gui};
+///
+/// const ID_SAVE: u16 = 1001;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+///
+/// wnd.on().wm_create({
+///     let toolbar = toolbar.clone();
+///     move |_| {
+///         toolbar.add_button(ID_SAVE, None, "Save", co::BTNS::AUTOSIZE)?;
+///         Ok(0)
+///     }
+/// });
+///
+/// wnd.on().wm_command_accel_menu(ID_SAVE, move || {
+///     println!("Save clicked.");
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Toolbar(Pin<Arc<Obj>>);
+
+unsafe impl Send for Toolbar {}
+
+impl AsRef<BaseNativeControl> for Toolbar {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for Toolbar {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Toolbar {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiNativeControl for Toolbar {}
+
+impl GuiNativeControlEvents<ToolbarEvents> for Toolbar {
+	fn on(&self) -> &ToolbarEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Toolbar {
+	/// Instantiates a new `Toolbar` object, to be created on the parent window
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Toolbar` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: ToolbarOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: ToolbarEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(&opts)?;
+			Ok(WmRet::NotHandled)
+		});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_size(move |p| {
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				unsafe { self2.hwnd().SendMessage(tbm::AutoSize {}); } // fit the parent width
+			}
+			Ok(())
+		});
+
+		new_self.default_message_handlers(parent.as_ref());
+		new_self
+	}
+
+	fn create(&self, opts: &ToolbarOpts) -> SysResult<()> {
+		self.0.base.create_window( // may panic
+			"ToolbarWindow32", None,
+			POINT::default(), SIZE::default(),
+			opts.window_ex_style,
+			opts.window_style | opts.toolbar_style.into(),
+		)?;
+
+		unsafe {
+			self.hwnd().SendMessage(tbm::ButtonStructSize {
+				size: std::mem::size_of::<TBBUTTON>() as _,
+			});
+			if opts.toolbar_ex_style != co::TBSTYLE_EX::NoValue {
+				self.hwnd().SendMessage(tbm::SetExtendedStyle {
+					style: opts.toolbar_ex_style,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	fn default_message_handlers(&self, parent: &Base) {
+		let self2 = self.clone();
+		parent.after_user_on().wm_destroy(move || {
+			if let Some(hil) = self2.image_list() { // destroy the image list, if any
+				let _ = unsafe { ImageListDestroyGuard::new(hil.raw_copy()) };
+			}
+			Ok(())
+		});
+	}
+
+	/// Adds a new button by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message, then resizes
+	/// the toolbar.
+	///
+	/// The `image_index` is the zero-based index of the image in the
+	/// [image list](crate::gui::Toolbar::set_image_list); if `None`, the button
+	/// has no image. If `text` is empty, the button has no text.
+	///
+	/// Clicking the button will send a `WM_COMMAND` message with `cmd_id` to
+	/// the parent window. Use [`co::BTNS::DROPDOWN`](crate::co::BTNS::DROPDOWN)
+	/// for a split button, whose arrow will fire the
+	/// [`tbn_drop_down`](crate::gui::events::ToolbarEvents::tbn_drop_down)
+	/// event.
+	///
+	/// Must be called after the control is created.
+	pub fn add_button(&self,
+		cmd_id: u16,
+		image_index: Option<u32>,
+		text: &str,
+		style: co::BTNS,
+	) -> SysResult<()>
+	{
+		let mut idx_str = IdxStr::Str(WString::from_str(text)); // the toolbar keeps a copy
+
+		let mut tbb = TBBUTTON::default();
+		tbb.iBitmap = image_index.map_or(-2, |idx| idx as _); // I_IMAGENONE
+		tbb.idCommand = cmd_id as _;
+		tbb.fsState = co::TBSTATE::ENABLED;
+		tbb.fsStyle = style;
+		if !text.is_empty() {
+			tbb.set_iString(&mut idx_str);
+		}
+
+		self.add_tbbutton(tbb)
+	}
+
+	/// Adds a new separator by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message, then resizes
+	/// the toolbar.
+	///
+	/// Must be called after the control is created.
+	pub fn add_separator(&self) -> SysResult<()> {
+		let mut tbb = TBBUTTON::default();
+		tbb.fsStyle = co::BTNS::SEP;
+		self.add_tbbutton(tbb)
+	}
+
+	fn add_tbbutton(&self, mut tbb: TBBUTTON) -> SysResult<()> {
+		unsafe {
+			self.hwnd().SendMessage(tbm::AddButtons {
+				buttons: std::slice::from_mut(&mut tbb),
+			})?;
+			self.hwnd().SendMessage(tbm::AutoSize {});
+		}
+		Ok(())
+	}
+
+	/// Retrieves the number of buttons, including separators, by sending a
+	/// [`tbm::ButtonCount`](crate::msg::tbm::ButtonCount) message.
+	#[must_use]
+	pub fn button_count(&self) -> u32 {
+		unsafe { self.hwnd().SendMessage(tbm::ButtonCount {}) }
+	}
+
+	/// Enables or disables the button by sending a
+	/// [`tbm::EnableButton`](crate::msg::tbm::EnableButton) message.
+	pub fn enable_button(&self, cmd_id: u16, enable: bool) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::EnableButton { btn_cmd_id: cmd_id, enable })
+		}
+	}
+
+	/// Retrieves a reference to the associated image list by sending a
+	/// [`tbm::GetImageList`](crate::msg::tbm::GetImageList) message.
+	///
+	/// The image list is owned by the control.
+	#[must_use]
+	pub fn image_list(&self) -> Option<&HIMAGELIST> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::GetImageList {})
+		}.map(|hil| {
+			let hil_ptr = &hil as *const HIMAGELIST;
+			unsafe { &*hil_ptr }
+		})
+	}
+
+	/// Tells whether the button is checked by sending a
+	/// [`tbm::IsButtonChecked`](crate::msg::tbm::IsButtonChecked) message.
+	#[must_use]
+	pub fn is_button_checked(&self, cmd_id: u16) -> bool {
+		unsafe { self.hwnd().SendMessage(tbm::IsButtonChecked { cmd_id }) }
+	}
+
+	/// Tells whether the button is enabled by sending a
+	/// [`tbm::IsButtonEnabled`](crate::msg::tbm::IsButtonEnabled) message.
+	#[must_use]
+	pub fn is_button_enabled(&self, cmd_id: u16) -> bool {
+		unsafe { self.hwnd().SendMessage(tbm::IsButtonEnabled { cmd_id }) }
+	}
+
+	/// Checks or unchecks the button by sending a
+	/// [`tbm::CheckButton`](crate::msg::tbm::CheckButton) message.
+	///
+	/// The button should have the [`co::BTNS::CHECK`](crate::co::BTNS::CHECK)
+	/// style.
+	pub fn set_button_checked(&self, cmd_id: u16, check: bool) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::CheckButton { btn_cmd_id: cmd_id, check })
+		}
+	}
+
+	/// Sets the image list used for the buttons by sending a
+	/// [`tbm::SetImageList`](crate::msg::tbm::SetImageList) message.
+	///
+	/// The image list will be owned by the control. Returns the previous one,
+	/// if any.
+	pub fn set_image_list(&self,
+		himagelist: ImageListDestroyGuard,
+	) -> Option<ImageListDestroyGuard>
+	{
+		let mut himagelist = himagelist;
+		let hil = himagelist.leak();
+
+		unsafe {
+			let prev = self.hwnd()
+				.SendMessage(tbm::SetImageList { himagelist: hil })
+				.map(|prev_hil| ImageListDestroyGuard::new(prev_hil));
+			self.hwnd().SendMessage(tbm::AutoSize {}); // button size may change
+			prev
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Toolbar`](crate::gui::Toolbar) programmatically with
+/// [`Toolbar::new`](crate::gui::Toolbar::new).
+pub struct ToolbarOpts {
+	/// Toolbar styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `TBSTYLE::FLAT | TBSTYLE::TOOLTIPS`.
+	pub toolbar_style: co::TBSTYLE,
+	/// Extended toolbar styles to be set with
+	/// [`tbm::SetExtendedStyle`](crate::msg::tbm::SetExtendedStyle).
+	///
+	/// Defaults to `TBSTYLE_EX::DRAWDDARROWS`.
+	pub toolbar_ex_style: co::TBSTYLE_EX,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	///
+	/// Common control styles, like
+	/// [`co::CCS::NODIVIDER`](crate::co::CCS::NODIVIDER), can be added here.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+}
+
+impl Default for ToolbarOpts {
+	fn default() -> Self {
+		Self {
+			toolbar_style: co::TBSTYLE::FLAT | co::TBSTYLE::TOOLTIPS,
+			toolbar_ex_style: co::TBSTYLE_EX::DRAWDDARROWS,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+		}
+	}
+}
+
+impl AutoCtrlId for ToolbarOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
@@ -50,6 +50,53 @@ impl<'a, T> TreeViewItem<'a, T> {
 		self.owner.raw_insert_item(Some(&self.hitem), text, icon_index, data)
 	}
 
+	/// Checks or unchecks the item by sending a
+	/// [`tvm::SetItem`](crate::msg::tvm::SetItem) message.
+	///
+	/// The tree view must have the
+	/// [`co::TVS::CHECKBOXES`](crate::co::TVS::CHECKBOXES) style.
+	pub fn check(&self, set: bool) {
+		self.set_state_image(if set { 2 } else { 1 }); // state image index: 1 unchecked, 2 checked
+	}
+
+	/// Sets the item as partially checked by sending a
+	/// [`tvm::SetItem`](crate::msg::tvm::SetItem) message.
+	///
+	/// The tree view must have the
+	/// [`co::TVS_EX::PARTIALCHECKBOXES`](crate::co::TVS_EX::PARTIALCHECKBOXES)
+	/// extended style, and neither
+	/// [`co::TVS_EX::DIMMEDCHECKBOXES`](crate::co::TVS_EX::DIMMEDCHECKBOXES)
+	/// nor
+	/// [`co::TVS_EX::EXCLUSIONCHECKBOXES`](crate::co::TVS_EX::EXCLUSIONCHECKBOXES),
+	/// which would shift the state image indexes.
+	pub fn check_partial(&self) {
+		self.set_state_image(3);
+	}
+
+	fn set_state_image(&self, index: u32) {
+		let mut tvix = TVITEMEX::default();
+		tvix.hItem = unsafe { self.hitem.raw_copy() };
+		tvix.mask = co::TVIF::STATE;
+		tvix.stateMask = co::TVIS::STATEIMAGEMASK;
+		tvix.state = unsafe { co::TVIS::from_raw(index << 12) }; // INDEXTOSTATEIMAGEMASK
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tvm::SetItem { tvitem: &tvix })
+		}.unwrap();
+	}
+
+	fn state_image(&self) -> u32 {
+		let state = unsafe {
+			self.owner.hwnd()
+				.SendMessage(tvm::GetItemState {
+					hitem: &self.hitem,
+					mask: co::TVIS::STATEIMAGEMASK,
+				})
+		};
+		state.raw() >> 12
+	}
+
 	/// Returns a [`Rc`](std::rc::Rc)/[`RefCell`](std::cell::RefCell) with the
 	/// stored data by sending an [`lvm::GetItem`](crate::msg::lvm::GetItem)
 	/// message.
@@ -132,6 +179,13 @@ impl<'a, T> TreeViewItem<'a, T> {
 		&self.hitem
 	}
 
+	/// Tells if the item is checked by sending a
+	/// [`tvm::GetItemState`](crate::msg::tvm::GetItemState) message.
+	#[must_use]
+	pub fn is_checked(&self) -> bool {
+		self.state_image() == 2
+	}
+
 	/// Tells if the item is expanded by sending a
 	/// [`tvm::GetItemState`](crate::msg::tvm::GetItemState) message.
 	#[must_use]
@@ -145,6 +199,16 @@ impl<'a, T> TreeViewItem<'a, T> {
 		}.has(co::TVIS::EXPANDED)
 	}
 
+	/// Tells if the item is partially checked by sending a
+	/// [`tvm::GetItemState`](crate::msg::tvm::GetItemState) message.
+	///
+	/// See [`check_partial`](crate::gui::spec::TreeViewItem::check_partial)
+	/// for the requirements.
+	#[must_use]
+	pub fn is_partially_checked(&self) -> bool {
+		self.state_image() == 3
+	}
+
 	/// Tells if the item is a root by sending a
 	/// [`tvm::GetNextItem`](crate::msg::tvm::GetNextItem) message.
 	#[must_use]
@@ -78,6 +78,32 @@ impl<'a, T> TreeViewItems<'a, T> {
 		TreeViewItem::new(self.owner, unsafe { hitem.raw_copy() })
 	}
 
+	/// Returns an iterator over all the checked items, in depth-first order,
+	/// including the ones inside collapsed nodes.
+	///
+	/// The tree view must have the
+	/// [`co::TVS::CHECKBOXES`](crate::co::TVS::CHECKBOXES) style.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_tree: gui::TreeView; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_tree = gui::TreeView::<()>::new(&wnd, gui::TreeViewOpts::default());
+	///
+	/// for item in my_tree.items().iter_checked() {
+	///     println!("Checked: {}", item.text());
+	/// }
+	/// ```
+	pub fn iter_checked(&self,
+	) -> impl Iterator<Item = TreeViewItem<'a, T>> + 'a
+	{
+		TreeViewAllItemIter::new(self.owner)
+			.filter(|item| item.is_checked())
+	}
+
 	/// Returns an iterator over the selected items.
 	#[must_use]
 	pub fn iter_selected(&self,
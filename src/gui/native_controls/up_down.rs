@@ -130,6 +130,64 @@ impl UpDown {
 		new_self
 	}
 
+	/// Instantiates a new `UpDown` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx),
+	/// attached to the given [`Edit`](crate::gui::Edit) with
+	/// [`set_buddy`](crate::gui::UpDown::set_buddy), and with the given range.
+	/// The initial position is the minimum value.
+	///
+	/// Unlike [`UDS::AUTOBUDDY`](crate::co::UDS::AUTOBUDDY), the buddy doesn't
+	/// need to be created immediately before the `UpDown`, but it must be
+	/// instantiated before it.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create an `UpDown` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let txt = gui::Edit::new(
+	///     &wnd,
+	///     gui::EditOpts {
+	///         edit_style: co::ES::AUTOHSCROLL | co::ES::NOHIDESEL | co::ES::NUMBER,
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// let updn = gui::UpDown::new_with_buddy(&wnd, &txt, (-100_000, 100_000));
+	/// ```
+	#[must_use]
+	pub fn new_with_buddy(
+		parent: &impl GuiParent,
+		buddy: &Edit,
+		range: (i32, i32),
+	) -> Self
+	{
+		let new_self = Self::new(parent, UpDownOpts {
+			up_down_style: co::UDS::SETBUDDYINT | co::UDS::ALIGNRIGHT
+				| co::UDS::ARROWKEYS | co::UDS::HOTTRACK,
+			range,
+			..Default::default()
+		});
+
+		let (self2, buddy) = (new_self.clone(), buddy.clone());
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.set_buddy(&buddy);
+			self2.set_pos(range.0); // also updates the buddy text
+			Ok(WmRet::NotHandled)
+		});
+
+		new_self
+	}
+
 	/// Instantiates a new `UpDown` object, to be loaded from a dialog
 	/// resource with
 	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
@@ -168,7 +226,7 @@ impl UpDown {
 					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
 
 				self.0.base.create_window( // may panic
-					"msctls_updown32", None, pos, SIZE::new(0, opts.height as _),
+					"msctls_updown32", None, pos, sz,
 					opts.window_ex_style,
 					opts.window_style | opts.up_down_style.into(),
 				)?;
@@ -187,6 +245,13 @@ impl UpDown {
 		Ok(())
 	}
 
+	/// Retrieves the buddy control, if any, by sending an
+	/// [`udm::GetBuddy`](crate::msg::udm::GetBuddy) message.
+	#[must_use]
+	pub fn buddy(&self) -> Option<HWND> {
+		unsafe { self.hwnd().SendMessage(udm::GetBuddy {}) }
+	}
+
 	/// Retrieves the current position by sending an
 	/// [`udm::GetPos32`](crate::msg::udm::GetPos32) message.
 	#[must_use]
@@ -211,6 +276,20 @@ impl UpDown {
 		(min, max)
 	}
 
+	/// Sets the buddy control by sending an
+	/// [`udm::SetBuddy`](crate::msg::udm::SetBuddy) message.
+	///
+	/// If the `UpDown` has [`UDS::ALIGNLEFT`](crate::co::UDS::ALIGNLEFT) or
+	/// [`UDS::ALIGNRIGHT`](crate::co::UDS::ALIGNRIGHT) style, it's moved next
+	/// to the buddy. With [`UDS::SETBUDDYINT`](crate::co::UDS::SETBUDDYINT)
+	/// style, the buddy text is updated on the next position change.
+	pub fn set_buddy(&self, buddy: &Edit) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(udm::SetBuddy { hbuddy: buddy.hwnd() });
+		}
+	}
+
 	/// Sets the current position by sending an
 	/// [`udm::SetPos32`](crate::msg::udm::SetPos32) message.
 	pub fn set_pos(&self, pos: i32) {
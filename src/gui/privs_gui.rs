@@ -31,14 +31,8 @@ static mut UI_HFONT: Option<DeleteObjectGuard<HFONT>> = None;
 
 /// Creates the global UI font object.
 pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
-	let mut ncm = NONCLIENTMETRICS::default();
+	let ncm = SystemParametersInfo_get_nonclientmetrics()?;
 	unsafe {
-		SystemParametersInfo(
-			co::SPI::GETNONCLIENTMETRICS,
-			std::mem::size_of::<NONCLIENTMETRICS>() as _,
-			&mut ncm,
-			co::SPIF::NoValue,
-		)?;
 		UI_HFONT = Some(HFONT::CreateFontIndirect(&ncm.lfMenuFont)?);
 	}
 	Ok(())
@@ -157,6 +151,14 @@ pub(in crate::gui) fn multiply_dpi_or_dtu(
 
 //------------------------------------------------------------------------------
 
+/// Retrieves the parent of a control or child window, which always has one.
+#[must_use]
+pub(in crate::gui) fn parent_of(hwnd: &HWND) -> SysResult<HWND> {
+	hwnd.GetParent()?.ok_or(co::ERROR::INVALID_WINDOW_HANDLE)
+}
+
+//------------------------------------------------------------------------------
+
 /// Calculates the bound rectangle to fit the text with current system font.
 #[must_use]
 pub(in crate::gui) fn calc_text_bound_box(text: &str) -> SysResult<SIZE> {
@@ -66,8 +66,43 @@ pub trait GuiWindowText: GuiWindow {
 		self.hwnd().SetWindowText(text).unwrap();
 	}
 
+	/// Sets the text by calling
+	/// [`HWND::SetWindowText`](crate::prelude::user_Hwnd::SetWindowText) only
+	/// if it differs from the current one, thus avoiding needless repaints.
+	/// Returns `true` if the text was changed.
+	///
+	/// The current text is retrieved with
+	/// [`HWND::GetWindowText`](crate::prelude::user_Hwnd::GetWindowText), so
+	/// the comparison is accurate even if the text was changed by the user or
+	/// by other means.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let percent = 50;
+	/// wnd.set_text_if_changed(&format!("Processing... {}%", percent));
+	/// ```
+	fn set_text_if_changed(&self, text: &str) -> bool {
+		if self.hwnd().GetWindowText().unwrap() == text {
+			false
+		} else {
+			self.hwnd().SetWindowText(text).unwrap();
+			true
+		}
+	}
+
 	/// Retrieves the text by calling
 	/// [`HWND::GetWindowText`](crate::prelude::user_Hwnd::GetWindowText).
+	///
+	/// The text length is queried first, so the buffer is allocated only once.
+	/// To read large texts repeatedly, prefer
+	/// [`Edit::text_into`](crate::gui::Edit::text_into), which reuses the
+	/// buffer.
 	#[must_use]
 	fn text(&self) -> String {
 		self.hwnd().GetWindowText().unwrap()
@@ -225,6 +260,78 @@ pub trait GuiParent: GuiWindow + AsRef<Base> {
 	{
 		self.as_ref().run_ui_thread(func)
 	}
+
+	/// Registers the closure which will handle, in the window's original UI
+	/// thread, the data of type `T` sent with
+	/// [`post_to_ui`](crate::prelude::GuiParent::post_to_ui).
+	///
+	/// Only one closure is kept for each type `T`: if you register another one,
+	/// it replaces the previous. If no closure is registered for a type, the
+	/// data posted with it is simply dropped.
+	///
+	/// Unlike [`on`](crate::prelude::GuiParent::on), this method can be called
+	/// after the window is created.
+	///
+	/// # Examples
+	///
+	/// Workers report their progress to the UI thread:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// struct Progress {
+	///     worker: u32,
+	///     percent: u32,
+	/// }
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on_ui_message({
+	///     let wnd = wnd.clone();
+	///     move |p: Progress| -> w::AnyResult<()> {
+	///         wnd.hwnd().SetWindowText(
+	///             &format!("Worker {}: {}%", p.worker, p.percent))?;
+	///         Ok(())
+	///     }
+	/// });
+	///
+	/// for worker in 0..4 {
+	///     std::thread::spawn({
+	///         let wnd = wnd.clone();
+	///         move || {
+	///             for percent in (0..=100).step_by(10) {
+	///                 w::Sleep(500);
+	///                 wnd.post_to_ui(Progress { worker, percent }).ok();
+	///             }
+	///         }
+	///     });
+	/// }
+	/// ```
+	fn on_ui_message<T, F>(&self, func: F)
+		where T: Send + 'static,
+			F: Fn(T) -> AnyResult<()> + 'static,
+	{
+		self.as_ref().on_ui_message(func)
+	}
+
+	/// Sends data of type `T`, from any thread, to the closure registered with
+	/// [`on_ui_message`](crate::prelude::GuiParent::on_ui_message), which will
+	/// run in the window's original UI thread.
+	///
+	/// Unlike [`run_ui_thread`](crate::prelude::GuiParent::run_ui_thread),
+	/// this method doesn't block: the data is queued with
+	/// [`HWND::PostMessage`](crate::prelude::user_Hwnd::PostMessage), and the
+	/// messages are processed in the order they were posted.
+	///
+	/// If the window is destroyed before the data is processed, the data is
+	/// dropped. If the window is already destroyed, an error is returned and
+	/// the data is dropped immediately.
+	fn post_to_ui<T>(&self, data: T) -> SysResult<()>
+		where T: Send + 'static,
+	{
+		self.as_ref().post_to_ui(data)
+	}
 }
 
 /// A closeable popup parent window.
@@ -276,7 +383,7 @@ pub trait GuiChildFocus: GuiChild {
 	/// because it takes care of border highlighting, like the native
 	/// [`Button`](crate::gui::Button) control needs.
 	fn focus(&self) {
-		let hparent = self.hwnd().GetParent().unwrap();
+		let hparent = parent_of(self.hwnd()).unwrap();
 		if hparent.is_dialog() {
 			unsafe {
 				hparent.SendMessage(wm::NextDlgCtl {
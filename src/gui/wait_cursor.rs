@@ -0,0 +1,70 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Shows the wait (hourglass) cursor until it goes out of scope, when the
+/// previous cursor is restored – including on early returns and during a
+/// panic unwind.
+///
+/// This is intended for long operations which block the UI thread: since no
+/// messages are processed meanwhile, the cursor is not reset by
+/// [`wm::SetCursor`](crate::msg::wm::SetCursor). For operations running in
+/// other threads, use [`ActivityScope`](crate::gui::ActivityScope).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// fn load_everything(wnd: &gui::WindowMain) -> w::AnyResult<()> {
+///     let _wait = gui::WaitCursor::with_disabled(wnd)?;
+///     w::Sleep(5000); // long operation; any error restores the cursor
+///     Ok(())
+/// }
+///
+/// load_everything(&wnd)?;
+/// # w::AnyResult::Ok(())
+/// ```
+pub struct WaitCursor {
+	hcursor_prev: Option<HCURSOR>,
+	hwnd_disabled: Option<HWND>,
+}
+
+impl Drop for WaitCursor {
+	fn drop(&mut self) {
+		if let Some(hwnd) = self.hwnd_disabled.as_ref() {
+			hwnd.EnableWindow(true);
+		}
+		self.hcursor_prev.as_ref()
+			.unwrap_or(&HCURSOR::NULL)
+			.SetCursor();
+	}
+}
+
+impl WaitCursor {
+	/// Loads the [`IDC::WAIT`](crate::co::IDC::WAIT) system cursor and sets
+	/// it as the current cursor.
+	pub fn new() -> SysResult<Self> {
+		let mut hcursor = HINSTANCE::NULL.LoadCursor(IdIdcStr::Idc(co::IDC::WAIT))?;
+		let hcursor = hcursor.leak(); // shared system cursor, must not be destroyed
+
+		Ok(Self {
+			hcursor_prev: hcursor.SetCursor(),
+			hwnd_disabled: None,
+		})
+	}
+
+	/// Sets the wait cursor, like [`new`](crate::gui::WaitCursor::new), and
+	/// also disables the window, so the user cannot interact with it. The
+	/// window is enabled again when the guard goes out of scope.
+	pub fn with_disabled(wnd: &impl GuiWindow) -> SysResult<Self> {
+		let mut wait = Self::new()?;
+		let hwnd = unsafe { wnd.hwnd().raw_copy() };
+		hwnd.EnableWindow(false);
+		wait.hwnd_disabled = Some(hwnd);
+		Ok(wait)
+	}
+}
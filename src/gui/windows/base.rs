@@ -1,4 +1,8 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ptr::NonNull;
+use std::rc::Rc;
 
 use crate::co;
 use crate::decl::*;
@@ -11,6 +15,12 @@ struct ThreadPack {
 	func: Box<dyn FnOnce() -> AnyResult<()>>,
 }
 
+/// Allocated on the heap and passed through `WM_UI_DATA`.
+type DataPack = Box<dyn Any + Send>;
+
+/// Closures registered with `on_ui_message`, keyed by the data type.
+type DataHandlers = Rc<RefCell<HashMap<TypeId, Rc<dyn Fn(DataPack) -> AnyResult<()>>>>>;
+
 //------------------------------------------------------------------------------
 
 /// Base to `RawBase` and `DlgBase`, which means all container windows.
@@ -22,6 +32,7 @@ pub(in crate::gui) struct Base {
 	user_events: WindowEvents, // ordinary window events, inserted by user: only last added is executed (overwrite previous)
 	after_user_events: WindowEvents, // all will be executed after user events
 	layout_arranger: LayoutArranger,
+	ui_data_handlers: DataHandlers,
 }
 
 impl AsRef<Base> for Base {
@@ -32,6 +43,7 @@ impl AsRef<Base> for Base {
 
 impl Base {
 	const WM_UI_THREAD: co::WM = unsafe { co::WM::from_raw(co::WM::APP.raw() + 0x3fff) };
+	const WM_UI_DATA: co::WM = unsafe { co::WM::from_raw(co::WM::APP.raw() + 0x3ffe) };
 
 	#[must_use]
 	pub(in crate::gui) fn new(
@@ -47,6 +59,7 @@ impl Base {
 			user_events: WindowEvents::new(is_dialog),
 			after_user_events: WindowEvents::new(is_dialog),
 			layout_arranger: LayoutArranger::new(),
+			ui_data_handlers: Rc::new(RefCell::new(HashMap::new())),
 		};
 		new_self.default_message_handlers();
 		new_self
@@ -144,6 +157,7 @@ impl Base {
 		self.before_user_events.clear_events();
 		self.user_events.clear_events();
 		self.after_user_events.clear_events();
+		self.ui_data_handlers.borrow_mut().clear();
 	}
 
 	pub(in crate::gui) fn add_to_layout_arranger(&self,
@@ -162,22 +176,21 @@ impl Base {
 			func().unwrap_or_else(|err| {
 				// If the user func returned an error, create another function
 				// which just returns it, then forward it to WM_UI_THREAD.
-				let pack = Box::new(ThreadPack { func: Box::new(|| Err(err)) });
-				let ptr_pack = Box::into_raw(pack);
-				hwnd.GetAncestor(co::GA::ROOTOWNER)
-					.map(|hwnd| unsafe {
-						hwnd.SendMessage(WndMsg {
-							msg_id: Self::WM_UI_THREAD,
-							wparam: Self::WM_UI_THREAD.raw() as _,
-							lparam: ptr_pack as _, // send pointer
-						});
-					});
+				Self::run_ui_thread_of(&hwnd, || Err(err));
 			});
 		});
 	}
 
 	pub(in crate::gui) fn run_ui_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		Self::run_ui_thread_of(&self.hwnd, func)
+	}
+
+	/// Same as [`run_ui_thread`](Self::run_ui_thread), but usable when only
+	/// the `HWND` of the window is kept.
+	pub(in crate::gui) fn run_ui_thread_of<F>(hwnd: &HWND, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
 		// This method is analog to SendMessage (synchronous), but intended to
 		// be called from another thread, so a callback function can, tunelled
@@ -191,7 +204,7 @@ impl Base {
 
 		// Bypass any modals and send straight to main window. This avoids any
 		// blind spots of unhandled messages by a modal being created/destroyed.
-		self.hwnd.GetAncestor(co::GA::ROOTOWNER)
+		hwnd.GetAncestor(co::GA::ROOTOWNER)
 			.map(|hwnd| unsafe {
 				hwnd.SendMessage(WndMsg {
 					msg_id: Self::WM_UI_THREAD,
@@ -201,6 +214,57 @@ impl Base {
 			});
 	}
 
+	pub(in crate::gui) fn post_to_ui<T>(&self, data: T) -> SysResult<()>
+		where T: Send + 'static,
+	{
+		if self.hwnd == HWND::NULL {
+			// Posting to a null HWND would put the message in the queue of the
+			// current thread, which is not the UI thread.
+			return Err(co::ERROR::INVALID_WINDOW_HANDLE);
+		}
+
+		let pack: Box<DataPack> = Box::new(Box::new(data));
+		let ptr_pack = Box::into_raw(pack);
+
+		unsafe {
+			self.hwnd.PostMessage(WndMsg {
+				msg_id: Self::WM_UI_DATA,
+				wparam: Self::WM_UI_DATA.raw() as _,
+				lparam: ptr_pack as _, // send pointer
+			})
+		}.map_err(|err| {
+			let _ = unsafe { Box::from_raw(ptr_pack) }; // not posted, drop data
+			err
+		})
+	}
+
+	/// Drops the data of all `WM_UI_DATA` messages still in the queue, which
+	/// would otherwise leak when the window is destroyed. Must be called while
+	/// processing `WM_NCDESTROY`, before the `HWND` is cleared.
+	pub(in crate::gui) fn discard_pending_ui_data(&self) {
+		let mut msg = MSG::default();
+		while PeekMessage(&mut msg, Some(&self.hwnd),
+			Self::WM_UI_DATA.raw(), Self::WM_UI_DATA.raw(), co::PM::REMOVE)
+		{
+			if unsafe { co::WM::from_raw(msg.wParam as _) } == Self::WM_UI_DATA {
+				let _ = unsafe { Box::from_raw(msg.lParam as *mut DataPack) };
+			}
+		}
+	}
+
+	pub(in crate::gui) fn on_ui_message<T, F>(&self, func: F)
+		where T: Send + 'static,
+			F: Fn(T) -> AnyResult<()> + 'static,
+	{
+		self.ui_data_handlers.borrow_mut().insert(
+			TypeId::of::<T>(),
+			Rc::new(move |pack: DataPack| {
+				let data = pack.downcast::<T>().unwrap(); // type ID was already matched
+				func(*data)
+			}),
+		);
+	}
+
 	fn default_message_handlers(&self) {
 		// We cant pass a pointer to Self because at this moment the parent
 		// struct isn't created and pinned yet, so we make LayoutArranger
@@ -226,6 +290,21 @@ impl Base {
 			}
 			Ok(WmRet::HandledOk)
 		});
+
+		let ui_data_handlers = self.ui_data_handlers.clone();
+		self.before_user_events.wm(Self::WM_UI_DATA, move |p| {
+			if unsafe { co::WM::from_raw(p.wparam as _) } == Self::WM_UI_DATA { // additional safety check
+				let ptr_pack = p.lparam as *mut DataPack; // retrieve pointer
+				let pack = *unsafe { Box::from_raw(ptr_pack) };
+				let handler = ui_data_handlers.borrow() // clone, so the handler can register others
+					.get(&(*pack).type_id())
+					.cloned();
+				if let Some(handler) = handler {
+					handler(pack).unwrap_or_else(|err| post_quit_error(p, err));
+				} // if no handler was registered, data is simply dropped
+			}
+			Ok(WmRet::HandledOk)
+		});
 	}
 
 	pub(in crate::gui) fn run_main_loop(
@@ -0,0 +1,311 @@
+use std::any::Any;
+use std::cell::{Cell, UnsafeCell};
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::prelude::*;
+use crate::user::privs::*;
+
+type ChangeFn = Rc<dyn Fn(&ClipboardContent) -> AnyResult<()>>;
+
+const RETRY_TIMER_ID: usize = 1;
+
+struct Obj { // actual fields of ClipboardMonitor
+	raw_base: RawBase,
+	max_history: usize,
+	history: UnsafeCell<VecDeque<ClipboardContent>>,
+	last_seq: Cell<u32>, // sequence number of the last processed change
+	last_hash: Cell<u64>, // hash of the last processed content
+	retry_ms: Cell<u32>, // delay of the pending read retry, zero if none
+	func: UnsafeCell<Option<ChangeFn>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// A
+/// [message-only](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows)
+/// window which listens to clipboard changes with
+/// [`HWND::AddClipboardFormatListener`](crate::prelude::user_Hwnd::AddClipboardFormatListener),
+/// reads the new text or image, and optionally keeps a bounded history.
+///
+/// The monitor must be created in a thread which runs a message loop, like the
+/// main thread of a [`WindowMain`](crate::gui::WindowMain) application.
+///
+/// Some details are handled automatically:
+///
+/// * if another application is still holding the clipboard open, reading is
+///   retried a few times with a timer, with an increasing delay, so the thread
+///   is never blocked;
+/// * a change whose
+///   [sequence number](crate::GetClipboardSequenceNumber) was already
+///   processed is ignored, so multiple notifications for a single copy are
+///   reported once;
+/// * a change with the same content of the previous one is ignored;
+/// * changes made by [`set_content`](crate::gui::ClipboardMonitor::set_content)
+///   are not reported.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let clip = gui::ClipboardMonitor::new(20)?; // keep the last 20 entries
+///
+/// clip.on_change(|content| {
+///     if let gui::ClipboardContent::Text(text) = content {
+///         println!("Copied: {}", text);
+///     }
+///     Ok(())
+/// });
+/// # w::AnyResult::Ok(())
+/// ```
+#[derive(Clone)]
+pub struct ClipboardMonitor(Pin<Arc<Obj>>);
+
+unsafe impl Send for ClipboardMonitor {}
+
+impl GuiWindow for ClipboardMonitor {
+	fn hwnd(&self) -> &HWND {
+		self.0.raw_base.base().hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl ClipboardMonitor {
+	/// Instantiates a new `ClipboardMonitor` object, immediately creating its
+	/// message-only window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// At most `max_history` entries are kept in the history, the oldest being
+	/// discarded first. If zero, no history is kept.
+	pub fn new(max_history: usize) -> AnyResult<Self> {
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					raw_base: RawBase::new(None::<&WindowMain>),
+					max_history,
+					history: UnsafeCell::new(VecDeque::with_capacity(max_history)),
+					last_seq: Cell::new(GetClipboardSequenceNumber()), // ignore current content
+					last_hash: Cell::new(0),
+					retry_ms: Cell::new(0),
+					func: UnsafeCell::new(None),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers();
+		new_self.create()?;
+		Ok(new_self)
+	}
+
+	fn create(&self) -> AnyResult<()> {
+		let hinst = HINSTANCE::GetModuleHandle(None)?;
+		let mut wcx = WNDCLASSEX::default();
+		let mut class_name_buf = WString::new();
+		RawBase::fill_wndclassex(
+			&hinst,
+			"", co::CS::default(),
+			&Icon::None, &Icon::None,
+			&Brush::None, &Cursor::None, &mut wcx,
+			&mut class_name_buf)?;
+		let atom = self.0.raw_base.register_class(&mut wcx)?;
+
+		let hparent_msg = unsafe { HWND::from_ptr(HWND_MESSAGE as _) };
+		self.0.raw_base.create_window(
+			Some(&hparent_msg),
+			atom, None, IdMenu::None,
+			POINT::default(), SIZE::default(),
+			co::WS_EX::NoValue, co::WS::NoValue,
+		)?;
+
+		self.hwnd().AddClipboardFormatListener()?;
+		Ok(())
+	}
+
+	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.0.raw_base.base().before_user_on().wm_clipboard_update(move || {
+			self2.process_change()
+		});
+
+		let self2 = self.clone();
+		self.0.raw_base.base().before_user_on().wm_timer(RETRY_TIMER_ID, move || {
+			self2.hwnd().KillTimer(RETRY_TIMER_ID)?; // one-shot
+			self2.process_change()
+		});
+
+		let self2 = self.clone();
+		self.0.raw_base.base().before_user_on().wm_destroy(move || {
+			self2.hwnd().RemoveClipboardFormatListener()?;
+			Ok(())
+		});
+	}
+
+	fn process_change(&self) -> AnyResult<()> {
+		let seq = GetClipboardSequenceNumber();
+		if seq == self.0.last_seq.get() {
+			return Ok(()); // change already processed
+		}
+
+		let content = match self.read_content() {
+			Err(co::ERROR::ACCESS_DENIED) => return self.schedule_retry(), // still locked
+			res => {
+				self.0.retry_ms.set(0);
+				match res {
+					Ok(Some(content)) => content,
+					Ok(None) => { // no supported format
+						self.0.last_seq.set(seq);
+						return Ok(());
+					},
+					Err(e) => return Err(e.into()),
+				}
+			},
+		};
+		self.0.last_seq.set(seq);
+
+		let hash = Self::hash_content(&content);
+		if hash == self.0.last_hash.get() {
+			return Ok(()); // same content set again
+		}
+		self.0.last_hash.set(hash);
+
+		if self.0.max_history > 0 {
+			let history = unsafe { &mut *self.0.history.get() };
+			if history.len() == self.0.max_history {
+				history.pop_front();
+			}
+			history.push_back(content.clone());
+		}
+
+		let func = unsafe { &*self.0.func.get() }.clone(); // the closure may replace itself
+		if let Some(func) = func {
+			func(&content)?;
+		}
+		Ok(())
+	}
+
+	fn schedule_retry(&self) -> AnyResult<()> {
+		let delay_ms = match self.0.retry_ms.get() {
+			0 => 10,
+			ms => ms * 2,
+		};
+		if delay_ms > 160 {
+			self.0.retry_ms.set(0); // give up, wait for the next change
+			return Ok(());
+		}
+		self.0.retry_ms.set(delay_ms);
+		self.hwnd().SetTimer(RETRY_TIMER_ID, delay_ms, None)?;
+		Ok(())
+	}
+
+	fn read_content(&self) -> SysResult<Option<ClipboardContent>> {
+		let _clip = self.hwnd().OpenClipboard()?;
+
+		if IsClipboardFormatAvailable(co::CF::UNICODETEXT) {
+			let hmem = unsafe {
+				HGLOBAL::from_ptr(GetClipboardData(co::CF::UNICODETEXT)? as _)
+			};
+			let block = hmem.GlobalLock()?;
+			let text = WString::from_wchars_slice(unsafe { block.as_slice_aligned::<u16>() });
+			Ok(Some(ClipboardContent::Text(text.to_string())))
+		} else if IsClipboardFormatAvailable(co::CF::DIB) {
+			let hmem = unsafe {
+				HGLOBAL::from_ptr(GetClipboardData(co::CF::DIB)? as _)
+			};
+			let block = hmem.GlobalLock()?;
+			Ok(Some(ClipboardContent::Dib(block.as_slice().to_vec())))
+		} else {
+			Ok(None)
+		}
+	}
+
+	fn hash_content(content: &ClipboardContent) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		content.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	/// Removes all the entries from the history.
+	pub fn clear_history(&self) {
+		unsafe { &mut *self.0.history.get() }.clear();
+	}
+
+	/// Returns a copy of the history entries, from the oldest to the newest.
+	#[must_use]
+	pub fn history(&self) -> Vec<ClipboardContent> {
+		unsafe { &*self.0.history.get() }.iter().cloned().collect()
+	}
+
+	/// Sets the closure to be called whenever the clipboard changes, replacing
+	/// the previous one, if any.
+	pub fn on_change<F>(&self, func: F)
+		where F: Fn(&ClipboardContent) -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.0.func.get() } = Some(Rc::new(func));
+	}
+
+	/// Replaces the clipboard contents. This change will not be reported to the
+	/// [`on_change`](crate::gui::ClipboardMonitor::on_change) closure, neither
+	/// added to the history.
+	///
+	/// If another application is holding the clipboard open, fails with
+	/// [`co::ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED).
+	///
+	/// # Examples
+	///
+	/// Restoring the oldest history entry:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let clip: gui::ClipboardMonitor; // initialized somewhere
+	/// # let clip = gui::ClipboardMonitor::new(20)?;
+	///
+	/// if let Some(oldest) = clip.history().first() {
+	///     clip.set_content(oldest)?;
+	/// }
+	/// # w::AnyResult::Ok(())
+	/// ```
+	pub fn set_content(&self, content: &ClipboardContent) -> SysResult<()> {
+		let (format, bytes) = match content {
+			ClipboardContent::Dib(data) => (co::CF::DIB, data.clone()),
+			ClipboardContent::Text(text) => (
+				co::CF::UNICODETEXT,
+				WString::from_str(text).as_slice().iter()
+					.flat_map(|ch| ch.to_le_bytes())
+					.collect::<Vec<_>>(),
+			),
+		};
+
+		let mut hmem = HGLOBAL::GlobalAlloc(Some(co::GMEM::MOVEABLE), bytes.len())?;
+		hmem.GlobalLock()?.as_mut_slice()[..bytes.len()].copy_from_slice(&bytes);
+
+		{
+			let _clip = self.hwnd().OpenClipboard()?;
+			EmptyClipboard()?;
+			unsafe { SetClipboardData(format, hmem.ptr() as _)?; }
+			let _ = hmem.leak(); // now owned by the system
+		} // CloseClipboard
+
+		self.0.last_seq.set(GetClipboardSequenceNumber()); // don't report our own change
+		self.0.last_hash.set(Self::hash_content(content));
+		Ok(())
+	}
+
+	/// Destroys the message-only window, so the clipboard is no longer
+	/// monitored.
+	pub fn stop(&self) -> SysResult<()> {
+		self.hwnd().DestroyWindow()
+	}
+}
@@ -142,13 +142,33 @@ impl DlgBase {
 		let at_least_one_after_user = ref_self.base.process_after_user_messages(wm_any)?;
 
 		if wm_any.msg_id == co::WM::NCDESTROY { // always check
+			ref_self.base.discard_pending_ui_data(); // data posted but never processed
 			unsafe { hwnd.SetWindowLongPtr(co::GWLP::DWLP_USER, 0); } // clear passed pointer
 			ref_self.base.set_hwnd(HWND::NULL); // clear stored HWND
 			ref_self.base.clear_events(); // prevents circular references
 		}
 
 		Ok(match process_result {
-			WmRet::HandledWithRet(res) => res,
+			WmRet::HandledWithRet(res) => match wm_any.msg_id {
+				// These messages are returned directly by a dialog procedure.
+				co::WM::CHARTOITEM
+					| co::WM::COMPAREITEM
+					| co::WM::CTLCOLORBTN
+					| co::WM::CTLCOLORDLG
+					| co::WM::CTLCOLOREDIT
+					| co::WM::CTLCOLORLISTBOX
+					| co::WM::CTLCOLORSCROLLBAR
+					| co::WM::CTLCOLORSTATIC
+					| co::WM::INITDIALOG
+					| co::WM::QUERYDRAGICON
+					| co::WM::VKEYTOITEM => res,
+				// Any other result must go through DWLP_MSGRESULT, otherwise
+				// it's lost, and FALSE would mean "not handled".
+				_ => {
+					unsafe { hwnd.SetWindowLongPtr(co::GWLP::DWLP_MSGRESULT, res); }
+					1 // TRUE
+				},
+			},
 			WmRet::HandledOk => 1, // TRUE
 			WmRet::NotHandled => if at_least_one_before_user || at_least_one_after_user {
 				1 // TRUE
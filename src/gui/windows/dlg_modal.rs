@@ -47,12 +47,16 @@ impl DlgModal {
 			.map_err(|err| err.into())
 	}
 
+	pub(in crate::gui) fn end_dialog(&self, ret_code: i32) -> SysResult<()> {
+		self.base().hwnd().EndDialog(ret_code as _)
+	}
+
 	fn default_message_handlers(&self) {
 		let self2 = self.clone();
 		self.base().before_user_on().wm_init_dialog(move |_| {
 			let hwnd = self2.base().hwnd();
 			let rc = hwnd.GetWindowRect()?;
-			let rc_parent = hwnd.GetParent()?.GetWindowRect()?;
+			let rc_parent = parent_of(hwnd)?.GetWindowRect()?;
 			hwnd.SetWindowPos( // center modal on parent
 				HwndPlace::None,
 				POINT::new(
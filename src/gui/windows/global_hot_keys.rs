@@ -0,0 +1,205 @@
+use std::any::Any;
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::prelude::*;
+
+type HotKeyFn = Rc<dyn Fn() -> AnyResult<()>>;
+
+struct Obj { // actual fields of GlobalHotKeys
+	raw_base: RawBase,
+	hotkeys: UnsafeCell<Vec<(i32, HotKeyFn)>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// A hidden window which registers global hotkeys with
+/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey), and
+/// dispatches each `WM_HOTKEY` to its closure. This is the usual structure of
+/// a background hotkey application.
+///
+/// Note that, unlike [`WindowMessageOnly`](crate::gui::WindowMessageOnly),
+/// this is a hidden top-level window: a
+/// [message-only](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows)
+/// window does not receive `WM_HOTKEY`, so hotkeys registered to it would never
+/// fire.
+///
+/// All hotkeys are unregistered when the window is destroyed, either by
+/// calling [`stop`](crate::gui::GlobalHotKeys::stop) or when the process ends.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, gui};
+///
+/// let hotkeys = gui::GlobalHotKeys::new()?;
+///
+/// hotkeys.add(co::MOD::CONTROL | co::MOD::ALT, co::VK::CHAR_H, || {
+///     println!("Hello from hotkey.");
+///     Ok(())
+/// })?;
+///
+/// let hotkeys2 = hotkeys.clone();
+/// hotkeys.add(co::MOD::CONTROL | co::MOD::ALT, co::VK::CHAR_Q, move || {
+///     hotkeys2.stop()?; // quit the loop
+///     Ok(())
+/// })?;
+///
+/// hotkeys.run_loop()?; // blocks until stopped
+/// # w::AnyResult::Ok(())
+/// ```
+#[derive(Clone)]
+pub struct GlobalHotKeys(Pin<Arc<Obj>>);
+
+unsafe impl Send for GlobalHotKeys {}
+
+impl GuiWindow for GlobalHotKeys {
+	fn hwnd(&self) -> &HWND {
+		self.0.raw_base.base().hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GlobalHotKeys {
+	/// Instantiates a new `GlobalHotKeys` object, immediately creating its
+	/// hidden window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	#[must_use]
+	pub fn new() -> AnyResult<Self> {
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					raw_base: RawBase::new(None::<&WindowMain>),
+					hotkeys: UnsafeCell::new(Vec::new()),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers();
+		new_self.create()?;
+		Ok(new_self)
+	}
+
+	fn create(&self) -> AnyResult<()> {
+		let hinst = HINSTANCE::GetModuleHandle(None)?;
+		let mut wcx = WNDCLASSEX::default();
+		let mut class_name_buf = WString::new();
+		RawBase::fill_wndclassex(
+			&hinst,
+			"", co::CS::default(),
+			&Icon::None, &Icon::None,
+			&Brush::None, &Cursor::None, &mut wcx,
+			&mut class_name_buf)?;
+		let atom = self.0.raw_base.register_class(&mut wcx)?;
+
+		self.0.raw_base.create_window(
+			None, // top-level, because message-only windows don't receive WM_HOTKEY
+			atom, None, IdMenu::None,
+			POINT::default(), SIZE::default(),
+			co::WS_EX::TOOLWINDOW, co::WS::POPUP, // not visible, not in taskbar
+		)?;
+
+		Ok(())
+	}
+
+	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.0.raw_base.base().before_user_on().wm(co::WM::HOTKEY, move |p| {
+			let id = p.wparam as i32;
+			let func = unsafe { &*self2.0.hotkeys.get() }.iter()
+				.find(|(hotkey_id, _)| *hotkey_id == id)
+				.map(|(_, func)| func.clone()); // the closure may add or remove hotkeys
+			if let Some(func) = func {
+				func()?;
+			}
+			Ok(WmRet::HandledOk)
+		});
+
+		let self2 = self.clone();
+		self.0.raw_base.base().before_user_on().wm_destroy(move || {
+			let hotkeys = unsafe { &mut *self2.0.hotkeys.get() };
+			let mut first_err = None;
+			for (id, _) in hotkeys.drain(..) {
+				if let Err(e) = self2.hwnd().UnregisterHotKey(id) {
+					first_err.get_or_insert(e); // keep unregistering the others
+				}
+			}
+			first_err.map_or(Ok(()), |e| Err(e.into()))
+		});
+
+		self.0.raw_base.base().before_user_on().wm_nc_destroy(move || {
+			PostQuitMessage(0);
+			Ok(())
+		});
+	}
+
+	/// Registers a global hotkey with
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey),
+	/// which will run the given closure when pressed.
+	///
+	/// Returns the ID of the hotkey, which can be passed to
+	/// [`remove`](crate::gui::GlobalHotKeys::remove).
+	///
+	/// Fails with
+	/// [`co::ERROR::HOTKEY_ALREADY_REGISTERED`](crate::co::ERROR::HOTKEY_ALREADY_REGISTERED)
+	/// if the key combination is already taken by another application.
+	pub fn add<F>(&self,
+		modifiers: co::MOD,
+		vkey_code: co::VK,
+		func: F,
+	) -> SysResult<i32>
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		let hotkeys = unsafe { &mut *self.0.hotkeys.get() };
+		let id = hotkeys.iter()
+			.map(|(id, _)| *id)
+			.max()
+			.map_or(1, |max_id| max_id + 1);
+
+		self.hwnd().RegisterHotKey(id, modifiers, vkey_code)?;
+		hotkeys.push((id, Rc::new(func)));
+		Ok(id)
+	}
+
+	/// Unregisters a global hotkey previously registered with
+	/// [`add`](crate::gui::GlobalHotKeys::add), with
+	/// [`HWND::UnregisterHotKey`](crate::prelude::user_Hwnd::UnregisterHotKey).
+	pub fn remove(&self, id: i32) -> SysResult<()> {
+		let hotkeys = unsafe { &mut *self.0.hotkeys.get() };
+		match hotkeys.iter().position(|(hotkey_id, _)| *hotkey_id == id) {
+			Some(idx) => {
+				self.hwnd().UnregisterHotKey(id)?;
+				hotkeys.remove(idx);
+				Ok(())
+			},
+			None => Err(co::ERROR::HOTKEY_NOT_REGISTERED),
+		}
+	}
+
+	/// Runs the message loop, dispatching the hotkeys to their closures. This
+	/// method will block until [`stop`](crate::gui::GlobalHotKeys::stop) is
+	/// called.
+	///
+	/// Returns the exit code passed to
+	/// [`PostQuitMessage`](crate::PostQuitMessage), or an error if one of the
+	/// closures failed.
+	pub fn run_loop(&self) -> AnyResult<i32> {
+		Base::run_main_loop(None, false)
+	}
+
+	/// Destroys the hidden window, unregistering all hotkeys, and makes
+	/// [`run_loop`](crate::gui::GlobalHotKeys::run_loop) return.
+	pub fn stop(&self) -> SysResult<()> {
+		self.hwnd().DestroyWindow()
+	}
+}
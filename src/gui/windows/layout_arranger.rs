@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use crate::co;
 use crate::decl::*;
-use crate::gui::*;
+use crate::gui::{*, privs::*};
 use crate::msg::*;
 use crate::prelude::*;
 
@@ -108,7 +108,7 @@ impl LayoutArranger {
 			let rc_orig = match &ctrl.rc_orig {
 				Some(rc) => *rc,
 				None => {
-					let rc = ctrl.hchild.GetParent()?
+					let rc = parent_of(&ctrl.hchild)?
 						.ScreenToClientRc(ctrl.hchild.GetWindowRect()?)?;
 					ctrl.rc_orig = Some(rc); // save control client coordinates relative to parent
 					rc
@@ -1,11 +1,13 @@
 //! Windows and dialogs.
 
 mod base;
+mod clipboard_monitor;
 mod dlg_base;
 mod dlg_control;
 mod dlg_main;
 mod dlg_modal;
 mod dlg_modeless;
+mod global_hot_keys;
 mod layout_arranger;
 mod msg_error;
 mod raw_base;
@@ -13,6 +15,8 @@ mod raw_control;
 mod raw_main;
 mod raw_modal;
 mod raw_modeless;
+mod render_target;
+mod splitter;
 mod window_control;
 mod window_main;
 mod window_message_only;
@@ -35,11 +39,15 @@ pub(in crate::gui) mod privs {
 }
 
 pub mod decl {
+	pub use super::clipboard_monitor::ClipboardMonitor;
+	pub use super::global_hot_keys::GlobalHotKeys;
 	pub use super::msg_error::MsgError;
 	pub use super::raw_control::WindowControlOpts;
 	pub use super::raw_main::WindowMainOpts;
 	pub use super::raw_modal::WindowModalOpts;
 	pub use super::raw_modeless::WindowModelessOpts;
+	pub use super::render_target::{RenderTarget, RenderTargetOpts};
+	pub use super::splitter::{Splitter, SplitterOpts};
 	pub use super::window_control::WindowControl;
 	pub use super::window_main::WindowMain;
 	pub use super::window_message_only::WindowMessageOnly;
@@ -182,6 +182,7 @@ impl RawBase {
 		let at_least_one_after_user = ref_self.base.process_after_user_messages(wm_any)?;
 
 		if wm_any.msg_id == co::WM::NCDESTROY { // always check
+			ref_self.base.discard_pending_ui_data(); // data posted but never processed
 			unsafe { hwnd.SetWindowLongPtr(co::GWLP::USERDATA, 0); } // clear passed pointer
 			ref_self.base.set_hwnd(HWND::NULL); // clear stored HWND
 			ref_self.base.clear_events(); // prevents circular references
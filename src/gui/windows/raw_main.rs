@@ -206,7 +206,7 @@ pub struct WindowMainOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	/// Use
 	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable)
-	/// to create one.
+	/// or the [`Accelerators`](crate::Accelerators) builder to create one.
 	///
 	/// Defaults to `None`.
 	pub accel_table: Option<DestroyAcceleratorTableGuard>,
@@ -1,4 +1,4 @@
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -12,6 +12,7 @@ struct Obj { // actual fields of RawModal
 	raw_base: RawBase,
 	opts: WindowModalOpts,
 	hchild_prev_focus_parent: UnsafeCell<HWND>,
+	ret_code: Cell<i32>, // set by end_dialog()
 	_pin: PhantomPinned,
 }
 
@@ -34,6 +35,7 @@ impl RawModal {
 					raw_base: RawBase::new(Some(parent)),
 					opts,
 					hchild_prev_focus_parent: UnsafeCell::new(HWND::NULL),
+					ret_code: Cell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -62,6 +64,7 @@ impl RawModal {
 			&mut class_name_buf)?;
 		let atom = self.0.raw_base.register_class(&mut wcx)?;
 
+		self.0.ret_code.set(0);
 		*unsafe { &mut *self.0.hchild_prev_focus_parent.get() } =
 			HWND::GetFocus().unwrap_or(HWND::NULL);
 		hparent.EnableWindow(false); // https://devblogs.microsoft.com/oldnewthing/20040227-00/?p=40463
@@ -107,11 +110,11 @@ impl RawModal {
 				// https://devblogs.microsoft.com/oldnewthing/20050222-00/?p=36393
 				// https://stackoverflow.com/a/29359913/6923555
 				PostQuitMessage(msg.wParam as _);
-				return Ok(0); // raw modals will always return 0
+				return Ok(0);
 			}
 
 			if *self.base().hwnd() == HWND::NULL || !self.base().hwnd().IsWindow() {
-				return Ok(0); // our modal was destroyed, terminate loop
+				return Ok(self.0.ret_code.get()); // our modal was destroyed, terminate loop
 			}
 
 			// If a child window, will retrieve its top-level parent.
@@ -123,7 +126,7 @@ impl RawModal {
 			if hwnd_top_level.IsDialogMessage(&mut msg) {
 				// Processed all keyboard actions for child controls.
 				if *self.base().hwnd() == HWND::NULL {
-					return Ok(0); // our modal was destroyed, terminate loop
+					return Ok(self.0.ret_code.get()); // our modal was destroyed, terminate loop
 				} else {
 					continue;
 				}
@@ -133,7 +136,7 @@ impl RawModal {
 			unsafe { DispatchMessage(&msg); }
 
 			if *self.base().hwnd() == HWND::NULL || !self.base().hwnd().IsWindow() {
-				return Ok(0); // our modal was destroyed, terminate loop
+				return Ok(self.0.ret_code.get()); // our modal was destroyed, terminate loop
 			}
 		}
 	}
@@ -147,17 +150,27 @@ impl RawModal {
 
 		let self2 = self.clone();
 		self.base().on().wm_close(move || {
-			if let Ok(hparent) = self2.base().hwnd().GetWindow(co::GW::OWNER) {
-				hparent.EnableWindow(true); // re-enable parent
-				self2.base().hwnd().DestroyWindow()?; // then destroy modal
-				let hchild_prev_focus_parent = unsafe { &mut *self2.0.hchild_prev_focus_parent.get() };
-				if *hchild_prev_focus_parent != HWND::NULL {
-					hchild_prev_focus_parent.SetFocus(); // this focus could be set on WM_DESTROY as well
-				}
-			}
+			self2.close()?;
 			Ok(())
 		});
 	}
+
+	pub(in crate::gui) fn end_dialog(&self, ret_code: i32) -> SysResult<()> {
+		self.0.ret_code.set(ret_code);
+		self.close()
+	}
+
+	fn close(&self) -> SysResult<()> {
+		if let Ok(hparent) = self.base().hwnd().GetWindow(co::GW::OWNER) {
+			hparent.EnableWindow(true); // re-enable parent
+			self.base().hwnd().DestroyWindow()?; // then destroy modal
+			let hchild_prev_focus_parent = unsafe { &mut *self.0.hchild_prev_focus_parent.get() };
+			if *hchild_prev_focus_parent != HWND::NULL {
+				hchild_prev_focus_parent.SetFocus(); // this focus could be set on WM_DESTROY as well
+			}
+		}
+		Ok(())
+	}
 }
 
 //------------------------------------------------------------------------------
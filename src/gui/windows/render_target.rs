@@ -0,0 +1,187 @@
+use std::any::Any;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::prelude::*;
+
+/// A child window meant to be a rendering surface for OpenGL or Direct3D.
+///
+/// The window class is registered with
+/// [`co::CS::OWNDC`](crate::co::CS::OWNDC), so the window has a private device
+/// context which persists across paints – this is required by OpenGL's
+/// `wglMakeCurrent`. There is no background brush, so the background is never
+/// erased.
+///
+/// The control never calls
+/// [`HWND::BeginPaint`](crate::prelude::user_Hwnd::BeginPaint), which would
+/// interfere with the rendering context. Instead, after your
+/// [`wm_paint`](crate::gui::events::WindowEvents::wm_paint) closure runs, the
+/// whole client area is validated with
+/// [`HWND::ValidateRect`](crate::prelude::user_Hwnd::ValidateRect), so Windows
+/// stops sending `WM_PAINT` messages.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let surface = gui::RenderTarget::new(&wnd, gui::RenderTargetOpts {
+///     size: (300, 200),
+///     ..Default::default()
+/// });
+///
+/// surface.on().wm_create({
+///     let surface = surface.clone();
+///     move |_| -> w::AnyResult<i32> {
+///         let hdc = surface.hdc()?;
+///         // attach your rendering context to hdc...
+///         Ok(0)
+///     }
+/// });
+///
+/// surface.on().wm_paint(move || -> w::AnyResult<()> {
+///     // render a frame...
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct RenderTarget(WindowControl);
+
+unsafe impl Send for RenderTarget {}
+
+impl AsRef<Base> for RenderTarget {
+	fn as_ref(&self) -> &Base {
+		self.0.as_ref()
+	}
+}
+
+impl GuiWindow for RenderTarget {
+	fn hwnd(&self) -> &HWND {
+		self.0.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiParent for RenderTarget {}
+
+impl GuiChild for RenderTarget {
+	fn ctrl_id(&self) -> u16 {
+		self.0.ctrl_id()
+	}
+}
+
+impl RenderTarget {
+	/// Instantiates a new `RenderTarget` object, to be created internally with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `RenderTarget` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: RenderTargetOpts) -> Self {
+		let new_self = Self(
+			WindowControl::new(parent, WindowControlOpts {
+				class_style: co::CS::OWNDC | co::CS::HREDRAW | co::CS::VREDRAW,
+				class_bg_brush: Brush::None,
+				position: opts.position,
+				size: opts.size,
+				style: opts.style,
+				ex_style: opts.ex_style,
+				ctrl_id: opts.ctrl_id,
+				resize_behavior: opts.resize_behavior,
+				..Default::default()
+			}),
+		);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.as_ref().after_user_on().wm_paint(move || {
+			self2.hwnd().ValidateRect(None)?; // we don't paint, just stop WM_PAINT
+			Ok(())
+		});
+	}
+
+	/// Returns the private device context of the window, retrieved with
+	/// [`HWND::GetDC`](crate::prelude::user_Hwnd::GetDC).
+	///
+	/// Since the window class has [`co::CS::OWNDC`](crate::co::CS::OWNDC), the
+	/// device context is the same during the whole life of the window, and it
+	/// doesn't need to be released. It must be called only after the window is
+	/// created.
+	pub fn hdc(&self) -> SysResult<HDC> {
+		self.hwnd().GetDC().map(|mut hdc| hdc.leak())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`RenderTarget`](crate::gui::RenderTarget)
+/// programmatically with [`RenderTarget::new`](crate::gui::RenderTarget::new).
+pub struct RenderTargetOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of window to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(100, 80)`.
+	pub size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::CLIPCHILDREN | WS::CLIPSIBLINGS`.
+	///
+	/// Note that `WS::CLIPCHILDREN` and `WS::CLIPSIBLINGS` are required by
+	/// OpenGL.
+	pub style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for RenderTargetOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (100, 80),
+			style: co::WS::CHILD | co::WS::VISIBLE | co::WS::CLIPCHILDREN | co::WS::CLIPSIBLINGS,
+			ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
@@ -0,0 +1,410 @@
+use std::any::Any;
+use std::cell::{Cell, UnsafeCell};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::prelude::*;
+
+type MovedFn = Rc<dyn Fn(f32) -> AnyResult<()>>;
+
+struct Obj { // actual fields of Splitter
+	wnd: WindowControl,
+	orientation: SplitterOrientation,
+	panes: (u16, u16), // control IDs of the first and second panes
+	min_pane_size: u32,
+	drag_offset: Cell<Option<i32>>, // cursor offset within the splitter, while dragging
+	ratio: Cell<Option<f32>>, // set once the splitter is moved
+	func: UnsafeCell<Option<MovedFn>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// A draggable divider which sits between two child controls – the panes –
+/// resizing them when moved by the user.
+///
+/// The two panes must be children of the same parent window, and they must be
+/// placed at each side of the splitter: left and right for a
+/// [`Horizontal`](crate::gui::SplitterOrientation::Horizontal) splitter, top
+/// and bottom for a
+/// [`Vertical`](crate::gui::SplitterOrientation::Vertical) one.
+///
+/// Once the splitter is moved, the proportion between the panes is kept when
+/// the parent window is resized. So, if the panes are resized by the parent,
+/// the first pane usually has no resize behavior, while the splitter and the
+/// second pane follow the first one.
+///
+/// # Examples
+///
+/// A file manager layout, with a tree at left and a list at right:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let tree = gui::TreeView::<()>::new(&wnd, gui::TreeViewOpts {
+///     position: (0, 0),
+///     size: (200, 400),
+///     resize_behavior: (gui::Horz::None, gui::Vert::Resize),
+///     ..Default::default()
+/// });
+/// let list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts {
+///     position: (206, 0),
+///     size: (394, 400),
+///     resize_behavior: (gui::Horz::Resize, gui::Vert::Resize),
+///     ..Default::default()
+/// });
+///
+/// let splitter = gui::Splitter::new(&wnd, &tree, &list, gui::SplitterOpts {
+///     position: (200, 0),
+///     size: (6, 400),
+///     resize_behavior: (gui::Horz::None, gui::Vert::Resize),
+///     ..Default::default()
+/// });
+///
+/// splitter.on_moved(|ratio| {
+///     println!("Tree now takes {:.0}%", ratio * 100.0);
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Splitter(Pin<Arc<Obj>>);
+
+unsafe impl Send for Splitter {}
+
+impl AsRef<Base> for Splitter {
+	fn as_ref(&self) -> &Base {
+		self.0.wnd.as_ref()
+	}
+}
+
+impl GuiWindow for Splitter {
+	fn hwnd(&self) -> &HWND {
+		self.0.wnd.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Splitter {
+	fn ctrl_id(&self) -> u16 {
+		self.0.wnd.ctrl_id()
+	}
+}
+
+impl Splitter {
+	/// Instantiates a new `Splitter` object, to be created internally with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx),
+	/// between the two given panes.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Splitter` in an event closure.
+	#[must_use]
+	pub fn new(
+		parent: &impl GuiParent,
+		first_pane: &impl GuiChild,
+		second_pane: &impl GuiChild,
+		opts: SplitterOpts,
+	) -> Self
+	{
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					wnd: WindowControl::new(parent, WindowControlOpts {
+						class_cursor: Cursor::Idc(match opts.orientation {
+							SplitterOrientation::Horizontal => co::IDC::SIZEWE,
+							SplitterOrientation::Vertical => co::IDC::SIZENS,
+						}),
+						class_bg_brush: Brush::Color(co::COLOR::BTNFACE),
+						position: opts.position,
+						size: opts.size,
+						style: opts.style,
+						ex_style: opts.ex_style,
+						ctrl_id: opts.ctrl_id,
+						resize_behavior: opts.resize_behavior,
+						..Default::default()
+					}),
+					orientation: opts.orientation,
+					panes: (first_pane.ctrl_id(), second_pane.ctrl_id()),
+					min_pane_size: opts.min_pane_size,
+					drag_offset: Cell::new(None),
+					ratio: Cell::new(None),
+					func: UnsafeCell::new(None),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers(parent);
+		new_self
+	}
+
+	fn default_message_handlers(&self, parent: &impl GuiParent) {
+		let self2 = self.clone();
+		self.as_ref().before_user_on().wm_l_button_down(move |p| {
+			let (offset, _) = self2.axis(p.coords.x, p.coords.y);
+			self2.0.drag_offset.set(Some(offset));
+			std::mem::forget(self2.hwnd().SetCapture()); // released at WM_LBUTTONUP
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.as_ref().before_user_on().wm_mouse_move(move |_| {
+			if let Some(offset) = self2.0.drag_offset.get() {
+				let pt = parent_of(self2.hwnd())?.ScreenToClient(GetCursorPos()?)?; // mouse coords may be negative
+				let (cursor, _) = self2.axis(pt.x, pt.y);
+				let ratio = self2.place(|_, _| cursor - offset)?;
+				self2.0.ratio.set(Some(ratio));
+
+				let func = unsafe { &*self2.0.func.get() }.clone(); // the closure may replace itself
+				if let Some(func) = func {
+					func(ratio)?;
+				}
+			}
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.as_ref().before_user_on().wm_l_button_up(move |_| {
+			if self2.0.drag_offset.get().is_some() {
+				ReleaseCapture()?;
+			}
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		self.as_ref().before_user_on().wm_capture_changed(move |_| {
+			self2.0.drag_offset.set(None); // drag finished or cancelled
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		parent.as_ref().before_user_on().wm_size(move |p| { // runs after the layout arranger
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				if let Some(ratio) = self2.0.ratio.get() {
+					self2.place_ratio(ratio)?;
+				}
+			}
+			Ok(())
+		});
+	}
+
+	/// Returns the coordinates ordered as (along the splitting axis, across).
+	#[must_use]
+	fn axis(&self, x: i32, y: i32) -> (i32, i32) {
+		match self.0.orientation {
+			SplitterOrientation::Horizontal => (x, y),
+			SplitterOrientation::Vertical => (y, x),
+		}
+	}
+
+	/// Returns the start and end coordinates of the rectangle along the
+	/// splitting axis.
+	#[must_use]
+	fn span(&self, rc: &RECT) -> (i32, i32) {
+		match self.0.orientation {
+			SplitterOrientation::Horizontal => (rc.left, rc.right),
+			SplitterOrientation::Vertical => (rc.top, rc.bottom),
+		}
+	}
+
+	/// Sets the start and end coordinates of the rectangle along the splitting
+	/// axis.
+	fn set_span(&self, rc: &mut RECT, start: i32, end: i32) {
+		match self.0.orientation {
+			SplitterOrientation::Horizontal => (rc.left, rc.right) = (start, end),
+			SplitterOrientation::Vertical => (rc.top, rc.bottom) = (start, end),
+		}
+	}
+
+	/// Moves the splitter to the position returned by the closure, which
+	/// receives the start of the first pane and the space available for both
+	/// panes. The panes are resized accordingly, and the new ratio is returned.
+	fn place<F>(&self, calc_pos: F) -> SysResult<f32>
+		where F: FnOnce(i32, i32) -> i32,
+	{
+		let hparent = parent_of(self.hwnd())?;
+		let hfirst = hparent.GetDlgItem(self.0.panes.0)?;
+		let hsecond = hparent.GetDlgItem(self.0.panes.1)?;
+
+		let mut rc_first = hparent.ScreenToClientRc(hfirst.GetWindowRect()?)?;
+		let mut rc_me = hparent.ScreenToClientRc(self.hwnd().GetWindowRect()?)?;
+		let mut rc_second = hparent.ScreenToClientRc(hsecond.GetWindowRect()?)?;
+
+		let mut min_sz = SIZE::new(self.0.min_pane_size as _, self.0.min_pane_size as _);
+		multiply_dpi_or_dtu(self.as_ref().parent().unwrap(), None, Some(&mut min_sz))?;
+		let (min_pane, _) = self.axis(min_sz.cx, min_sz.cy);
+
+		let (start, _) = self.span(&rc_first);
+		let (_, end) = self.span(&rc_second);
+		let (me_start, me_end) = self.span(&rc_me);
+		let thickness = me_end - me_start;
+		let available = end - start - thickness;
+
+		let pos = calc_pos(start, available)
+			.min(end - thickness - min_pane)
+			.max(start + min_pane);
+
+		self.set_span(&mut rc_first, start, pos);
+		self.set_span(&mut rc_me, pos, pos + thickness);
+		self.set_span(&mut rc_second, pos + thickness, end);
+
+		let mut hdwp = HDWP::BeginDeferWindowPos(3)?;
+		for (hwnd, rc) in [(&hfirst, rc_first), (self.hwnd(), rc_me), (&hsecond, rc_second)] {
+			hdwp.DeferWindowPos(
+				hwnd,
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			)?;
+		}
+
+		Ok(if available > 0 {
+			(pos - start) as f32 / available as f32
+		} else {
+			0.0
+		})
+	}
+
+	/// Moves the splitter so the first pane takes the given proportion of the
+	/// available space.
+	fn place_ratio(&self, ratio: f32) -> SysResult<f32> {
+		self.place(|start, available| {
+			start + (available as f32 * ratio.clamp(0.0, 1.0)).round() as i32
+		})
+	}
+
+	/// Sets the closure to be called whenever the user drags the splitter,
+	/// replacing the previous one, if any.
+	///
+	/// The closure receives the new ratio, which is the proportion of the
+	/// available space taken by the first pane, from `0.0` to `1.0`.
+	pub fn on_moved<F>(&self, func: F)
+		where F: Fn(f32) -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.0.func.get() } = Some(Rc::new(func));
+	}
+
+	/// Returns the current ratio, which is the proportion of the available
+	/// space taken by the first pane, from `0.0` to `1.0`.
+	///
+	/// Must be called after the control is created.
+	pub fn ratio(&self) -> SysResult<f32> {
+		let hparent = parent_of(self.hwnd())?;
+		let rc_first = hparent.GetDlgItem(self.0.panes.0)?.GetWindowRect()?;
+		let rc_me = self.hwnd().GetWindowRect()?;
+		let rc_second = hparent.GetDlgItem(self.0.panes.1)?.GetWindowRect()?;
+
+		let (start, _) = self.span(&rc_first);
+		let (pos, pos_end) = self.span(&rc_me);
+		let (_, end) = self.span(&rc_second);
+		let available = end - start - (pos_end - pos);
+
+		Ok(if available > 0 {
+			(pos - start) as f32 / available as f32
+		} else {
+			0.0
+		})
+	}
+
+	/// Moves the splitter so the first pane takes the given proportion of the
+	/// available space, from `0.0` to `1.0`, resizing both panes. The
+	/// proportion is then kept when the parent window is resized.
+	///
+	/// The [`on_moved`](crate::gui::Splitter::on_moved) closure is not called.
+	///
+	/// Must be called after the control is created.
+	pub fn set_ratio(&self, ratio: f32) -> SysResult<()> {
+		let ratio = self.place_ratio(ratio)?;
+		self.0.ratio.set(Some(ratio));
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Splitter`](crate::gui::Splitter) programmatically
+/// with [`Splitter::new`](crate::gui::Splitter::new).
+pub struct SplitterOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// The thickness of the splitter is its width, if horizontal, or its
+	/// height, if vertical.
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(6, 100)`.
+	pub size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::CLIPSIBLINGS`.
+	pub style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub ex_style: co::WS_EX,
+
+	/// How the panes are laid out around the splitter.
+	///
+	/// Defaults to `gui::SplitterOrientation::Horizontal`.
+	pub orientation: SplitterOrientation,
+	/// The minimum size of each pane, along the splitting axis, when the user
+	/// drags the splitter.
+	///
+	/// If the parent window is a dialog, the value is in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `20`.
+	pub min_pane_size: u32,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for SplitterOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (6, 100),
+			style: co::WS::CHILD | co::WS::VISIBLE | co::WS::CLIPSIBLINGS,
+			ex_style: co::WS_EX::LEFT,
+			orientation: SplitterOrientation::Horizontal,
+			min_pane_size: 20,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
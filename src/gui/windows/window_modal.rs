@@ -69,16 +69,10 @@ impl WindowModal {
 	/// Physically creates the window, then runs the modal loop. This method
 	/// will block until the window is closed.
 	///
-	/// For a modal created with
-	/// [`WindowModal::new`](crate::gui::WindowModal::new), the returned `i32`
-	/// is always zero.
-	///
-	/// For a modal created with
-	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), the returned
-	/// `i32` is the value passed to
-	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog). Note that, if
-	/// the user clicks the "X" to close the modal, the default behavior is to
-	/// call `EndDialog(0)`. To override this behavior, handle the
+	/// The returned `i32` is the value passed to
+	/// [`WindowModal::end_dialog`](crate::gui::WindowModal::end_dialog). Note
+	/// that, if the user clicks the "X" to close the modal, the default
+	/// behavior is to return zero. To override this behavior, handle the
 	/// modal's [`wm_close`](crate::gui::events::WindowEvents::wm_close)
 	/// yourself.
 	///
@@ -91,4 +85,43 @@ impl WindowModal {
 			RawDlg::Dlg(d) => d.show_modal(),
 		}
 	}
+
+	/// Closes the modal, making
+	/// [`WindowModal::show_modal`](crate::gui::WindowModal::show_modal) return
+	/// the given value.
+	///
+	/// For a modal created with
+	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), calls
+	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog). For a modal
+	/// created with [`WindowModal::new`](crate::gui::WindowModal::new),
+	/// re-enables the parent and destroys the modal.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let modal = gui::WindowModal::new(&wnd, gui::WindowModalOpts::default());
+	/// let btn_ok = gui::Button::new(&modal, gui::ButtonOpts::default());
+	///
+	/// let modal2 = modal.clone();
+	/// btn_ok.on().bn_clicked(move || {
+	///     modal2.end_dialog(1)?; // show_modal() will return 1
+	///     Ok(())
+	/// });
+	///
+	/// if modal.show_modal()? == 1 {
+	///     println!("OK clicked.");
+	/// }
+	/// # w::AnyResult::Ok(())
+	/// ```
+	pub fn end_dialog(&self, ret_code: i32) -> SysResult<()> {
+		match &self.0 {
+			RawDlg::Raw(r) => r.end_dialog(ret_code),
+			RawDlg::Dlg(d) => d.end_dialog(ret_code),
+		}
+	}
 }
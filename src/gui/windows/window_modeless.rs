@@ -1,5 +1,8 @@
 use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
 
+use crate::co;
 use crate::decl::*;
 use crate::gui::{*, privs::*};
 use crate::prelude::*;
@@ -97,4 +100,90 @@ impl WindowModeless {
 			),
 		)
 	}
+
+	/// Keeps the window docked to its owner: whenever the owner moves, the
+	/// window is moved to the owner's top-left corner plus `offset`, in
+	/// pixels.
+	///
+	/// When the owner is minimized the window is hidden, and when the owner is
+	/// restored the window is shown again – unless it was already hidden
+	/// before the owner was minimized.
+	///
+	/// # Panics
+	///
+	/// Panics if the owner window was already created.
+	///
+	/// # Examples
+	///
+	/// A tool palette docked to the right edge of the main window:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd = gui::WindowMain::new(
+	///     gui::WindowMainOpts {
+	///         size: (600, 400),
+	///         ..Default::default()
+	///     },
+	/// );
+	/// let palette = gui::WindowModeless::new(
+	///     &wnd,
+	///     gui::WindowModelessOpts::default(),
+	/// );
+	///
+	/// palette.follow_owner(w::POINT::new(610, 0));
+	/// ```
+	pub fn follow_owner(&self, offset: POINT) {
+		let owner = self.as_ref().parent().unwrap(); // modeless windows always have an owner
+		if *owner.hwnd() != HWND::NULL {
+			panic!("Cannot follow the owner after its creation.");
+		}
+
+		let self2 = self.clone();
+		owner.before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.move_to_owner(offset)?; // initial position
+			Ok(WmRet::NotHandled)
+		});
+
+		let self2 = self.clone();
+		owner.before_user_on().wm_move(move |_| {
+			self2.move_to_owner(offset)?;
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		let hidden_by_owner = Rc::new(Cell::new(false));
+		owner.before_user_on().wm_size(move |p| {
+			let hwnd = self2.hwnd();
+			if !hwnd.IsWindow() {
+				return Ok(()); // already destroyed
+			}
+			match p.request {
+				co::SIZE_R::MINIMIZED if hwnd.IsWindowVisible() => {
+					hwnd.ShowWindow(co::SW::HIDE);
+					hidden_by_owner.set(true);
+				},
+				co::SIZE_R::RESTORED | co::SIZE_R::MAXIMIZED if hidden_by_owner.get() => {
+					hwnd.ShowWindow(co::SW::SHOWNOACTIVATE);
+					hidden_by_owner.set(false);
+				},
+				_ => {},
+			}
+			Ok(())
+		});
+	}
+
+	fn move_to_owner(&self, offset: POINT) -> SysResult<()> {
+		let hwnd = self.hwnd();
+		if !hwnd.IsWindow() {
+			return Ok(()); // already destroyed
+		}
+		let rc_owner = hwnd.GetWindow(co::GW::OWNER)?.GetWindowRect()?;
+		hwnd.SetWindowPos(
+			HwndPlace::None,
+			POINT::new(rc_owner.left + offset.x, rc_owner.top + offset.y),
+			SIZE::default(),
+			co::SWP::NOZORDER | co::SWP::NOSIZE | co::SWP::NOACTIVATE,
+		)
+	}
 }
@@ -14,6 +14,35 @@ const_bitflag! { ACCESS_RIGHTS: u32;
 	SYNCHRONIZE 0x0010_0000
 }
 
+const_ordinary! { AC_LINE: u8;
+	/// [`SYSTEM_POWER_STATUS`](crate::SYSTEM_POWER_STATUS) `ACLineStatus`
+	/// (`u8`).
+	=>
+	OFFLINE 0
+	ONLINE 1
+	BACKUP_POWER 2
+	UNKNOWN 255
+}
+
+const_bitflag! { BATTERY_FLAG: u8;
+	/// [`SYSTEM_POWER_STATUS`](crate::SYSTEM_POWER_STATUS) `BatteryFlag`
+	/// (`u8`).
+	=>
+	/// The battery is not being charged and its capacity is between low and
+	/// high.
+	NoValue 0
+	/// The battery capacity is at more than 66 percent.
+	HIGH 1
+	/// The battery capacity is at less than 33 percent.
+	LOW 2
+	/// The battery capacity is at less than 5 percent.
+	CRITICAL 4
+	CHARGING 8
+	NO_BATTERY 128
+	/// Unable to read the battery flag information.
+	UNKNOWN 255
+}
+
 const_bitflag! { CLAIM_SECURITY_ATTRIBUTE: u16;
 	/// [`CLAIM_SECURITY_ATTRIBUTE_V1`](crate::CLAIM_SECURITY_ATTRIBUTE_V1)
 	/// `Flags` (`u16`);
@@ -85,6 +114,18 @@ const_ordinary! { CP: u16;
 	/// Symbol code page (42).
 	SYMBOL 42
 
+	/// ANSI/OEM Thai (ISO 8859-11); Thai (Windows).
+	WINDOWS_874 874
+	/// ANSI/OEM Japanese; Japanese (Shift-JIS).
+	SHIFT_JIS 932
+	/// ANSI/OEM Simplified Chinese (PRC, Singapore); Chinese Simplified
+	/// (GB2312).
+	GB2312 936
+	/// ANSI/OEM Korean (Unified Hangul Code).
+	KS_C_5601_1987 949
+	/// ANSI/OEM Traditional Chinese (Taiwan; Hong Kong SAR, PRC); Chinese
+	/// Traditional (Big5).
+	BIG5 950
 	/// Unicode UTF-16 little endian byte order (BMP of ISO 10646); available
 	/// only to managed applications.
 	UTF16 1200
@@ -282,6 +323,16 @@ const_bitflag! { EVENT_RIGHTS: u32;
 	MODIFY_STATE 0x0002
 }
 
+const_bitflag! { EXECUTION_STATE: u32;
+	/// [`SetThreadExecutionState`](crate::SetThreadExecutionState) `flags`
+	/// (`u32`).
+	=>
+	AWAYMODE_REQUIRED 0x0000_0040
+	CONTINUOUS 0x8000_0000
+	DISPLAY_REQUIRED 0x0000_0002
+	SYSTEM_REQUIRED 0x0000_0001
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File
 	/// [attributes](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants)
@@ -327,6 +378,9 @@ const_bitflag! { FILE_FLAG: u32;
 	=>
 	BACKUP_SEMANTICS 0x0200_0000
 	DELETE_ON_CLOSE 0x0400_0000
+	/// Used only with
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe).
+	FIRST_PIPE_INSTANCE 0x0008_0000
 	NO_BUFFERING 0x2000_0000
 	OPEN_NO_RECALL 0x0010_0000
 	OPEN_REPARSE_POINT 0x0020_0000
@@ -525,6 +579,16 @@ const_bitflag! { GR: u32;
 	USEROBJECTS_PEAK 4
 }
 
+const_bitflag! { HANDLE_FLAG: u32;
+	/// [`HPIPE::SetHandleInformation`](crate::prelude::kernel_Hpipe::SetHandleInformation)
+	/// `mask` and `flags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	INHERIT 0x0000_0001
+	PROTECT_FROM_CLOSE 0x0000_0002
+}
+
 const_bitflag! { HEAP_ALLOC: u32;
 	/// [`HHEAP::HeapAlloc`](crate::prelude::kernel_Hheap::HeapAlloc) `flags`
 	/// (`u32`).
@@ -878,6 +942,28 @@ const_ordinary! { PBT: u32;
 	POWERSETTINGCHANGE 0x8013
 }
 
+const_bitflag! { PIPE: u32;
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// `pipe_mode` (`u32`).
+	=>
+	/// Data is written as a stream of bytes; also `PIPE_READMODE_BYTE` and
+	/// `PIPE_WAIT`.
+	TYPE_BYTE 0x0000_0000
+	TYPE_MESSAGE 0x0000_0004
+	READMODE_MESSAGE 0x0000_0002
+	NOWAIT 0x0000_0001
+	REJECT_REMOTE_CLIENTS 0x0000_0008
+}
+
+const_ordinary! { PIPE_ACCESS: u32;
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// `open_mode` (`u32`).
+	=>
+	INBOUND 0x0000_0001
+	OUTBOUND 0x0000_0002
+	DUPLEX 0x0000_0003
+}
+
 const_bitflag! { PRIORITY_CLASS: u32;
 	/// [`GetPriorityClass`](crate::prelude::kernel_Hprocess::GetPriorityClass)
 	/// and
@@ -1175,6 +1261,15 @@ const_bitflag! { SHTDN_REASON: u32; // used by InitiateSystemShutdownEx (advapi)
 	FLAG_PLANNED 0x8000_0000
 }
 
+const_bitflag! { SHUTDOWN: u32;
+	/// [`SetProcessShutdownParameters`](crate::SetProcessShutdownParameters)
+	/// `flags` (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	NORETRY 0x0000_0001
+}
+
 const_ordinary! { SORT: u16;
 	/// Sort order
 	/// [identifiers](https://learn.microsoft.com/en-us/windows/win32/intl/sort-order-identifiers)
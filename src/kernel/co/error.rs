@@ -1,5 +1,6 @@
 #![allow(non_snake_case, non_upper_case_globals)]
 
+use crate::kernel::privs::*;
 use crate::prelude::FormattedError;
 
 const_no_debug_display! { ERROR: u32;
@@ -32,7 +33,9 @@ const_no_debug_display! { ERROR: u32;
 	/// ```
 	///
 	/// The numbers inside the brackets are the system error code, in
-	/// hexadecimal and decimal formats for convenience.
+	/// hexadecimal and decimal formats for convenience. The description is
+	/// retrieved only when the error is formatted; if it cannot be retrieved,
+	/// only the numbers are shown.
 }
 
 impl std::error::Error for ERROR {
@@ -43,18 +46,12 @@ impl std::error::Error for ERROR {
 
 impl std::fmt::Display for ERROR {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "[{}] {}", self.0, self.FormatMessage().trim_end())
+		fmt_error_code(f, None, self.0)
 	}
 }
 impl std::fmt::Debug for ERROR {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		if self.0 > 0xffff {
-			write!(f, "ERROR({:#010x} {}) {}",
-				self.0, self.0, self.FormatMessage().trim_end())
-		} else {
-			write!(f, "ERROR({:#06x} {}) {}",
-				self.0, self.0, self.FormatMessage().trim_end())
-		}
+		fmt_error_code(f, Some("ERROR"), self.0)
 	}
 }
 
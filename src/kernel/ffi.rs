@@ -2,19 +2,24 @@ use crate::kernel::ffi_types::*;
 
 extern_sys! { "kernel32";
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
+	CallNamedPipeW(PCSTR, PCVOID, u32, PVOID, u32, *mut u32, u32) -> BOOL
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
+	ConnectNamedPipe(HANDLE, PVOID) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
 	CreateDirectoryW(PCSTR, PVOID) -> BOOL
 	CreateEventExW(PCVOID, PCSTR, u32, u32) -> HANDLE
 	CreateEventW(PCVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
+	CreateNamedPipeW(PCSTR, u32, u32, u32, u32, u32, u32, PVOID) -> HANDLE
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
 	CreateProcessW(PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
 	DeleteFileW(PCSTR) -> BOOL
+	DeleteProcThreadAttributeList(PVOID)
+	DisconnectNamedPipe(HANDLE) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
 	EnumResourceNamesW(HANDLE, PCSTR, PFUNC, isize) -> BOOL
@@ -29,6 +34,7 @@ extern_sys! { "kernel32";
 	FindResourceExW(HANDLE, PCSTR, PCSTR, u16) -> HANDLE
 	FindResourceW(HANDLE, PCSTR, PCSTR) -> HANDLE
 	FlushConsoleInputBuffer(HANDLE) -> BOOL
+	FlushFileBuffers(HANDLE) -> BOOL
 	FlushInstructionCache(HANDLE, PCVOID, usize) -> BOOL
 	FlushProcessWriteBuffers()
 	FlushViewOfFile(PVOID, usize) -> BOOL
@@ -78,12 +84,14 @@ extern_sys! { "kernel32";
 	GetProcessHeaps(u32, *mut HANDLE) -> u32
 	GetProcessId(HANDLE) -> u32
 	GetProcessIdOfThread(HANDLE) -> u32
+	GetProcessShutdownParameters(*mut u32, *mut u32) -> BOOL
 	GetProcessTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
 	GetStartupInfoW(PVOID)
 	GetStdHandle(u32) -> HANDLE
 	GetSystemDirectoryW(PSTR, u32) -> u32
 	GetSystemFileCacheSize(*mut usize, *mut usize, *mut u32) -> BOOL
 	GetSystemInfo(PVOID)
+	GetSystemPowerStatus(PVOID) -> BOOL
 	GetSystemTime(PVOID)
 	GetSystemTimeAsFileTime(PVOID)
 	GetSystemTimePreciseAsFileTime(PVOID)
@@ -117,6 +125,7 @@ extern_sys! { "kernel32";
 	HeapUnlock(HANDLE) -> BOOL
 	HeapValidate(HANDLE, u32, PVOID) -> BOOL
 	HeapWalk(HANDLE, PVOID) -> BOOL
+	InitializeProcThreadAttributeList(PVOID, u32, u32, *mut usize) -> BOOL
 	IsDebuggerPresent() -> BOOL
 	IsNativeVhdBoot(*mut BOOL) -> BOOL
 	IsProcessCritical(HANDLE, *mut BOOL) -> BOOL
@@ -166,10 +175,13 @@ extern_sys! { "kernel32";
 	SetFileAttributesW(PCSTR, u32) -> BOOL
 	SetFilePointerEx(HANDLE, i64, *mut i64, u32) -> BOOL
 	SetFileTime(HANDLE, PCVOID, PCVOID, PCVOID) -> BOOL
+	SetHandleInformation(HANDLE, u32, u32) -> BOOL
 	SetLastError(u32)
 	SetPriorityClass(HANDLE, u32) -> BOOL
 	SetProcessAffinityUpdateMode(HANDLE, u32) -> BOOL
 	SetProcessPriorityBoost(HANDLE, BOOL) -> BOOL
+	SetProcessShutdownParameters(u32, u32) -> BOOL
+	SetThreadExecutionState(u32) -> u32
 	SetThreadIdealProcessor(HANDLE, u32) -> u32
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
@@ -186,10 +198,12 @@ extern_sys! { "kernel32";
 	Thread32Next(HANDLE, PVOID) -> BOOL
 	UnlockFile(HANDLE, u32, u32, u32, u32) -> BOOL
 	UnmapViewOfFile(PCVOID) -> BOOL
+	UpdateProcThreadAttribute(PVOID, u32, usize, PCVOID, usize, PVOID, *mut usize) -> BOOL
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
 	WaitForSingleObject(HANDLE, u32) -> u32
+	WaitNamedPipeW(PCSTR, u32) -> BOOL
 	WideCharToMultiByte(u32, u32, PCSTR, i32, PSTR, i32, *const u8, *mut BOOL) -> i32
 	WriteConsoleW(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
 	WriteFile(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
@@ -201,7 +201,10 @@ pub fn GetBinaryType(application_name: &str) -> SysResult<co::SCS> {
 /// [`GetCommandLine`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getcommandlinew)
 /// function.
 ///
-/// For an example, see [`CommandLineToArgv`](crate::CommandLineToArgv).
+/// Returns the raw command line of the current process, including the program
+/// name, exactly as it was passed by the caller. To split it into arguments,
+/// use [`CommandLineToArgv`](crate::CommandLineToArgv), which requires the
+/// `shell` feature.
 #[must_use]
 pub fn GetCommandLine() -> String {
 	unsafe { WString::from_wchars_nullt(ffi::GetCommandLineW()) }
@@ -275,6 +278,28 @@ pub fn GetDriveType(root_path_name: Option<&str>) -> co::DRIVE {
 
 /// [`GetDiskFreeSpaceEx`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getdiskfreespaceexw)
 /// function.
+///
+/// If the drive is a removable media with no disk inserted, fails with
+/// [`co::ERROR::NOT_READY`](crate::co::ERROR::NOT_READY).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let (mut free_to_caller, mut total, mut total_free) = (0u64, 0u64, 0u64);
+/// match w::GetDiskFreeSpaceEx(
+///     Some("D:\\"),
+///     Some(&mut free_to_caller),
+///     Some(&mut total),
+///     Some(&mut total_free),
+/// ) {
+///     Ok(_) => println!("{} of {} bytes free", free_to_caller, total),
+///     Err(co::ERROR::NOT_READY) => println!("No disk inserted"),
+///     Err(e) => return Err(e),
+/// }
+/// # w::SysResult::Ok(())
+/// ```
 pub fn GetDiskFreeSpaceEx(
 	directory_name: Option<&str>,
 	free_bytes_available_to_caller: Option<&mut u64>,
@@ -464,6 +489,27 @@ pub fn GetLogicalDrives() -> u32 {
 
 /// [`GetLogicalDriveStrings`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getlogicaldrivestringsw)
 /// function.
+///
+/// Returns the root directories of the drives, like `C:\`.
+///
+/// # Examples
+///
+/// Listing all drives with their type and free space:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// for root in w::GetLogicalDriveStrings()? {
+///     let drive_type = w::GetDriveType(Some(&root));
+///     let mut free = 0u64;
+///     match w::GetDiskFreeSpaceEx(Some(&root), Some(&mut free), None, None) {
+///         Ok(_) => println!("{} {} - {} bytes free", root, drive_type, free),
+///         Err(co::ERROR::NOT_READY) => println!("{} {} - not ready", root, drive_type),
+///         Err(e) => return Err(e),
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
 #[must_use]
 pub fn GetLogicalDriveStrings() -> SysResult<Vec<String>> {
 	let len = match unsafe {
@@ -549,6 +595,7 @@ pub fn GetPrivateProfileSection(
 	let mut buf_sz = WString::SSO_LEN; // start with no string heap allocation
 	loop {
 		let mut buf = WString::new_alloc_buf(buf_sz);
+		SetLastError(co::ERROR::SUCCESS); // function doesn't reset it
 		let returned_chars = unsafe { // char count without terminating null
 			ffi::GetPrivateProfileSectionW(
 				WString::from_str(section_name).as_ptr(),
@@ -609,6 +656,7 @@ pub fn GetPrivateProfileSectionNames(
 	let mut buf_sz = WString::SSO_LEN; // start with no string heap allocation
 	loop {
 		let mut buf = WString::new_alloc_buf(buf_sz);
+		SetLastError(co::ERROR::SUCCESS); // function doesn't reset it
 		let returned_chars = unsafe { // char count without terminating null
 			ffi::GetPrivateProfileSectionNamesW(
 				buf.as_mut_ptr(),
@@ -630,6 +678,9 @@ pub fn GetPrivateProfileSectionNames(
 /// [`GetPrivateProfileString`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getprivateprofilestringw)
 /// function.
 ///
+/// Returns `None` if the key or the file doesn't exist; a default value can be
+/// supplied with [`Option::unwrap_or`].
+///
 /// # Examples
 ///
 /// Reading from an INI file:
@@ -662,7 +713,8 @@ pub fn GetPrivateProfileString(
 	let mut buf_sz = WString::SSO_LEN; // start with no string heap allocation
 	loop {
 		let mut buf = WString::new_alloc_buf(buf_sz);
-		unsafe { // char count without terminating null
+		SetLastError(co::ERROR::SUCCESS); // function doesn't reset it
+		let returned_chars = unsafe { // char count without terminating null
 			ffi::GetPrivateProfileStringW(
 				WString::from_str(section_name).as_ptr(),
 				WString::from_str(key_name).as_ptr(),
@@ -670,14 +722,14 @@ pub fn GetPrivateProfileString(
 				buf.as_mut_ptr(),
 				buf.buf_len() as _,
 				WString::from_str(file_name).as_ptr(),
-			);
-		}
+			)
+		} + 1; // plus terminating null count
 
 		match GetLastError() {
-			co::ERROR::SUCCESS => {
+			co::ERROR::SUCCESS if (returned_chars as usize) < buf_sz => { // to break, must have at least 1 char gap
 				return Ok(Some(buf.to_string()));
 			},
-			co::ERROR::MORE_DATA => {
+			co::ERROR::SUCCESS | co::ERROR::MORE_DATA => { // value may have been truncated
 				buf_sz *= 2; // double the buffer size to try again
 			},
 			co::ERROR::FILE_NOT_FOUND => {
@@ -690,6 +742,19 @@ pub fn GetPrivateProfileString(
 	}
 }
 
+/// [`GetProcessShutdownParameters`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessshutdownparameters)
+/// function.
+///
+/// Returns the shutdown level and the flags of the current process,
+/// respectively.
+pub fn GetProcessShutdownParameters() -> SysResult<(u32, co::SHUTDOWN)> {
+	let mut level = u32::default();
+	let mut flags = co::SHUTDOWN::default();
+	bool_to_sysresult(
+		unsafe { ffi::GetProcessShutdownParameters(&mut level, flags.as_mut()) },
+	).map(|_| (level, flags))
+}
+
 /// [`GetStartupInfo`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getstartupinfow)
 /// function.
 #[must_use]
@@ -748,6 +813,30 @@ pub fn GetSystemInfo() -> SYSTEM_INFO {
 	si
 }
 
+/// [`GetSystemPowerStatus`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getsystempowerstatus)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let sps = w::GetSystemPowerStatus()?;
+///
+/// if sps.ACLineStatus == co::AC_LINE::OFFLINE {
+///     if let Some(percent) = sps.battery_percent() {
+///         println!("On battery, {}% remaining.", percent);
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn GetSystemPowerStatus() -> SysResult<SYSTEM_POWER_STATUS> {
+	let mut sps = SYSTEM_POWER_STATUS::default();
+	bool_to_sysresult(
+		unsafe { ffi::GetSystemPowerStatus(&mut sps as *mut _ as _) },
+	).map(|_| sps)
+}
+
 /// [`GetSystemTime`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemtime)
 /// function.
 ///
@@ -951,6 +1040,18 @@ pub fn GetVolumePathName(file_name: &str) -> SysResult<String> {
 
 /// [`GlobalMemoryStatusEx`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-globalmemorystatusex)
 /// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let msx = w::GlobalMemoryStatusEx()?;
+/// println!("Memory load: {}%", msx.dwMemoryLoad);
+/// println!("Physical: {} of {} bytes available",
+///     msx.ullAvailPhys, msx.ullTotalPhys);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
 #[must_use]
 pub fn GlobalMemoryStatusEx() -> SysResult<MEMORYSTATUSEX> {
 	let mut msx = MEMORYSTATUSEX::default();
@@ -1287,14 +1388,68 @@ pub fn MulDiv(number: i32, numerator: i32, denominator: i32) -> i32 {
 	unsafe { ffi::MulDiv(number, numerator, denominator) }
 }
 
+/// Calls [`MultiByteToWideChar`](crate::MultiByteToWideChar) to decode the
+/// data from the given code page, returning a [`String`](std::string::String).
+///
+/// Decoding stops at the first null, if any.
+///
+/// # Examples
+///
+/// Decoding a Shift-JIS buffer:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let data: Vec<u8>; // read from a file
+/// # let data = Vec::<u8>::new();
+///
+/// let text = w::multi_byte_to_string(
+///     co::CP::SHIFT_JIS,
+///     co::MBC::ERR_INVALID_CHARS,
+///     &data,
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn multi_byte_to_string(
+	code_page: co::CP,
+	flags: co::MBC,
+	multi_byte_str: &[u8],
+) -> SysResult<String>
+{
+	MultiByteToWideChar(code_page, flags, multi_byte_str)
+		.map(|wide| WString::from_wchars_slice(&wide).to_string())
+}
+
 /// [`MultiByteToWideChar`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-multibytetowidechar)
 /// function.
 ///
 /// If `multi_byte_str` doesn't have a terminating null, the resulting
-/// `Vec<u16>` also won't include one.
+/// `Vec<u16>` also won't include one. An empty `multi_byte_str` results in an
+/// empty `Vec<u16>`.
+///
+/// # Examples
+///
+/// Decoding a Shift-JIS buffer into a [`String`](std::string::String):
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let data: Vec<u8>; // read from a file
+/// # let data = Vec::<u8>::new();
+///
+/// let wide = w::MultiByteToWideChar(
+///     co::CP::SHIFT_JIS,
+///     co::MBC::ERR_INVALID_CHARS,
+///     &data,
+/// )?;
+/// let text = w::WString::from_wchars_slice(&wide).to_string();
+/// # w::SysResult::Ok(())
+/// ```
 ///
 /// # Related functions
 ///
+/// * [`multi_byte_to_string`](crate::multi_byte_to_string)
 /// * [`WideCharToMultiByte`](crate::WideCharToMultiByte)
 #[must_use]
 pub fn MultiByteToWideChar(
@@ -1303,6 +1458,10 @@ pub fn MultiByteToWideChar(
 	multi_byte_str: &[u8],
 ) -> SysResult<Vec<u16>>
 {
+	if multi_byte_str.is_empty() {
+		return Ok(Vec::new());
+	}
+
 	let num_bytes = match unsafe {
 		ffi::MultiByteToWideChar(
 			code_page.raw() as _,
@@ -1361,6 +1520,9 @@ pub fn OutputDebugString(output_string: &str) {
 /// # w::SysResult::Ok(())
 /// ```
 ///
+/// For a simpler way to measure elapsed time, see
+/// [`Stopwatch`](crate::Stopwatch).
+///
 /// # Related functions
 ///
 /// * [`QueryPerformanceFrequency`](crate::QueryPerformanceFrequency)
@@ -1371,9 +1533,13 @@ pub fn QueryPerformanceCounter() -> SysResult<i64> {
 		.map(|_| perf_count)
 }
 
-/// [`QueryPerformanceFrequency`](https://learn.microsoft.com/en-us/windows/win32/api/profileapi/nf-profileapi-queryperformancecounter)
+/// [`QueryPerformanceFrequency`](https://learn.microsoft.com/en-us/windows/win32/api/profileapi/nf-profileapi-queryperformancefrequency)
 /// function.
 ///
+/// Returns the number of counts per second. The frequency is fixed at system
+/// boot and is consistent across all processors, so it can be retrieved once
+/// and cached.
+///
 /// # Related functions
 ///
 /// * [`QueryPerformanceCounter`](crate::QueryPerformanceCounter)
@@ -1455,6 +1621,56 @@ pub fn SetLastError(err_code: co::ERROR) {
 	unsafe { ffi::SetLastError(err_code.raw()) }
 }
 
+/// [`SetProcessShutdownParameters`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setprocessshutdownparameters)
+/// function.
+///
+/// Sets the shutdown order of the current process relative to the other
+/// processes in the system. Applications have levels from `0x100` to `0x3ff`;
+/// higher levels are shut down first. The default level is `0x280`.
+///
+/// # Examples
+///
+/// Asking to be shut down before the other applications, and without the
+/// retry dialog box:
+///
+/// ```no_run
+/// use winsafe::{self as w, co};
+///
+/// w::SetProcessShutdownParameters(0x3ff, co::SHUTDOWN::NORETRY)?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn SetProcessShutdownParameters(level: u32, flags: co::SHUTDOWN) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe { ffi::SetProcessShutdownParameters(level, flags.raw()) },
+	)
+}
+
+/// [`SetThreadExecutionState`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setthreadexecutionstate)
+/// function.
+///
+/// Returns the previous state, or `None` if the function fails.
+///
+/// # Examples
+///
+/// Preventing the system from sleeping while a long task runs in the current
+/// thread:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::SetThreadExecutionState(co::EXECUTION_STATE::CONTINUOUS | co::EXECUTION_STATE::SYSTEM_REQUIRED);
+///
+/// // long task...
+///
+/// w::SetThreadExecutionState(co::EXECUTION_STATE::CONTINUOUS); // clear the requirement
+/// ```
+pub fn SetThreadExecutionState(flags: co::EXECUTION_STATE) -> Option<co::EXECUTION_STATE> {
+	match unsafe { ffi::SetThreadExecutionState(flags.raw()) } {
+		0 => None,
+		prev => Some(unsafe { co::EXECUTION_STATE::from_raw(prev) }),
+	}
+}
+
 /// [`SetThreadStackGuarantee`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadstackguarantee)
 /// function.
 ///
@@ -1471,6 +1687,45 @@ pub fn Sleep(milliseconds: u32) {
 	unsafe { ffi::Sleep(milliseconds) }
 }
 
+/// Calls [`WideCharToMultiByte`](crate::WideCharToMultiByte) to encode the
+/// string in the given code page. No terminating null is added.
+///
+/// Characters which can't be represented are replaced by `default_char`, or by
+/// the system default if `None`. With [`CP::UTF7`](crate::co::CP::UTF7) and
+/// [`CP::UTF8`](crate::co::CP::UTF8), `default_char` must be `None`.
+///
+/// # Examples
+///
+/// Encoding a [`&str`](str) as Windows-1252:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let data = w::string_to_multi_byte(
+///     co::CP::WINDOWS_1252,
+///     co::WC::NoValue,
+///     "Café",
+///     Some(b'?'),
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn string_to_multi_byte(
+	code_page: co::CP,
+	flags: co::WC,
+	s: &str,
+	default_char: Option<u8>,
+) -> SysResult<Vec<u8>>
+{
+	WideCharToMultiByte(
+		code_page,
+		flags,
+		&s.encode_utf16().collect::<Vec<_>>(),
+		default_char,
+		None,
+	)
+}
+
 /// [`SwitchToThread`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-switchtothread)
 /// function.
 pub fn SwitchToThread() -> SysResult<()> {
@@ -1565,11 +1820,41 @@ pub fn VerSetConditionMask(
 /// function.
 ///
 /// If `wide_char_str` doesn't have a terminating null, the resulting `Vec<u8>`
-/// also won't include one.
+/// also won't include one. An empty `wide_char_str` results in an empty
+/// `Vec<u8>`.
+///
+/// With [`CP::UTF7`](crate::co::CP::UTF7) and
+/// [`CP::UTF8`](crate::co::CP::UTF8), `default_char` and `used_default_char`
+/// must be `None`.
+///
+/// # Examples
+///
+/// Encoding a [`&str`](str) as Windows-1252:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let text = "Café";
+///
+/// let mut used_default = false;
+/// let data = w::WideCharToMultiByte(
+///     co::CP::WINDOWS_1252,
+///     co::WC::NoValue,
+///     &text.encode_utf16().collect::<Vec<_>>(),
+///     Some(b'?'),
+///     Some(&mut used_default),
+/// )?;
+///
+/// if used_default {
+///     println!("Some characters couldn't be represented.");
+/// }
+/// # w::SysResult::Ok(())
+/// ```
 ///
 /// # Related functions
 ///
 /// * [`MultiByteToWideChar`](crate::MultiByteToWideChar)
+/// * [`string_to_multi_byte`](crate::string_to_multi_byte)
 #[must_use]
 pub fn WideCharToMultiByte(
 	code_page: co::CP,
@@ -1579,7 +1864,12 @@ pub fn WideCharToMultiByte(
 	used_default_char: Option<&mut bool>,
 ) -> SysResult<Vec<u8>>
 {
-	let mut default_char_buf = default_char.unwrap_or_default();
+	if wide_char_str.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let default_char_ptr = default_char.as_ref()
+		.map_or(std::ptr::null(), |c| c as *const u8);
 
 	let num_bytes = match unsafe {
 		ffi::WideCharToMultiByte(
@@ -1589,7 +1879,7 @@ pub fn WideCharToMultiByte(
 			wide_char_str.len() as _,
 			std::ptr::null_mut(),
 			0,
-			&mut default_char_buf,
+			default_char_ptr,
 			std::ptr::null_mut(),
 		)
 	} {
@@ -1609,8 +1899,12 @@ pub fn WideCharToMultiByte(
 				wide_char_str.len() as _,
 				u8_buf.as_mut_ptr() as _,
 				num_bytes as _,
-				&mut default_char_buf,
-				&mut bool_buf,
+				default_char_ptr,
+				if used_default_char.is_some() {
+					&mut bool_buf
+				} else {
+					std::ptr::null_mut()
+				},
 			)
 		},
 	).map(|_| {
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::kernel::{ffi, privs::*};
@@ -22,6 +23,148 @@ impl kernel_Hpipe for HPIPE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hpipe: Handle {
+	/// [`CallNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-callnamedpipew)
+	/// function.
+	///
+	/// Connects to a message-type named pipe, writes the request, reads the
+	/// response – up to `response_size` bytes – and closes the pipe. If
+	/// `timeout_ms` is `None`, waits indefinitely for an instance of the pipe
+	/// to be available.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let response = w::HPIPE::CallNamedPipe(
+	///     "\\\\.\\pipe\\my_service",
+	///     "status".as_bytes(),
+	///     4096,
+	///     Some(2000),
+	/// )?;
+	///
+	/// println!("{}", String::from_utf8_lossy(&response));
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn CallNamedPipe(
+		name: &str,
+		request: &[u8],
+		response_size: u32,
+		timeout_ms: Option<u32>,
+	) -> SysResult<Vec<u8>>
+	{
+		let mut response = vec![0u8; response_size as _];
+		let mut bytes_read = u32::default();
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::CallNamedPipeW(
+					WString::from_str(name).as_ptr(),
+					request.as_ptr() as _,
+					request.len() as _,
+					response.as_mut_ptr() as _,
+					response_size,
+					&mut bytes_read,
+					timeout_ms.unwrap_or(NMPWAIT_WAIT_FOREVER),
+				)
+			},
+		).map(|_| {
+			response.truncate(bytes_read as _);
+			response
+		})
+	}
+
+	/// [`ConnectNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)
+	/// function.
+	///
+	/// Waits for a client to connect to the named pipe instance. If the client
+	/// connected before the call, returns successfully too.
+	///
+	/// Note that asynchronous connection – which use the
+	/// [`OVERLAPPED`](crate::OVERLAPPED) struct – is not currently supported by
+	/// this method.
+	///
+	/// # Related functions
+	///
+	/// * [`HPIPE::DisconnectNamedPipe`](crate::prelude::kernel_Hpipe::DisconnectNamedPipe)
+	fn ConnectNamedPipe(&self) -> SysResult<()> {
+		match bool_to_sysresult(
+			unsafe { ffi::ConnectNamedPipe(self.ptr(), std::ptr::null_mut()) },
+		) {
+			Err(co::ERROR::PIPE_CONNECTED) => Ok(()), // client connected before the call
+			res => res,
+		}
+	}
+
+	/// [`CreateNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createnamedpipew)
+	/// function.
+	///
+	/// If `max_instances` is `None`, the number of instances is limited only by
+	/// the system resources. A `default_timeout_ms` of zero means 50
+	/// milliseconds.
+	///
+	/// # Examples
+	///
+	/// A server answering one client at a time:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hpipe = w::HPIPE::CreateNamedPipe(
+	///     "\\\\.\\pipe\\my_service",
+	///     co::PIPE_ACCESS::DUPLEX,
+	///     None,
+	///     co::PIPE::TYPE_MESSAGE | co::PIPE::READMODE_MESSAGE,
+	///     Some(1),
+	///     4096,
+	///     4096,
+	///     0,
+	///     None,
+	/// )?;
+	///
+	/// let mut buf = [0u8; 4096];
+	/// loop {
+	///     hpipe.ConnectNamedPipe()?;
+	///     let num_read = hpipe.ReadFile(&mut buf)?;
+	///     let request = &buf[..num_read as usize];
+	///
+	///     hpipe.WriteFile(request)?; // echo the request back
+	///     hpipe.FlushFileBuffers()?;
+	///     hpipe.DisconnectNamedPipe()?;
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn CreateNamedPipe(
+		name: &str,
+		open_mode: co::PIPE_ACCESS,
+		flags: Option<co::FILE_FLAG>,
+		pipe_mode: co::PIPE,
+		max_instances: Option<u8>,
+		out_buffer_size: u32,
+		in_buffer_size: u32,
+		default_timeout_ms: u32,
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+	) -> SysResult<CloseHandleGuard<HPIPE>>
+	{
+		unsafe {
+			match HPIPE::from_ptr(
+				ffi::CreateNamedPipeW(
+					WString::from_str(name).as_ptr(),
+					open_mode.raw() | flags.unwrap_or_default().raw(),
+					pipe_mode.raw(),
+					max_instances.map_or(PIPE_UNLIMITED_INSTANCES, |n| n as _),
+					out_buffer_size,
+					in_buffer_size,
+					default_timeout_ms,
+					security_attributes.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				),
+			) {
+				HPIPE::INVALID => Err(GetLastError()),
+				handle => Ok(CloseHandleGuard::new(handle)),
+			}
+		}
+	}
+
 	/// [`CreatePipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-createpipe)
 	/// function.
 	///
@@ -45,6 +188,28 @@ pub trait kernel_Hpipe: Handle {
 		}
 	}
 
+	/// [`DisconnectNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-disconnectnamedpipe)
+	/// function.
+	///
+	/// Any unread data is discarded, so consider calling
+	/// [`HPIPE::FlushFileBuffers`](crate::prelude::kernel_Hpipe::FlushFileBuffers)
+	/// first.
+	///
+	/// # Related functions
+	///
+	/// * [`HPIPE::ConnectNamedPipe`](crate::prelude::kernel_Hpipe::ConnectNamedPipe)
+	fn DisconnectNamedPipe(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::DisconnectNamedPipe(self.ptr()) })
+	}
+
+	/// [`FlushFileBuffers`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-flushfilebuffers)
+	/// function.
+	///
+	/// Waits until the client has read all the data written to the pipe.
+	fn FlushFileBuffers(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::FlushFileBuffers(self.ptr()) })
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// function.
 	///
@@ -59,6 +224,52 @@ pub trait kernel_Hpipe: Handle {
 			.ReadFile(buffer)
 	}
 
+	/// [`SetHandleInformation`](https://learn.microsoft.com/en-us/windows/win32/api/handleapi/nf-handleapi-sethandleinformation)
+	/// function.
+	///
+	/// Usually used to prevent the parent's end of a pipe from being inherited
+	/// by a child process.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let mut sa = w::SECURITY_ATTRIBUTES::default();
+	/// sa.set_bInheritHandle(true);
+	///
+	/// let (hread, hwrite) = w::HPIPE::CreatePipe(Some(&mut sa), 0)?;
+	/// hread.SetHandleInformation(co::HANDLE_FLAG::INHERIT, co::HANDLE_FLAG::NoValue)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn SetHandleInformation(&self,
+		mask: co::HANDLE_FLAG,
+		flags: co::HANDLE_FLAG,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::SetHandleInformation(self.ptr(), mask.raw(), flags.raw())
+			},
+		)
+	}
+
+	/// [`WaitNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-waitnamedpipew)
+	/// function.
+	///
+	/// Waits until an instance of the named pipe is available for connection.
+	/// If `timeout_ms` is `None`, waits indefinitely.
+	fn WaitNamedPipe(name: &str, timeout_ms: Option<u32>) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::WaitNamedPipeW(
+					WString::from_str(name).as_ptr(),
+					timeout_ms.unwrap_or(NMPWAIT_WAIT_FOREVER),
+				)
+			},
+		)
+	}
+
 	/// [`WriteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-writefile)
 	/// function.
 	///
@@ -3,7 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::{ffi, iterators::*, privs::*};
+use crate::kernel::{ffi, iterators::*};
 use crate::prelude::*;
 
 impl_handle! { HPROCESSLIST;
@@ -75,6 +75,27 @@ pub trait kernel_Hprocesslist: Handle {
 	/// }
 	/// # w::SysResult::Ok(())
 	/// ```
+	///
+	/// Listing the address range of each module loaded by another process:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let pid: u32; // initialized somewhere
+	/// # let pid = 0;
+	///
+	/// let mut hpl = w::HPROCESSLIST::CreateToolhelp32Snapshot(
+	///     co::TH32CS::SNAPMODULE | co::TH32CS::SNAPMODULE32,
+	///     Some(pid),
+	/// )?;
+	///
+	/// for mod_entry in hpl.iter_modules() {
+	///     let mod_entry = mod_entry?;
+	///     println!("{:p} +{:#x} {}",
+	///         mod_entry.modBaseAddr, mod_entry.modBaseSize, mod_entry.szExePath());
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn iter_modules(&mut self,
 	) -> impl Iterator<Item = SysResult<&MODULEENTRY32>> + '_
@@ -144,19 +165,41 @@ pub trait kernel_Hprocesslist: Handle {
 
 	/// [`CreateToolhelp32Snapshot`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/nf-tlhelp32-createtoolhelp32snapshot)
 	/// function.
+	///
+	/// When taking a module snapshot, the call is retried up to 5 times while
+	/// it fails with [`ERROR::BAD_LENGTH`](crate::co::ERROR::BAD_LENGTH), which
+	/// happens if the target process is still loading or unloading modules.
+	/// If it keeps failing, the error is returned.
 	#[must_use]
 	fn CreateToolhelp32Snapshot(
 		flags: co::TH32CS,
 		th32_process_id: Option<u32>,
 	) -> SysResult<CloseHandleGuard<HPROCESSLIST>>
 	{
-		unsafe {
-			ptr_to_sysresult_handle(
-				ffi::CreateToolhelp32Snapshot(
-					flags.raw(),
-					th32_process_id.unwrap_or_default(),
-				),
-			).map(|h| CloseHandleGuard::new(h))
+		const MAX_RETRIES: u32 = 5;
+		let is_module = flags.has(co::TH32CS::SNAPMODULE)
+			|| flags.has(co::TH32CS::SNAPMODULE32);
+		let mut retries = 0;
+
+		loop {
+			let res = unsafe {
+				match HPROCESSLIST(
+					ffi::CreateToolhelp32Snapshot(
+						flags.raw(),
+						th32_process_id.unwrap_or_default(),
+					),
+				) {
+					HPROCESSLIST::NULL | HPROCESSLIST::INVALID => Err(GetLastError()),
+					handle => Ok(CloseHandleGuard::new(handle)),
+				}
+			};
+
+			match res {
+				Err(co::ERROR::BAD_LENGTH) if is_module && retries < MAX_RETRIES => {
+					retries += 1; // try again
+				},
+				res => return res,
+			}
 		}
 	}
 
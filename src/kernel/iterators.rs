@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
@@ -84,6 +86,8 @@ impl<'a> DirListIter<'a> {
 pub(in crate::kernel) struct DirWalkIter<'a> {
 	runner: DirListIter<'a>,
 	subdir_runner: Option<Box<DirWalkIter<'a>>>,
+	opts: Arc<path::DirWalkOpts>, // shared by all subdir runners
+	depth: u32,
 	no_more: bool,
 }
 
@@ -91,55 +95,69 @@ impl<'a> Iterator for DirWalkIter<'a> {
 	type Item = SysResult<String>;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		if self.no_more {
-			return None;
-		}
+		loop { // skipped entries don't recurse, so huge directories won't overflow the stack
+			if self.no_more {
+				return None;
+			}
 
-		match &mut self.subdir_runner {
-			None => {
-				let cur_file = self.runner.next();
-				match cur_file {
-					Some(cur_file) => { // a file was found
-						match cur_file {
-							Err(e) => { // actually an error
-								self.no_more = true; // prevent further iterations
-								Some(Err(e)) // return the error
-							},
-							Ok(cur_file) => {
-								if path::is_directory(&cur_file) {
-									self.subdir_runner = Some(Box::new(Self::new(cur_file))); // recursively
-									self.next()
-								} else {
-									Some(Ok(cur_file))
-								}
-							},
-						}
-					},
-					None => None, // no file found, halt
-				}
-			},
-			Some(subdir_runner) => {
-				let inner_file = subdir_runner.next();
-				match inner_file {
-					None => { // subdir_runner finished his work
-						self.subdir_runner = None;
-						self.next()
-					},
-					Some(inner_file) => {
-						Some(inner_file)
-					},
-				}
-			},
+			match &mut self.subdir_runner {
+				None => {
+					let cur_file = self.runner.next();
+					match cur_file {
+						Some(cur_file) => { // a file was found
+							match cur_file {
+								Err(e) => { // actually an error
+									self.no_more = true; // prevent further iterations
+									return Some(Err(e)); // return the error
+								},
+								Ok(cur_file) => {
+									if path::is_directory(&cur_file) {
+										if !matches!(self.opts.max_depth, Some(max) if self.depth >= max) {
+											self.subdir_runner = Some(Box::new(
+												Self::new_subdir(cur_file.clone(), self.opts.clone(), self.depth + 1),
+											)); // recursively
+										}
+										if self.opts.include_dirs {
+											return Some(Ok(cur_file)); // directory comes before its contents
+										}
+									} else if (self.opts.filter)(&cur_file) {
+										return Some(Ok(cur_file));
+									}
+								},
+							}
+						},
+						None => return None, // no file found, halt
+					}
+				},
+				Some(subdir_runner) => {
+					let inner_file = subdir_runner.next();
+					match inner_file {
+						None => { // subdir_runner finished his work
+							self.subdir_runner = None;
+						},
+						Some(inner_file) => {
+							return Some(inner_file);
+						},
+					}
+				},
+			}
 		}
 	}
 }
 
 impl<'a> DirWalkIter<'a> {
 	#[must_use]
-	pub(in crate::kernel) fn new(dir_path: String) -> Self {
+	pub(in crate::kernel) fn new(dir_path: String, opts: path::DirWalkOpts) -> Self {
+		Self::new_subdir(dir_path, Arc::new(opts), 0)
+	}
+
+	#[must_use]
+	fn new_subdir(dir_path: String, opts: Arc<path::DirWalkOpts>, depth: u32) -> Self {
 		Self {
 			runner: DirListIter::new(dir_path, None),
 			subdir_runner: None,
+			opts,
+			depth,
 			no_more: false,
 		}
 	}
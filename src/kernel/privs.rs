@@ -14,6 +14,9 @@ const_values_num_privs! {
 	MAX_COMPUTERNAME_LENGTH usize = 15
 	MAX_MODULE_NAME32 usize = 255
 	MAX_PATH usize = 260
+	NMPWAIT_WAIT_FOREVER u32 = 0xffff_ffff
+	PIPE_UNLIMITED_INSTANCES u32 = 255
+	PROC_THREAD_ATTRIBUTE_HANDLE_LIST usize = 0x0002_0002
 	SECURITY_SQOS_PRESENT u32 = 0x0010_0000
 }
 
@@ -90,6 +93,48 @@ pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
 	}
 }
 
+/// Writes the error code in hexadecimal and decimal formats, followed by its
+/// system description, retrieved lazily with `FormatMessage`. If
+/// `FormatMessage` fails, only the numbers are written. Used by the `Display`
+/// and `Debug` implementations of the error constant types.
+///
+/// With `type_name`, writes `NAME(0x0005 5) Access is denied.`, otherwise
+/// writes `[0x0005 5] Access is denied.`.
+pub(crate) fn fmt_error_code(
+	f: &mut std::fmt::Formatter<'_>,
+	type_name: Option<&str>,
+	err_code: u32,
+) -> std::fmt::Result
+{
+	let desc = unsafe { // errors are discarded, so this never recurses
+		FormatMessage(
+			co::FORMAT_MESSAGE::ALLOCATE_BUFFER
+				| co::FORMAT_MESSAGE::FROM_SYSTEM
+				| co::FORMAT_MESSAGE::IGNORE_INSERTS,
+			None,
+			err_code,
+			LANGID::USER_DEFAULT,
+			None,
+		)
+	}.ok();
+
+	let (open, close) = match type_name {
+		Some(name) => { write!(f, "{}", name)?; ("(", ")") },
+		None => ("[", "]"),
+	};
+
+	if err_code > 0xffff {
+		write!(f, "{}{:#010x} {}{}", open, err_code, err_code, close)?;
+	} else {
+		write!(f, "{}{:#06x} {}{}", open, err_code, err_code, close)?;
+	}
+
+	match desc.as_deref().map(|d| d.trim_end()) {
+		Some(desc) if !desc.is_empty() => write!(f, " {}", desc),
+		_ => Ok(()),
+	}
+}
+
 /// Converts a string to an ISO-8859-1 null-terminated byte array.
 #[must_use]
 pub(crate) fn str_to_iso88591(s: &str) -> Vec<u8> {
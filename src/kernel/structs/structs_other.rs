@@ -730,7 +730,7 @@ pub struct SECURITY_DESCRIPTOR {
 /// struct.
 #[repr(C)]
 pub struct STARTUPINFO<'a, 'b> {
-	cb: u32,
+	pub(in crate::kernel) cb: u32,
 	lpReserved: *mut u16,
 	lpDesktop: *mut u16,
 	lpTitle: *mut u16,
@@ -790,6 +790,38 @@ pub struct SYSTEM_INFO {
 
 impl_default!(SYSTEM_INFO);
 
+/// [`SYSTEM_POWER_STATUS`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-system_power_status)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct SYSTEM_POWER_STATUS {
+	pub ACLineStatus: co::AC_LINE,
+	pub BatteryFlag: co::BATTERY_FLAG,
+	pub BatteryLifePercent: u8,
+	SystemStatusFlag: u8,
+	pub BatteryLifeTime: u32,
+	pub BatteryFullLifeTime: u32,
+}
+
+impl SYSTEM_POWER_STATUS {
+	/// Returns the `BatteryLifePercent` field, or `None` if the status is
+	/// unknown.
+	#[must_use]
+	pub const fn battery_percent(&self) -> Option<u8> {
+		match self.BatteryLifePercent {
+			255 => None,
+			p => Some(p),
+		}
+	}
+
+	/// Returns the `SystemStatusFlag` field, which tells whether battery saver
+	/// is on.
+	#[must_use]
+	pub const fn SystemStatusFlag(&self) -> bool {
+		self.SystemStatusFlag != 0
+	}
+}
+
 /// [`SYSTEMTIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime)
 /// struct.
 ///
@@ -23,6 +23,20 @@ use crate::prelude::*;
 /// let text = w::WString::parse(raw_bytes)?.to_string();
 /// # w::SysResult::Ok(())
 /// ```
+///
+/// Editing a binary file in place, without reading it into memory:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut f = w::FileMapped::open(
+///     "C:\\Temp\\foo.bin",
+///     w::FileAccess::ExistingRW,
+/// )?;
+/// f.as_mut_slice()[0] = 0xff;
+/// f.flush(None)?;
+/// # w::SysResult::Ok(())
+/// ```
 pub struct FileMapped {
 	hview: UnmapViewOfFileGuard, // drop order is important
 	_hmap: CloseHandleGuard<HFILEMAP>,
@@ -32,9 +46,53 @@ pub struct FileMapped {
 
 impl FileMapped {
 	/// Opens a file with the desired access, then map its contents in memory.
+	///
+	/// A file cannot be mapped beyond its size, and an empty file cannot be
+	/// mapped at all. To grow the file before mapping it, use
+	/// [`open_with_size`](crate::FileMapped::open_with_size).
 	#[must_use]
 	pub fn open(file_path: &str, access: FileAccess) -> SysResult<Self> {
 		let file = File::open(file_path, access)?;
+		Self::map(file, access)
+	}
+
+	/// Opens a file with the desired access, sets its size to the given number
+	/// of bytes, then map its contents in memory. Useful to create a new file,
+	/// or to grow an existing one, since a file cannot be mapped beyond its
+	/// size.
+	///
+	/// If the file is shrunk, the bytes beyond the new size are lost. The
+	/// access must allow writing, otherwise the resizing will fail.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let mut f = w::FileMapped::open_with_size(
+	///     "C:\\Temp\\new.bin",
+	///     w::FileAccess::OpenOrCreateRW,
+	///     1024 * 1024,
+	/// )?;
+	/// f.as_mut_slice().fill(0xab);
+	/// f.flush(None)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn open_with_size(
+		file_path: &str,
+		access: FileAccess,
+		num_bytes: u64,
+	) -> SysResult<Self>
+	{
+		let file = File::open(file_path, access)?;
+		if file.size()? != num_bytes {
+			file.set_size(num_bytes)?;
+		}
+		Self::map(file, access)
+	}
+
+	fn map(file: File, access: FileAccess) -> SysResult<Self> {
 		let hmap = file.hfile().CreateFileMapping(
 			None,
 			match access {
@@ -61,6 +119,12 @@ impl FileMapped {
 	}
 
 	/// Returns a mutable slice to the mapped memory.
+	///
+	/// The file must have been opened with write access, otherwise writing to
+	/// the slice will cause an access violation.
+	///
+	/// Changes are written lazily to the disk; call
+	/// [`flush`](crate::FileMapped::flush) to write them immediately.
 	#[must_use]
 	pub fn as_mut_slice(&mut self) -> &mut [u8] {
 		self.hview.as_mut_slice(self.size as _)
@@ -72,6 +136,30 @@ impl FileMapped {
 		self.hview.as_slice(self.size as _)
 	}
 
+	/// Writes the modified bytes of the mapped memory to the disk by calling
+	/// [`HFILEMAPVIEW::FlushViewOfFile`](crate::prelude::kernel_Hfilemapview::FlushViewOfFile).
+	///
+	/// If `range` is `None`, the whole mapped memory is flushed.
+	///
+	/// # Panics
+	///
+	/// Panics if `range` goes beyond the file size.
+	pub fn flush(&self, range: Option<std::ops::Range<usize>>) -> SysResult<()> {
+		match range {
+			Some(range) => {
+				if range.start > range.end || range.end > self.size as usize {
+					panic!("Flush range {:?} beyond file size {}.", range, self.size);
+				}
+				if range.is_empty() {
+					Ok(()) // zero bytes would flush the whole view
+				} else {
+					self.hview.FlushViewOfFile(range.start, range.len())
+				}
+			},
+			None => self.hview.FlushViewOfFile(0, 0), // zero means up to the end
+		}
+	}
+
 	/// Returns the underlying file handle.
 	#[must_use]
 	pub fn hfile(&self) -> &HFILE {
@@ -1,6 +1,9 @@
 mod encoding;
 mod file_mapped;
 mod file;
+mod process;
+mod retry;
+mod stopwatch;
 mod w_string;
 
 pub mod path;
@@ -8,4 +11,7 @@ pub mod path;
 pub use encoding::Encoding;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
+pub use process::{ChildProcess, PipeReader, ProcessBuilder, ProcessOutput};
+pub use retry::retry_on;
+pub use stopwatch::Stopwatch;
 pub use w_string::WString;
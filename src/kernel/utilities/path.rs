@@ -8,6 +8,27 @@ use crate::decl::*;
 use crate::kernel::iterators::*;
 use crate::prelude::*;
 
+/// Returns the number of files within a directory, and all its
+/// subdirectories, recursively, without storing their paths.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let total = w::path::count_entries("C:\\Temp")?;
+///
+/// for (idx, file_path) in w::path::dir_walk("C:\\Temp").enumerate() {
+///     let file_path = file_path?;
+///     println!("{}/{}: {}", idx + 1, total, file_path);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn count_entries(dir_path: &str) -> SysResult<usize> {
+	dir_walk(dir_path)
+		.try_fold(0, |count, file_path| file_path.map(|_| count + 1))
+}
+
 /// Returns an iterator over the files and folders within a directory.
 /// Optionally, a wildcard can be specified to filter files by name.
 ///
@@ -79,7 +100,75 @@ pub fn dir_walk<'a>(
 	dir_path: &'a str,
 ) -> impl Iterator<Item = SysResult<String>> + 'a
 {
-	DirWalkIter::new(dir_path.to_owned())
+	DirWalkIter::new(dir_path.to_owned(), DirWalkOpts::default())
+}
+
+/// Returns an iterator over the files within a directory, and its
+/// subdirectories, according to the given options.
+///
+/// To report progress with a running count in a single pass, use
+/// [`Iterator::enumerate`]; if a total is also needed, it can be retrieved
+/// beforehand with [`path::count_entries`](crate::path::count_entries).
+///
+/// # Examples
+///
+/// Listing the text files up to two levels below the directory:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let opts = w::path::DirWalkOpts {
+///     max_depth: Some(2),
+///     filter: Box::new(|file_path| {
+///         w::path::has_extension(file_path, &[".txt"])
+///     }),
+///     ..Default::default()
+/// };
+///
+/// for (idx, file_path) in w::path::dir_walk_with_opts("C:\\Temp", opts).enumerate() {
+///     let file_path = file_path?;
+///     println!("{}: {}", idx + 1, file_path);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn dir_walk_with_opts<'a>(
+	dir_path: &'a str,
+	opts: DirWalkOpts,
+) -> impl Iterator<Item = SysResult<String>> + 'a
+{
+	DirWalkIter::new(dir_path.to_owned(), opts)
+}
+
+/// Options for [`path::dir_walk_with_opts`](crate::path::dir_walk_with_opts).
+pub struct DirWalkOpts {
+	/// Maximum depth of subdirectories to be walked into. Zero means only the
+	/// entries of the directory itself are visited; unlike
+	/// [`path::dir_list`](crate::path::dir_list), its subdirectories are
+	/// returned only if `include_dirs` is `true`.
+	///
+	/// Defaults to `None`, which means no limit.
+	pub max_depth: Option<u32>,
+	/// Whether the directories themselves are returned, each one before its
+	/// contents.
+	///
+	/// Defaults to `false`.
+	pub include_dirs: bool,
+	/// Predicate called with the full path of each file; the file is returned
+	/// only if it returns `true`. Directories are not filtered, so they are
+	/// always walked into.
+	///
+	/// Defaults to a predicate which accepts all files.
+	pub filter: Box<dyn Fn(&str) -> bool + Send + Sync>,
+}
+
+impl Default for DirWalkOpts {
+	fn default() -> Self {
+		Self {
+			max_depth: None,
+			include_dirs: false,
+			filter: Box::new(|_| true),
+		}
+	}
 }
 
 /// Returns a new string with the path of the current EXE file, without the EXE
@@ -0,0 +1,354 @@
+use std::io::Read;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+/// Builds and launches a child process with
+/// [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess),
+/// redirecting its standard output and standard error to anonymous pipes, so
+/// they can be read by the parent.
+///
+/// This is similar to [`std::process::Command`], but using Win32 directly.
+///
+/// The standard input of the child process is not redirected, so the child
+/// process has no standard input.
+///
+/// # Examples
+///
+/// Running a command and capturing its output:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let output = w::ProcessBuilder::new("cmd.exe /c dir C:\\")
+///     .output()?;
+///
+/// println!("Exit code: {}", output.exit_code);
+/// println!("{}", String::from_utf8_lossy(&output.stdout));
+/// # w::SysResult::Ok(())
+/// ```
+pub struct ProcessBuilder {
+	command_line: String,
+	current_dir: Option<String>,
+	creation_flags: co::CREATE,
+}
+
+impl ProcessBuilder {
+	/// Creates a new builder with the given command line, which contains the
+	/// program and its arguments. Arguments with spaces must be quoted.
+	#[must_use]
+	pub fn new(command_line: &str) -> Self {
+		Self {
+			command_line: command_line.to_owned(),
+			current_dir: None,
+			creation_flags: co::CREATE::NO_WINDOW,
+		}
+	}
+
+	/// Sets the process creation flags.
+	///
+	/// Defaults to [`co::CREATE::NO_WINDOW`](crate::co::CREATE::NO_WINDOW),
+	/// so no console window is shown for console programs.
+	#[must_use]
+	pub fn creation_flags(mut self, flags: co::CREATE) -> Self {
+		self.creation_flags = flags;
+		self
+	}
+
+	/// Sets the working directory of the child process.
+	///
+	/// Defaults to the working directory of the current process.
+	#[must_use]
+	pub fn current_dir(mut self, dir: &str) -> Self {
+		self.current_dir = Some(dir.to_owned());
+		self
+	}
+
+	/// Launches the child process, running it until it exits, and returns the
+	/// captured output along with its exit code.
+	///
+	/// Standard output and standard error are read simultaneously, so the
+	/// child process never blocks on a full pipe.
+	pub fn output(&self) -> SysResult<ProcessOutput> {
+		self.spawn()?.output()
+	}
+
+	/// Launches the child process, returning immediately.
+	///
+	/// The pipes are created with inheritable write ends, which are passed to
+	/// the child process, while the read ends – kept by the parent – are made
+	/// non-inheritable with
+	/// [`HPIPE::SetHandleInformation`](crate::prelude::kernel_Hpipe::SetHandleInformation).
+	/// Right after the child process is launched, the parent closes its copies
+	/// of the write ends, so reading reaches the end when the child exits.
+	///
+	/// The child inherits only its own write ends, which are listed with
+	/// `PROC_THREAD_ATTRIBUTE_HANDLE_LIST`, so processes spawned concurrently
+	/// from other threads never receive copies of them.
+	pub fn spawn(&self) -> SysResult<ChildProcess> {
+		let mut sa = SECURITY_ATTRIBUTES::default();
+		sa.set_bInheritHandle(true);
+
+		let (stdout_read, stdout_write) = HPIPE::CreatePipe(Some(&mut sa), 0)?;
+		let (stderr_read, stderr_write) = HPIPE::CreatePipe(Some(&mut sa), 0)?;
+		stdout_read.SetHandleInformation(co::HANDLE_FLAG::INHERIT, co::HANDLE_FLAG::NoValue)?;
+		stderr_read.SetHandleInformation(co::HANDLE_FLAG::INHERIT, co::HANDLE_FLAG::NoValue)?;
+
+		let inherited = [stdout_write.ptr(), stderr_write.ptr()];
+		let attr_list = ProcThreadAttrList::with_handle_list(&inherited)?;
+
+		let mut si = STARTUPINFOEX::default();
+		si.StartupInfo.dwFlags = co::STARTF::USESTDHANDLES;
+		si.StartupInfo.hStdOutput = unsafe { stdout_write.raw_copy() };
+		si.StartupInfo.hStdError = unsafe { stderr_write.raw_copy() };
+		si.lpAttributeList = attr_list.as_ptr();
+
+		let mut buf_cmd_line = WString::from_str(&self.command_line);
+		let mut pi = PROCESS_INFORMATION::default();
+
+		let pi = unsafe {
+			bool_to_sysresult(
+				ffi::CreateProcessW(
+					std::ptr::null(),
+					buf_cmd_line.as_mut_ptr(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+					1, // inherit only the handles in the attribute list
+					(self.creation_flags | co::CREATE::EXTENDED_STARTUPINFO_PRESENT).raw(),
+					std::ptr::null_mut(),
+					WString::from_opt_str(self.current_dir.as_deref()).as_ptr(),
+					&mut si as *mut _ as _,
+					&mut pi as *mut _ as _,
+				),
+			).map(|_| CloseHandlePiGuard::new(pi))
+		}?;
+
+		drop(stdout_write); // the child has its own copies
+		drop(stderr_write);
+
+		Ok(ChildProcess { pi, stdout: stdout_read, stderr: stderr_read })
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A child process launched by
+/// [`ProcessBuilder::spawn`](crate::ProcessBuilder::spawn).
+///
+/// When the object goes out of scope, the handles are closed, but the child
+/// process is not terminated.
+pub struct ChildProcess {
+	pi: CloseHandlePiGuard,
+	stdout: CloseHandleGuard<HPIPE>,
+	stderr: CloseHandleGuard<HPIPE>,
+}
+
+impl ChildProcess {
+	/// Returns the handle to the child process.
+	#[must_use]
+	pub fn hprocess(&self) -> &HPROCESS {
+		&self.pi.hProcess
+	}
+
+	/// Reads standard output and standard error until the child process
+	/// closes them, then waits for it to exit.
+	///
+	/// Standard error is read in a separate thread, so the child process never
+	/// blocks on a full pipe.
+	pub fn output(self) -> SysResult<ProcessOutput> {
+		let hstderr = unsafe { self.stderr.raw_copy() };
+
+		let (stdout, stderr) = std::thread::scope(|scope| {
+			let thread_stderr = scope.spawn(move || read_to_end(&hstderr));
+			let stdout = read_to_end(&self.stdout);
+			let stderr = thread_stderr.join().unwrap(); // won't panic
+			(stdout, stderr)
+		});
+
+		Ok(ProcessOutput {
+			exit_code: self.wait()?,
+			stdout: stdout?,
+			stderr: stderr?,
+		})
+	}
+
+	/// Returns the process ID.
+	#[must_use]
+	pub fn process_id(&self) -> u32 {
+		self.pi.dwProcessId
+	}
+
+	/// Returns a reader over the standard error of the child process.
+	///
+	/// Note that, if the child process writes a lot to standard output while
+	/// you're reading only standard error, it may block on the full pipe.
+	#[must_use]
+	pub fn read_stderr(&self) -> PipeReader<'_> {
+		PipeReader { hpipe: &self.stderr }
+	}
+
+	/// Returns a reader over the standard output of the child process.
+	///
+	/// Note that, if the child process writes a lot to standard error while
+	/// you're reading only standard output, it may block on the full pipe.
+	///
+	/// # Examples
+	///
+	/// Reading the output line by line, as it's produced:
+	///
+	/// ```no_run
+	/// use std::io::BufRead;
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let child = w::ProcessBuilder::new("ping 127.0.0.1").spawn()?;
+	///
+	/// for line in std::io::BufReader::new(child.read_stdout()).lines() {
+	///     println!("{}", line.unwrap());
+	/// }
+	/// child.wait()?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn read_stdout(&self) -> PipeReader<'_> {
+		PipeReader { hpipe: &self.stdout }
+	}
+
+	/// Waits for the child process to exit, then returns its exit code.
+	pub fn wait(&self) -> SysResult<u32> {
+		self.pi.hProcess.WaitForSingleObject(None)?;
+		self.pi.hProcess.GetExitCodeProcess()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Reads from an anonymous pipe of a [`ChildProcess`](crate::ChildProcess),
+/// implementing [`std::io::Read`].
+///
+/// The end is reached when the child process closes its end of the pipe –
+/// usually when it exits.
+pub struct PipeReader<'a> {
+	hpipe: &'a HPIPE,
+}
+
+impl<'a> Read for PipeReader<'a> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let len = buf.len().min(u32::MAX as _);
+		read_pipe(self.hpipe, &mut buf[..len])
+			.map_err(|err| std::io::Error::from_raw_os_error(err.raw() as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Output of a finished child process, returned by
+/// [`ProcessBuilder::output`](crate::ProcessBuilder::output).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct ProcessOutput {
+	/// The exit code of the process.
+	pub exit_code: u32,
+	/// Bytes written to standard output.
+	pub stdout: Vec<u8>,
+	/// Bytes written to standard error.
+	pub stderr: Vec<u8>,
+}
+
+//------------------------------------------------------------------------------
+
+/// Reads from the pipe, treating a broken pipe as the end of data.
+fn read_pipe(hpipe: &HPIPE, buf: &mut [u8]) -> SysResult<usize> {
+	match hpipe.ReadFile(buf) {
+		Ok(num_read) => Ok(num_read as _),
+		Err(co::ERROR::BROKEN_PIPE) => Ok(0), // write end was closed
+		Err(e) => Err(e),
+	}
+}
+
+/// Reads the whole pipe until the end of data.
+fn read_to_end(hpipe: &HPIPE) -> SysResult<Vec<u8>> {
+	let mut data = Vec::new();
+	let mut buf = [0u8; 4096];
+	loop {
+		match read_pipe(hpipe, &mut buf)? {
+			0 => return Ok(data),
+			num_read => data.extend_from_slice(&buf[..num_read]),
+		}
+	}
+}
+
+/// [`STARTUPINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-startupinfoexw)
+/// struct, used only by [`ProcessBuilder::spawn`].
+#[repr(C)]
+#[allow(non_snake_case)]
+struct STARTUPINFOEX<'a, 'b> {
+	StartupInfo: STARTUPINFO<'a, 'b>,
+	lpAttributeList: *mut std::ffi::c_void,
+}
+
+impl<'a, 'b> Default for STARTUPINFOEX<'a, 'b> {
+	fn default() -> Self {
+		let mut obj = Self {
+			StartupInfo: STARTUPINFO::default(),
+			lpAttributeList: std::ptr::null_mut(),
+		};
+		obj.StartupInfo.cb = std::mem::size_of::<Self>() as _;
+		obj
+	}
+}
+
+/// Owns a buffer initialized with
+/// [`InitializeProcThreadAttributeList`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-initializeprocthreadattributelist),
+/// which is freed with
+/// [`DeleteProcThreadAttributeList`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-deleteprocthreadattributelist)
+/// when dropped.
+///
+/// The handle array must outlive the list, since the list only points to it.
+struct ProcThreadAttrList<'h> {
+	buf: Vec<usize>, // usize elements to keep the buffer pointer-aligned
+	_handles: std::marker::PhantomData<&'h [*mut std::ffi::c_void]>,
+}
+
+impl<'h> Drop for ProcThreadAttrList<'h> {
+	fn drop(&mut self) {
+		unsafe { ffi::DeleteProcThreadAttributeList(self.as_ptr()); }
+	}
+}
+
+impl<'h> ProcThreadAttrList<'h> {
+	/// Creates a list with a single `PROC_THREAD_ATTRIBUTE_HANDLE_LIST`
+	/// attribute, restricting inheritance to the given handles.
+	fn with_handle_list(handles: &'h [*mut std::ffi::c_void]) -> SysResult<Self> {
+		let mut sz = 0usize;
+		unsafe { ffi::InitializeProcThreadAttributeList(std::ptr::null_mut(), 1, 0, &mut sz); } // retrieve needed size
+
+		let mut buf = vec![0usize; sz.div_ceil(std::mem::size_of::<usize>())];
+		bool_to_sysresult(
+			unsafe {
+				ffi::InitializeProcThreadAttributeList(buf.as_mut_ptr() as _, 1, 0, &mut sz)
+			},
+		)?;
+		let list = Self { buf, _handles: std::marker::PhantomData }; // from now on, must be deleted
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::UpdateProcThreadAttribute(
+					list.as_ptr(),
+					0,
+					PROC_THREAD_ATTRIBUTE_HANDLE_LIST,
+					handles.as_ptr() as _,
+					std::mem::size_of_val(handles),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| list)
+	}
+
+	fn as_ptr(&self) -> *mut std::ffi::c_void {
+		self.buf.as_ptr() as _
+	}
+}
+
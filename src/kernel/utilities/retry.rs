@@ -0,0 +1,61 @@
+use crate::co;
+use crate::decl::*;
+
+/// Runs a closure which returns a [`SysResult`](crate::SysResult), retrying it
+/// while it fails with one of the given transient errors.
+///
+/// The closure is run at most `attempts` times (at least once). Between the
+/// attempts, the thread [sleeps](crate::Sleep), starting with `delay_ms`
+/// milliseconds and doubling the delay after each failed attempt, so the
+/// system is not hammered.
+///
+/// The closure is not retried if it succeeds, or if it fails with an error not
+/// listed in `errors` – in this case, the error is returned right away. If all
+/// attempts fail, the last error is returned.
+///
+/// This is useful for operations which may fail temporarily, like file
+/// operations during an antivirus scan.
+///
+/// # Examples
+///
+/// Deleting a file which may be temporarily locked, trying at most 5 times,
+/// waiting 100, 200, 400 and 800 ms between the attempts:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::retry_on(
+///     &[co::ERROR::SHARING_VIOLATION, co::ERROR::ACCESS_DENIED],
+///     5,
+///     100,
+///     || w::DeleteFile("C:\\Temp\\foo.txt"),
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn retry_on<T, F>(
+	errors: &[co::ERROR],
+	attempts: u32,
+	delay_ms: u32,
+	func: F,
+) -> SysResult<T>
+	where F: FnMut() -> SysResult<T>,
+{
+	let mut func = func;
+	let mut delay_ms = delay_ms;
+	let mut attempt = 1;
+
+	loop {
+		match func() {
+			Ok(val) => return Ok(val),
+			Err(err) => {
+				if attempt >= attempts || !errors.contains(&err) {
+					return Err(err); // no more attempts, or not a transient error
+				}
+			},
+		}
+
+		Sleep(delay_ms);
+		delay_ms = delay_ms.saturating_mul(2); // back off
+		attempt += 1;
+	}
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use crate::decl::*;
+
+/// Measures elapsed time with high resolution, using
+/// [`QueryPerformanceCounter`](crate::QueryPerformanceCounter) and
+/// [`QueryPerformanceFrequency`](crate::QueryPerformanceFrequency).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let sw = w::Stopwatch::start();
+///
+/// // perform some operation...
+///
+/// println!("Operation lasted {:.2?}", sw.elapsed());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Stopwatch {
+	freq: i64,
+	t0: i64,
+}
+
+impl Stopwatch {
+	/// Creates a new stopwatch, which starts counting immediately.
+	#[must_use]
+	pub fn start() -> Self {
+		Self {
+			freq: QueryPerformanceFrequency().unwrap(), // never fails on Windows XP or later
+			t0: QueryPerformanceCounter().unwrap(),
+		}
+	}
+
+	/// Returns the time elapsed since the stopwatch was started or restarted.
+	#[must_use]
+	pub fn elapsed(&self) -> Duration {
+		let ticks = (QueryPerformanceCounter().unwrap() - self.t0).max(0) as u128;
+		let nanos = ticks * 1_000_000_000 / self.freq as u128; // u128 won't overflow
+		Duration::from_nanos(nanos as _)
+	}
+
+	/// Returns the time elapsed since the stopwatch was started or restarted,
+	/// then starts counting again from zero.
+	///
+	/// # Examples
+	///
+	/// Measuring each iteration of a loop:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let mut sw = w::Stopwatch::start();
+	/// for i in 0..10 {
+	///     // perform some operation...
+	///     println!("Iteration {} lasted {:?}", i, sw.restart());
+	/// }
+	/// ```
+	pub fn restart(&mut self) -> Duration {
+		let elapsed = self.elapsed();
+		self.t0 = QueryPerformanceCounter().unwrap();
+		elapsed
+	}
+}
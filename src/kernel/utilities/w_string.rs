@@ -418,7 +418,7 @@ impl Buffer {
 	#[must_use]
 	fn from_str_vec(v: &[impl AsRef<str>]) -> Self {
 		let tot_chars = v.iter() // number of chars of all strings, including terminating nulls
-			.fold(0, |tot, s| tot + s.as_ref().chars().count() + 1) // include terminating null
+			.fold(0, |tot, s| tot + s.as_ref().encode_utf16().count() + 1) // include terminating null
 			+ 1; // double terminating null
 		let mut new_self = Self::new_alloc_buf(tot_chars, ForceHeap::No);
 		v.iter()
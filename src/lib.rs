@@ -14,6 +14,7 @@
 #[cfg(feature = "dwm")] mod dwm;
 #[cfg(feature = "dxgi")] mod dxgi;
 #[cfg(feature = "gdi")] mod gdi;
+#[cfg(feature = "gdiplus")] mod gdiplus;
 #[cfg(feature = "kernel")] mod kernel;
 #[cfg(feature = "mf")] mod mf;
 #[cfg(feature = "ole")] mod ole;
@@ -24,7 +25,10 @@
 #[cfg(feature = "user")] mod user;
 #[cfg(feature = "uxtheme")] mod uxtheme;
 #[cfg(feature = "version")] mod version;
+#[cfg(feature = "winhttp")] mod winhttp;
 #[cfg(feature = "winspool")] mod winspool;
+#[cfg(feature = "ws2")] mod ws2;
+#[cfg(feature = "wtsapi")] mod wtsapi;
 #[cfg(all(feature = "advapi", feature = "comctl"))] mod advapi_comctl;
 #[cfg(all(feature = "advapi", feature = "shell"))] mod advapi_shell;
 #[cfg(all(feature = "comctl", feature = "gdi"))] mod comctl_gdi;
@@ -44,6 +48,7 @@ mod decl {
 	#[cfg(feature = "dwm")] pub use super::dwm::decl::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::decl::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::decl::*;
+	#[cfg(feature = "gdiplus")] pub use super::gdiplus::decl::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::decl::*;
 	#[cfg(feature = "mf")] pub use super::mf::decl::*;
 	#[cfg(feature = "ole")] pub use super::ole::decl::*;
@@ -54,7 +59,10 @@ mod decl {
 	#[cfg(feature = "user")] pub use super::user::decl::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::decl::*;
 	#[cfg(feature = "version")] pub use super::version::decl::*;
+	#[cfg(feature = "winhttp")] pub use super::winhttp::decl::*;
 	#[cfg(feature = "winspool")] pub use super::winspool::decl::*;
+	#[cfg(feature = "ws2")] pub use super::ws2::decl::*;
+	#[cfg(feature = "wtsapi")] pub use super::wtsapi::decl::*;
 	#[cfg(all(feature = "advapi", feature = "comctl"))] pub use super::advapi_comctl::decl::*;
 	#[cfg(all(feature = "advapi", feature = "shell"))] pub use super::advapi_shell::decl::*;
 	#[cfg(all(feature = "comctl", feature = "gdi"))] pub use super::comctl_gdi::decl::*;
@@ -88,7 +96,10 @@ pub mod co {
 	#[cfg(feature = "user")] pub use super::user::co::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::co::*;
 	#[cfg(feature = "version")] pub use super::version::co::*;
+	#[cfg(feature = "winhttp")] pub use super::winhttp::co::*;
 	#[cfg(feature = "winspool")] pub use super::winspool::co::*;
+	#[cfg(feature = "ws2")] pub use super::ws2::co::*;
+	#[cfg(feature = "wtsapi")] pub use super::wtsapi::co::*;
 	#[cfg(all(feature = "advapi", feature = "shell"))] pub use super::advapi_shell::co::*;
 }
 
@@ -102,6 +113,7 @@ pub mod guard {
 	#[cfg(feature = "advapi")] pub use super::advapi::guard::*;
 	#[cfg(feature = "comctl")] pub use super::comctl::guard::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::guard::*;
+	#[cfg(feature = "gdiplus")] pub use super::gdiplus::guard::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::guard::*;
 	#[cfg(feature = "mf")] pub use super::mf::guard::*;
 	#[cfg(feature = "ole")] pub use super::ole::guard::*;
@@ -109,7 +121,10 @@ pub mod guard {
 	#[cfg(feature = "user")] pub use super::user::guard::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::guard::*;
 	#[cfg(feature = "version")] pub use super::version::guard::*;
+	#[cfg(feature = "winhttp")] pub use super::winhttp::guard::*;
 	#[cfg(feature = "winspool")] pub use super::winspool::guard::*;
+	#[cfg(feature = "ws2")] pub use super::ws2::guard::*;
+	#[cfg(feature = "wtsapi")] pub use super::wtsapi::guard::*;
 }
 
 #[cfg(feature = "user")]
@@ -118,6 +133,15 @@ pub mod msg {
 
 	pub use super::user::messages::WndMsg;
 
+	#[cfg(feature = "comctl")]
+	pub mod acm {
+		//! Animation control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-animation-control-reference-messages),
+		//! whose constants have [`ACM`](crate::co::ACM) prefix.
+
+		pub use super::super::comctl::messages::acm::*;
+	}
+
 	#[cfg(feature = "user")]
 	pub mod bm {
 		//! Button control
@@ -168,6 +192,15 @@ pub mod msg {
 		pub use super::super::comctl::messages::hdm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod hkm {
+		//! Hot key control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-hot-key-control-reference-messages),
+		//! whose constants have [`HKM`](crate::co::HKM) prefix.
+
+		pub use super::super::comctl::messages::hkm::*;
+	}
+
 	#[cfg(feature = "user")]
 	pub mod lb {
 		//! ListBox control
@@ -204,6 +237,15 @@ pub mod msg {
 		pub use super::super::comctl::messages::pbm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod rb {
+		//! Rebar control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-messages),
+		//! whose constants have [`RB`](crate::co::RB) prefix.
+
+		pub use super::super::comctl::messages::rb::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod sb {
 		//! Status bar control
@@ -301,6 +343,7 @@ pub mod prelude {
 	#[cfg(feature = "dwm")] pub use super::dwm::traits::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::traits::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::traits::*;
+	#[cfg(feature = "gdiplus")] pub use super::gdiplus::traits::*;
 	#[cfg(feature = "gui")] pub use super::gui::traits::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::traits::*;
 	#[cfg(feature = "mf")] pub use super::mf::traits::*;
@@ -312,7 +355,9 @@ pub mod prelude {
 	#[cfg(feature = "user")] pub use super::user::traits::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::traits::*;
 	#[cfg(feature = "version")] pub use super::version::traits::*;
+	#[cfg(feature = "winhttp")] pub use super::winhttp::traits::*;
 	#[cfg(feature = "winspool")] pub use super::winspool::traits::*;
+	#[cfg(feature = "ws2")] pub use super::ws2::traits::*;
 	#[cfg(all(feature = "comctl", feature = "gdi"))] pub use super::comctl_gdi::traits::*;
 	#[cfg(all(feature = "comctl", feature = "shell"))] pub use super::comctl_shell::traits::*;
 	#[cfg(all(feature = "gdi", feature = "mf"))] pub use super::gdi_mf::traits::*;
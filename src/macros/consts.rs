@@ -264,7 +264,7 @@ macro_rules! const_cmd {
 
 		impl From<$name> for crate::co::CMD {
 			fn from(v: $name) -> Self {
-				Self(v.0)
+				unsafe { Self::from_raw(v.0) }
 			}
 		}
 	};
@@ -385,6 +385,15 @@ const_ordinary! { SEVERITY: u8;
 	FAILURE 1
 }
 
+const_ordinary! { STATFLAG: u32;
+	/// [`STATFLAG`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ne-wtypes-statflag)
+	/// enumeration (`u32`).
+	=>
+	DEFAULT 0
+	NONAME 1
+	NOOPEN 2
+}
+
 const_bitflag! { STGC: u32;
 	/// [`STGC`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ne-wtypes-stgc)
 	/// enumeration (`u32`).
@@ -429,6 +438,16 @@ const_ordinary! { STGMOVE: u32;
 	SHALLOWCOPY 2
 }
 
+const_ordinary! { STGTY: u32;
+	/// [`STGTY`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-stgty)
+	/// enumeration (`u32`).
+	=>
+	STORAGE 1
+	STREAM 2
+	LOCKBYTES 3
+	PROPERTY 4
+}
+
 const_ordinary! { STREAM_SEEK: u32;
 	/// [`STREAM_SEEK`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-stream_seek)
 	/// enumeration (`u32`).
@@ -1,4 +1,5 @@
 use crate::co;
+use crate::kernel::privs::*;
 use crate::prelude::*;
 
 const_no_debug_display! { HRESULT: u32;
@@ -31,7 +32,9 @@ const_no_debug_display! { HRESULT: u32;
 	/// ```
 	///
 	/// The numbers inside the brackets are the system error code, in
-	/// hexadecimal and decimal formats for convenience.
+	/// hexadecimal and decimal formats for convenience. The description is
+	/// retrieved only when the error is formatted; if it cannot be retrieved,
+	/// only the numbers are shown.
 	///
 	/// An [`ERROR`](crate::co::ERROR) can be seamlessly converted into an
 	/// `HRESULT`. This operation is equivalent to the
@@ -55,22 +58,12 @@ impl std::error::Error for HRESULT {
 
 impl std::fmt::Display for HRESULT {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		if self.0 > 0xffff {
-			write!(f, "[{:#010x}] {}", self.0, self.FormatMessage().trim_end())
-		} else {
-			write!(f, "[{:#06x}] {}", self.0, self.FormatMessage().trim_end())
-		}
+		fmt_error_code(f, None, self.0)
 	}
 }
 impl std::fmt::Debug for HRESULT {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		if self.0 > 0xffff {
-			write!(f, "HRESULT({:#010x} {}) {}",
-				self.0, self.0, self.FormatMessage().trim_end())
-		} else {
-			write!(f, "HRESULT({:#06x} {}) {}",
-				self.0, self.0, self.FormatMessage().trim_end())
-		}
+		fmt_error_code(f, Some("HRESULT"), self.0)
 	}
 }
 
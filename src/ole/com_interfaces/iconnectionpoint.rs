@@ -0,0 +1,96 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::mem::ManuallyDrop;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::ole::{privs::*, vts::*};
+use crate::prelude::*;
+
+com_interface! { IConnectionPoint: "b196b286-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpoint)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Usually obtained with
+	/// [`IConnectionPointContainer::FindConnectionPoint`](crate::prelude::ole_IConnectionPointContainer::FindConnectionPoint).
+}
+
+impl ole_IConnectionPoint for IConnectionPoint {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPoint`](crate::IConnectionPoint).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPoint: ole_IUnknown {
+	/// [`IConnectionPoint::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-advise)
+	/// method.
+	///
+	/// Returns a guard which keeps the connection cookie, and automatically
+	/// calls
+	/// [`IConnectionPoint::Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise)
+	/// when it goes out of scope.
+	fn Advise(&self, sink: &impl ole_IUnknown) -> HrResult<UnadviseGuard> {
+		let mut cookie = u32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).Advise)(
+					self.ptr(),
+					sink.ptr(),
+					&mut cookie,
+				)
+			},
+		).map(|_| {
+			let cp = ManuallyDrop::new(unsafe { IConnectionPoint::from_ptr(self.ptr()) });
+			unsafe { UnadviseGuard::new((*cp).clone(), cookie) } // guard keeps its own reference
+		})
+	}
+
+	/// [`IConnectionPoint::GetConnectionInterface`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectioninterface)
+	/// method.
+	fn GetConnectionInterface(&self) -> HrResult<co::IID> {
+		let mut iid = co::IID::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).GetConnectionInterface)(
+					self.ptr(),
+					&mut iid as *mut _ as _,
+				)
+			},
+		).map(|_| iid)
+	}
+
+	/// [`IConnectionPoint::GetConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectionpointcontainer)
+	/// method.
+	fn GetConnectionPointContainer(&self) -> HrResult<IConnectionPointContainer> {
+		let mut queried = unsafe { IConnectionPointContainer::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).GetConnectionPointContainer)(
+					self.ptr(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
+	/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+	/// method.
+	///
+	/// **Note:** Only needed if the cookie was taken out of the
+	/// [`UnadviseGuard`](crate::guard::UnadviseGuard) with
+	/// [`UnadviseGuard::leak`](crate::guard::UnadviseGuard::leak).
+	fn Unadvise(&self, cookie: u32) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe { (vt::<IConnectionPointVT>(self).Unadvise)(self.ptr(), cookie) },
+		)
+	}
+}
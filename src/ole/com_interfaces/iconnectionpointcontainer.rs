@@ -0,0 +1,65 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::ole::{privs::*, vts::*};
+use crate::prelude::*;
+
+com_interface! { IConnectionPointContainer: "b196b284-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpointcontainer)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Usually queried from an object which fires events, with
+	/// [`IUnknown::QueryInterface`](crate::prelude::ole_IUnknown::QueryInterface).
+}
+
+impl ole_IConnectionPointContainer for IConnectionPointContainer {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPointContainer`](crate::IConnectionPointContainer).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPointContainer: ole_IUnknown {
+	/// [`IConnectionPointContainer::FindConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpointcontainer-findconnectionpoint)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let obj: w::IUnknown; // initialized somewhere
+	/// # let obj = unsafe { w::IUnknown::null() };
+	/// let events_iid: co::IID; // source interface of the object
+	/// # let events_iid = co::IID::default();
+	/// let sink: w::IUnknown; // object which implements the source interface
+	/// # let sink = unsafe { w::IUnknown::null() };
+	///
+	/// let cpc = obj.QueryInterface::<w::IConnectionPointContainer>()?;
+	/// let cp = cpc.FindConnectionPoint(&events_iid)?;
+	/// let _cookie = cp.Advise(&sink)?;
+	///
+	/// // IConnectionPoint::Unadvise() automatically called
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn FindConnectionPoint(&self, iid: &co::IID) -> HrResult<IConnectionPoint> {
+		let mut queried = unsafe { IConnectionPoint::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointContainerVT>(self).FindConnectionPoint)(
+					self.ptr(),
+					iid as *const _ as _,
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+}
@@ -52,7 +52,7 @@ pub trait ole_ISequentialStream: ole_IUnknown {
 		let mut num_written = u32::default();
 		ok_to_hrresult(
 			unsafe {
-				(vt::<ISequentialStreamVT>(self).Read)(
+				(vt::<ISequentialStreamVT>(self).Write)(
 					self.ptr(),
 					vec_ptr(data) as _,
 					data.len() as _,
@@ -1,7 +1,12 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::mem::ManuallyDrop;
+use std::sync::{atomic::AtomicU32, atomic::Ordering, Mutex};
+
 use crate::co;
 use crate::decl::*;
+use crate::kernel::ffi_types::*;
 use crate::ole::{privs::*, vts::*};
 use crate::prelude::*;
 
@@ -26,6 +31,22 @@ com_interface! { IStream: "0000000c-0000-0000-c000-000000000046";
 	/// let stream = w::SHCreateMemStream(&raw_data)?;
 	/// # w::HrResult::Ok(())
 	/// ```
+	///
+	/// `IStream` implements [`Read`](std::io::Read),
+	/// [`Write`](std::io::Write) and [`Seek`](std::io::Seek), so it can be
+	/// used with the standard library:
+	///
+	/// ```no_run
+	/// use std::io::Read;
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let mut stream: w::IStream; // initialized somewhere
+	/// # let mut stream = w::SHCreateMemStream(&[])?;
+	///
+	/// let mut contents = Vec::<u8>::new();
+	/// stream.read_to_end(&mut contents)?;
+	/// # w::AnyResult::Ok(())
+	/// ```
 }
 
 impl ole_ISequentialStream for IStream {}
@@ -123,6 +144,35 @@ pub trait ole_IStream: ole_ISequentialStream {
 		)
 	}
 
+	/// [`IStream::Stat`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-istream-stat)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let stream: w::IStream; // initialized somewhere
+	/// # let stream = w::SHCreateMemStream(&[])?;
+	///
+	/// let stg = stream.Stat(co::STATFLAG::NONAME)?;
+	/// println!("Size: {} bytes", stg.cbSize);
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn Stat(&self, flag: co::STATFLAG) -> HrResult<STATSTG> {
+		let mut stg = STATSTG::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IStreamVT>(self).Stat)(
+					self.ptr(),
+					&mut stg as *mut _ as _,
+					flag.raw(),
+				)
+			},
+		).map(|_| stg)
+	}
+
 	/// [`IStream::UnlockRegion`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-istream-unlockregion)
 	/// method.
 	fn UnlockRegion(&self,
@@ -143,3 +193,400 @@ pub trait ole_IStream: ole_ISequentialStream {
 		)
 	}
 }
+
+impl IStream {
+	/// Creates a memory stream with a copy of the given data, by calling
+	/// [`SHCreateMemStream`](crate::SHCreateMemStream).
+	///
+	/// **Note:** To use this method, enable the `shell`
+	/// [Cargo feature](https://doc.rust-lang.org/cargo/reference/features.html#the-features-section).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let raw_data: Vec<u8>; // initialized somewhere
+	/// # let raw_data = Vec::<u8>::new();
+	///
+	/// let stream = w::IStream::from_slice(&raw_data)?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[cfg(feature = "shell")]
+	#[must_use]
+	pub fn from_slice(src: &[u8]) -> HrResult<IStream> {
+		SHCreateMemStream(src)
+	}
+
+	/// Creates a custom `IStream` implementation which reads, writes and seeks
+	/// into the given Rust stream, like a [`File`](std::fs::File) or a
+	/// [`Cursor`](std::io::Cursor). This allows Rust streams to be passed to
+	/// COM methods which expect an `IStream`.
+	///
+	/// The stream is owned by the COM object, and it's dropped when the last
+	/// reference is released.
+	///
+	/// The following methods are not supported, returning
+	/// [`STG_E_INVALIDFUNCTION`](crate::co::HRESULT::STG_E_INVALIDFUNCTION) or
+	/// [`E_NOTIMPL`](crate::co::HRESULT::E_NOTIMPL): `SetSize`, `LockRegion`,
+	/// `UnlockRegion` and `Clone`. `Revert` has no effect, and `Commit` flushes
+	/// the Rust stream.
+	///
+	/// If you just need to read from a memory block, prefer
+	/// [`SHCreateMemStream`](crate::SHCreateMemStream).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::io::Cursor;
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let stream = w::IStream::from_rust_stream(Cursor::new(Vec::<u8>::new()));
+	/// stream.Write(&[1, 2, 3])?;
+	///
+	/// let new_off = stream.Seek(0, co::STREAM_SEEK::SET)?;
+	/// let mut buf = [0u8; 3];
+	/// stream.Read(&mut buf)?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn from_rust_stream<S>(stream: S) -> IStream
+		where S: Read + Write + Seek + Send + 'static,
+	{
+		let box_impl = Box::new(RustStreamImpl {
+			vt: &RUST_STREAM_VT,
+			counter: AtomicU32::new(1),
+			stream: Mutex::new(Box::new(stream)),
+		});
+		unsafe { IStream::from_ptr(Box::into_raw(box_impl) as _) }
+	}
+}
+
+impl Read for IStream {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let len = buf.len().min(u32::MAX as _);
+		ole_ISequentialStream::Read(self, &mut buf[..len])
+			.map(|num_read| num_read as _)
+			.map_err(hresult_to_io_error)
+	}
+}
+
+impl Write for IStream {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		let len = buf.len().min(u32::MAX as _);
+		ole_ISequentialStream::Write(self, &buf[..len])
+			.map(|num_written| num_written as _)
+			.map_err(hresult_to_io_error)
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		self.Commit(co::STGC::DEFAULT)
+			.map_err(hresult_to_io_error)
+	}
+}
+
+impl Seek for IStream {
+	fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+		let (displacement, origin) = match pos {
+			SeekFrom::Start(off) => (off as i64, co::STREAM_SEEK::SET),
+			SeekFrom::Current(off) => (off, co::STREAM_SEEK::CUR),
+			SeekFrom::End(off) => (off, co::STREAM_SEEK::END),
+		};
+		self.Seek(displacement, origin)
+			.map_err(hresult_to_io_error)
+	}
+}
+
+fn hresult_to_io_error(hr: co::HRESULT) -> std::io::Error {
+	std::io::Error::other(hr)
+}
+
+//------------------------------------------------------------------------------
+
+/// Any Rust stream which can be wrapped by
+/// [`IStream::from_rust_stream`](crate::IStream::from_rust_stream).
+trait RustStream: Read + Write + Seek + Send {}
+impl<T: Read + Write + Seek + Send> RustStream for T {}
+
+static RUST_STREAM_VT: IStreamVT = IStreamVT {
+	ISequentialStreamVT: ISequentialStreamVT {
+		IUnknownVT: IUnknownVT {
+			QueryInterface: RustStreamImpl::QueryInterface,
+			AddRef: RustStreamImpl::AddRef,
+			Release: RustStreamImpl::Release,
+		},
+		Read: RustStreamImpl::Read,
+		Write: RustStreamImpl::Write,
+	},
+	Seek: RustStreamImpl::Seek,
+	SetSize: RustStreamImpl::SetSize,
+	CopyTo: RustStreamImpl::CopyTo,
+	Commit: RustStreamImpl::Commit,
+	Revert: RustStreamImpl::Revert,
+	LockRegion: RustStreamImpl::LockRegion,
+	UnlockRegion: RustStreamImpl::UnlockRegion,
+	Stat: RustStreamImpl::Stat,
+	Clone: RustStreamImpl::Clone,
+};
+
+/// The COM object created by
+/// [`IStream::from_rust_stream`](crate::IStream::from_rust_stream). Unlike
+/// other custom implementations, the object pointer is the heap block itself,
+/// whose first field points to a static virtual table, so the object is not
+/// tied to the lifetime of any wrapper.
+#[repr(C)]
+struct RustStreamImpl {
+	vt: &'static IStreamVT, // must be the first field
+	counter: AtomicU32,
+	stream: Mutex<Box<dyn RustStream>>,
+}
+
+impl RustStreamImpl {
+	#[must_use]
+	fn obj<'a>(p: COMPTR) -> &'a Self {
+		unsafe { &*(p as *const Self) }
+	}
+
+	#[must_use]
+	fn io_error_to_hres(err: std::io::Error, default: co::HRESULT) -> HRES {
+		match err.raw_os_error() {
+			Some(code) => unsafe { co::ERROR::from_raw(code as _) }.to_hresult().raw(),
+			None => default.raw(),
+		}
+	}
+
+	/// Reads until the buffer is full or the end of the stream is reached.
+	fn read_full(&self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let mut stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+		let mut total = 0;
+		while total < buf.len() {
+			match stream.read(&mut buf[total..]) {
+				Ok(0) => break, // end of stream
+				Ok(num_read) => total += num_read,
+				Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+				Err(e) => return Err(e),
+			}
+		}
+		Ok(total)
+	}
+
+	fn QueryInterface(p: COMPTR, riid: PCVOID, ppv: *mut COMPTR) -> HRES {
+		if ppv.is_null() {
+			return co::HRESULT::E_POINTER.raw();
+		}
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID
+			|| *riid == ISequentialStream::IID
+			|| *riid == IStream::IID
+		{
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.raw()
+		} else {
+			unsafe { *ppv = std::ptr::null_mut(); }
+			co::HRESULT::E_NOINTERFACE.raw()
+		}
+	}
+
+	fn AddRef(p: COMPTR) -> u32 {
+		Self::obj(p).counter.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: COMPTR) -> u32 {
+		let count = Self::obj(p).counter.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			std::sync::atomic::fence(Ordering::Acquire); // see all uses before dropping, like Arc
+			let _ = unsafe { Box::from_raw(p as *mut Self) }; // free the memory block
+		}
+		count
+	}
+
+	fn Read(p: COMPTR, pv: PVOID, cb: u32, pcbRead: *mut u32) -> HRES {
+		if cb == 0 { // pv may be null, which is not allowed in a slice
+			if !pcbRead.is_null() {
+				unsafe { *pcbRead = 0; }
+			}
+			return co::HRESULT::S_OK.raw();
+		} else if pv.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.raw();
+		}
+		let buf = unsafe { std::slice::from_raw_parts_mut(pv as *mut u8, cb as _) };
+		let (num_read, hr) = match Self::obj(p).read_full(buf) {
+			Ok(num_read) => (num_read, if num_read == buf.len() {
+				co::HRESULT::S_OK.raw()
+			} else {
+				co::HRESULT::S_FALSE.raw() // end of stream reached
+			}),
+			Err(e) => (0, Self::io_error_to_hres(e, co::HRESULT::STG_E_READFAULT)),
+		};
+		if !pcbRead.is_null() {
+			unsafe { *pcbRead = num_read as _; }
+		}
+		hr
+	}
+
+	fn Write(p: COMPTR, pv: PCVOID, cb: u32, pcbWritten: *mut u32) -> HRES {
+		if cb == 0 { // pv may be null, which is not allowed in a slice
+			if !pcbWritten.is_null() {
+				unsafe { *pcbWritten = 0; }
+			}
+			return co::HRESULT::S_OK.raw();
+		} else if pv.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.raw();
+		}
+		let data = unsafe { std::slice::from_raw_parts(pv as *const u8, cb as _) };
+		let mut stream = Self::obj(p).stream.lock().unwrap_or_else(|e| e.into_inner());
+
+		let mut total = 0;
+		let mut hr = co::HRESULT::S_OK.raw();
+		while total < data.len() {
+			match stream.write(&data[total..]) {
+				Ok(0) => {
+					hr = co::HRESULT::STG_E_MEDIUMFULL.raw();
+					break;
+				},
+				Ok(num_written) => total += num_written,
+				Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+				Err(e) => {
+					hr = Self::io_error_to_hres(e, co::HRESULT::STG_E_WRITEFAULT);
+					break;
+				},
+			}
+		}
+		if !pcbWritten.is_null() {
+			unsafe { *pcbWritten = total as _; }
+		}
+		hr
+	}
+
+	fn Seek(p: COMPTR, dlibMove: i64, dwOrigin: u32, plibNewPosition: *mut u64) -> HRES {
+		let pos = match unsafe { co::STREAM_SEEK::from_raw(dwOrigin) } {
+			co::STREAM_SEEK::SET => {
+				if dlibMove < 0 {
+					return co::HRESULT::STG_E_INVALIDFUNCTION.raw(); // before the beginning
+				}
+				SeekFrom::Start(dlibMove as _)
+			},
+			co::STREAM_SEEK::CUR => SeekFrom::Current(dlibMove),
+			co::STREAM_SEEK::END => SeekFrom::End(dlibMove),
+			_ => return co::HRESULT::STG_E_INVALIDFUNCTION.raw(),
+		};
+
+		let mut stream = Self::obj(p).stream.lock().unwrap_or_else(|e| e.into_inner());
+		match stream.seek(pos) {
+			Ok(new_pos) => {
+				if !plibNewPosition.is_null() {
+					unsafe { *plibNewPosition = new_pos; }
+				}
+				co::HRESULT::S_OK.raw()
+			},
+			Err(e) => Self::io_error_to_hres(e, co::HRESULT::STG_E_INVALIDFUNCTION),
+		}
+	}
+
+	fn SetSize(_p: COMPTR, _libNewSize: u64) -> HRES {
+		co::HRESULT::E_NOTIMPL.raw()
+	}
+
+	fn CopyTo(p: COMPTR,
+		pstm: COMPTR,
+		cb: u64,
+		pcbRead: *mut u64,
+		pcbWritten: *mut u64,
+	) -> HRES
+	{
+		if pstm.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.raw();
+		}
+		let dest = ManuallyDrop::new(unsafe { IStream::from_ptr(pstm) });
+		let mut buf = vec![0u8; 64 * 1024];
+		let (mut total_read, mut total_written) = (0u64, 0u64);
+		let mut hr = co::HRESULT::S_OK.raw();
+
+		while total_read < cb {
+			let chunk_len = (cb - total_read).min(buf.len() as _) as usize;
+			let num_read = match Self::obj(p).read_full(&mut buf[..chunk_len]) {
+				Ok(num_read) => num_read,
+				Err(e) => {
+					hr = Self::io_error_to_hres(e, co::HRESULT::STG_E_READFAULT);
+					break;
+				},
+			};
+			if num_read == 0 {
+				break; // end of stream
+			}
+			total_read += num_read as u64;
+
+			match dest.Write(&buf[..num_read]) { // lock is not held, dest may be this same stream
+				Ok(num_written) => total_written += num_written as u64,
+				Err(e) => {
+					hr = e.raw();
+					break;
+				},
+			}
+			if num_read < chunk_len {
+				break; // end of stream
+			}
+		}
+
+		if !pcbRead.is_null() {
+			unsafe { *pcbRead = total_read; }
+		}
+		if !pcbWritten.is_null() {
+			unsafe { *pcbWritten = total_written; }
+		}
+		hr
+	}
+
+	fn Commit(p: COMPTR, _grfCommitFlags: u32) -> HRES {
+		let mut stream = Self::obj(p).stream.lock().unwrap_or_else(|e| e.into_inner());
+		match stream.flush() {
+			Ok(_) => co::HRESULT::S_OK.raw(),
+			Err(e) => Self::io_error_to_hres(e, co::HRESULT::STG_E_WRITEFAULT),
+		}
+	}
+
+	fn Revert(_p: COMPTR) -> HRES {
+		co::HRESULT::S_OK.raw() // not transacted, no effect
+	}
+
+	fn LockRegion(_p: COMPTR, _libOffset: u64, _cb: u64, _dwLockType: u32) -> HRES {
+		co::HRESULT::STG_E_INVALIDFUNCTION.raw()
+	}
+
+	fn UnlockRegion(_p: COMPTR, _libOffset: u64, _cb: u64, _dwLockType: u32) -> HRES {
+		co::HRESULT::STG_E_INVALIDFUNCTION.raw()
+	}
+
+	fn Stat(p: COMPTR, pstatstg: PVOID, _grfStatFlag: u32) -> HRES {
+		if pstatstg.is_null() {
+			return co::HRESULT::STG_E_INVALIDPOINTER.raw();
+		}
+		let mut stream = Self::obj(p).stream.lock().unwrap_or_else(|e| e.into_inner());
+		let size = stream.stream_position()
+			.and_then(|cur_pos| {
+				let end_pos = stream.seek(SeekFrom::End(0))?;
+				stream.seek(SeekFrom::Start(cur_pos))?; // restore position
+				Ok(end_pos)
+			});
+
+		match size {
+			Ok(size) => {
+				let mut stg = STATSTG::default(); // no name is returned
+				stg.r#type = co::STGTY::STREAM;
+				stg.cbSize = size;
+				stg.grfMode = co::STGM::READWRITE;
+				unsafe { std::ptr::write(pstatstg as *mut STATSTG, stg); } // caller memory may be uninitialized
+				co::HRESULT::S_OK.raw()
+			},
+			Err(e) => Self::io_error_to_hres(e, co::HRESULT::STG_E_ACCESSDENIED),
+		}
+	}
+
+	fn Clone(_p: COMPTR, ppstm: *mut COMPTR) -> HRES {
+		if !ppstm.is_null() {
+			unsafe { *ppstm = std::ptr::null_mut(); }
+		}
+		co::HRESULT::E_NOTIMPL.raw()
+	}
+}
@@ -1,5 +1,7 @@
 mod iadvisesink;
 mod ibindctx;
+mod iconnectionpoint;
+mod iconnectionpointcontainer;
 mod idataobject;
 mod idroptarget;
 mod imoniker;
@@ -15,6 +17,8 @@ mod iunknown;
 pub mod decl {
 	pub use super::iadvisesink::IAdviseSink;
 	pub use super::ibindctx::IBindCtx;
+	pub use super::iconnectionpoint::IConnectionPoint;
+	pub use super::iconnectionpointcontainer::IConnectionPointContainer;
 	pub use super::idataobject::IDataObject;
 	pub use super::idroptarget::IDropTarget;
 	pub use super::imoniker::IMoniker;
@@ -31,6 +35,8 @@ pub mod decl {
 pub mod traits {
 	pub use super::iadvisesink::ole_IAdviseSink;
 	pub use super::ibindctx::ole_IBindCtx;
+	pub use super::iconnectionpoint::ole_IConnectionPoint;
+	pub use super::iconnectionpointcontainer::ole_IConnectionPointContainer;
 	pub use super::idataobject::ole_IDataObject;
 	pub use super::imoniker::ole_IMoniker;
 	pub use super::ipersist::ole_IPersist;
@@ -89,6 +89,56 @@ impl CoTaskMemFreeGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation which automatically calls
+/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+/// when the object goes out of scope.
+pub struct UnadviseGuard {
+	cp: IConnectionPoint,
+	cookie: u32,
+}
+
+impl Drop for UnadviseGuard {
+	fn drop(&mut self) {
+		if self.cookie != 0 {
+			let _ = self.cp.Unadvise(self.cookie); // ignore errors
+		}
+	}
+}
+
+impl UnadviseGuard {
+	/// Constructs the guard by taking ownership of the connection point and
+	/// the cookie.
+	///
+	/// # Safety
+	///
+	/// Be sure the cookie was returned by
+	/// [`IConnectionPoint::Advise`](crate::prelude::ole_IConnectionPoint::Advise)
+	/// on the given connection point.
+	#[must_use]
+	pub const unsafe fn new(cp: IConnectionPoint, cookie: u32) -> Self {
+		Self { cp, cookie }
+	}
+
+	/// Returns the connection cookie.
+	#[must_use]
+	pub const fn cookie(&self) -> u32 {
+		self.cookie
+	}
+
+	/// Ejects the connection cookie, leaving zero in its place.
+	///
+	/// Since the cookie will be invalidated, the destructor will not run. It's
+	/// your responsibility to call
+	/// [`IConnectionPoint::Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise),
+	/// otherwise the sink will remain connected.
+	#[must_use]
+	pub fn leak(&mut self) -> u32 {
+		std::mem::replace(&mut self.cookie, 0)
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation which automatically calls
 /// [`CoUninitialize`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-couninitialize)
 /// when the object goes out of scope.
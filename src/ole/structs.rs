@@ -241,6 +241,44 @@ impl SNB {
 	}
 }
 
+/// [`STATSTG`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ns-objidl-statstg)
+/// struct.
+///
+/// The `pwcsName` string, if any, is freed with
+/// [`CoTaskMemFree`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemfree)
+/// when the struct is dropped.
+#[repr(C)]
+pub struct STATSTG {
+	pwcsName: *mut u16,
+	pub r#type: co::STGTY,
+	pub cbSize: u64,
+	pub mtime: FILETIME,
+	pub ctime: FILETIME,
+	pub atime: FILETIME,
+	pub grfMode: co::STGM,
+	pub grfLocksSupported: co::LOCKTYPE,
+	pub clsid: co::CLSID,
+	pub grfStateBits: u32,
+	reserved: u32,
+}
+
+impl_default!(STATSTG);
+
+impl Drop for STATSTG {
+	fn drop(&mut self) {
+		let _ = unsafe { CoTaskMemFreeGuard::new(self.pwcsName as _, 0) }; // size is irrelevant
+	}
+}
+
+impl STATSTG {
+	/// Returns the `pwcsName` field.
+	#[must_use]
+	pub fn pwcsName(&self) -> Option<String> {
+		unsafe { self.pwcsName.as_mut() }
+			.map(|p| unsafe { WString::from_wchars_nullt(p) }.to_string())
+	}
+}
+
 /// [`STGMEDIUM`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ns-objidl-ustgmedium-r1)
 /// struct.
 #[repr(C)]
@@ -27,6 +27,23 @@ pub struct IBindCtxVT {
 	pub RevokeObjectParam: fn(COMPTR, PCSTR) -> HRES,
 }
 
+#[repr(C)]
+pub struct IConnectionPointVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetConnectionInterface: fn(COMPTR, PVOID) -> HRES,
+	pub GetConnectionPointContainer: fn(COMPTR, *mut COMPTR) -> HRES,
+	pub Advise: fn(COMPTR, COMPTR, *mut u32) -> HRES,
+	pub Unadvise: fn(COMPTR, u32) -> HRES,
+	pub EnumConnections: fn(COMPTR, *mut COMPTR) -> HRES,
+}
+
+#[repr(C)]
+pub struct IConnectionPointContainerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub EnumConnectionPoints: fn(COMPTR, *mut COMPTR) -> HRES,
+	pub FindConnectionPoint: fn(COMPTR, PCVOID, *mut COMPTR) -> HRES,
+}
+
 #[repr(C)]
 pub struct IDataObjectVT {
 	pub IUnknownVT: IUnknownVT,
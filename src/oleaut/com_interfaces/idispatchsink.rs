@@ -0,0 +1,187 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::{privs::*, vts::*};
+use crate::oleaut::{privs::*, vts::*};
+use crate::prelude::*;
+
+com_interface_userdef! { IDispatchSink, IDispatchSinkImpl: "00020400-0000-0000-c000-000000000046";
+	/// Custom implementation of the
+	/// [`IDispatch`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nn-oaidl-idispatch)
+	/// COM interface, used to receive events from a connection point whose
+	/// source interface is a dispinterface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let obj: w::IUnknown; // initialized somewhere
+	/// # let obj = unsafe { w::IUnknown::null() };
+	/// let events_iid: co::IID; // dispinterface of the object events
+	/// # let events_iid = co::IID::default();
+	///
+	/// let sink = w::IDispatchSink::new_impl();
+	/// sink.set_source_iid(&events_iid);
+	///
+	/// sink.Invoke(1, move |args: &[&w::VARIANT]| -> w::HrResult<()> {
+	///     println!("Event 1 fired with {} arguments.", args.len());
+	///     Ok(())
+	/// });
+	///
+	/// let cpc = obj.QueryInterface::<w::IConnectionPointContainer>()?;
+	/// let cp = cpc.FindConnectionPoint(&events_iid)?;
+	/// let _cookie = cp.Advise(&sink)?; // events arrive until dropped
+	/// # w::HrResult::Ok(())
+	/// ```
+}
+
+impl IDispatchSink {
+	/// Defines the source interface IID, which will be answered by
+	/// [`IUnknown::QueryInterface`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-queryinterface),
+	/// in addition to `IUnknown` and `IDispatch`.
+	///
+	/// Must be set before the sink is passed to
+	/// [`IConnectionPoint::Advise`](crate::prelude::ole_IConnectionPoint::Advise).
+	pub fn set_source_iid(&self, iid: &co::IID) {
+		let mut box_impl = std::mem::ManuallyDrop::new(unsafe { Box::from_raw(self.0) });
+		box_impl.source_iid = *iid;
+	}
+
+	/// [`IDispatch::Invoke`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nf-oaidl-idispatch-invoke)
+	/// method, called when the event with the given `DISPID` is fired.
+	///
+	/// The positional arguments are passed in their natural order. Events
+	/// without a handler are silently accepted.
+	pub fn Invoke<F>(&self, dispid: i32, func: F)
+		where F: Fn(&[&VARIANT]) -> HrResult<()> + 'static,
+	{
+		let mut box_impl = std::mem::ManuallyDrop::new(unsafe { Box::from_raw(self.0) });
+		box_impl.Invoke.insert(dispid, Box::new(func));
+	}
+}
+
+type InvokeFn = Box<dyn Fn(&[&VARIANT]) -> HrResult<()>>;
+
+#[repr(C)]
+struct IDispatchSinkImpl {
+	vt: IDispatchVT,
+	counter: AtomicU32,
+	source_iid: co::IID,
+	Invoke: HashMap<i32, InvokeFn>,
+}
+
+impl IDispatchSinkImpl {
+	fn new() -> Self {
+		Self {
+			vt: IDispatchVT {
+				IUnknownVT: IUnknownVT {
+					QueryInterface: Self::QueryInterface,
+					AddRef: Self::AddRef,
+					Release: Self::Release,
+				},
+				GetTypeInfoCount: Self::GetTypeInfoCount,
+				GetTypeInfo: Self::GetTypeInfo,
+				GetIDsOfNames: Self::GetIDsOfNames,
+				Invoke: Self::Invoke,
+			},
+			counter: AtomicU32::new(1),
+			source_iid: IDispatch::IID,
+			Invoke: HashMap::new(),
+		}
+	}
+
+	fn QueryInterface(p: COMPTR, riid: PCVOID, ppv: *mut COMPTR) -> HRES {
+		let box_impl = box_impl_of::<Self>(p);
+		let iid = unsafe { &*(riid as *const co::IID) };
+		if *iid == IUnknown::IID || *iid == IDispatch::IID || *iid == box_impl.source_iid {
+			unsafe { *ppv = p; }
+			Self::AddRef(p);
+			co::HRESULT::S_OK.raw()
+		} else {
+			unsafe { *ppv = std::ptr::null_mut(); }
+			co::HRESULT::E_NOINTERFACE.raw()
+		}
+	}
+
+	fn AddRef(p: COMPTR) -> u32 {
+		let box_impl = box_impl_of::<Self>(p);
+		box_impl.counter.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: COMPTR) -> u32 {
+		let mut box_impl = box_impl_of::<Self>(p);
+		let count = box_impl.counter.fetch_sub(1, Ordering::Relaxed) - 1;
+		if count == 0 {
+			unsafe { std::mem::ManuallyDrop::drop(&mut box_impl); } // free the memory block
+		}
+		count
+	}
+
+	fn GetTypeInfoCount(_p: COMPTR, pctinfo: *mut u32) -> HRES {
+		unsafe { *pctinfo = 0; } // no type information provided
+		co::HRESULT::S_OK.raw()
+	}
+
+	fn GetTypeInfo(_p: COMPTR, _iTInfo: u32, _lcid: u32, ppTInfo: *mut COMPTR) -> HRES {
+		unsafe { *ppTInfo = std::ptr::null_mut(); }
+		co::HRESULT::E_NOTIMPL.raw()
+	}
+
+	fn GetIDsOfNames(
+		_p: COMPTR,
+		_riid: PCVOID,
+		_rgszNames: *const PCSTR,
+		_cNames: u32,
+		_lcid: u32,
+		_rgDispId: PVOID,
+	) -> HRES
+	{
+		co::HRESULT::E_NOTIMPL.raw()
+	}
+
+	fn Invoke(
+		p: COMPTR,
+		dispIdMember: i32,
+		_riid: PCVOID,
+		_lcid: u32,
+		_wFlags: u16,
+		pDispParams: PVOID,
+		_pVarResult: PVOID,
+		_pExcepInfo: PVOID,
+		_puArgErr: *mut u32,
+	) -> HRES
+	{
+		let box_impl = box_impl_of::<Self>(p);
+		hrresult_to_hres(
+			match box_impl.Invoke.get(&dispIdMember) {
+				Some(func) => {
+					let args = if pDispParams.is_null() {
+						Vec::new()
+					} else {
+						let dp = unsafe { &*(pDispParams as *const DispParams) };
+						if dp.rgvarg.is_null() {
+							Vec::new()
+						} else {
+							let all = unsafe {
+								std::slice::from_raw_parts(dp.rgvarg, dp.cArgs as _)
+							};
+							all[dp.cNamedArgs as usize..].iter().rev().collect::<Vec<_>>() // positional args come in reverse order, after named ones
+						}
+					};
+					func(&args)
+				},
+				None => Ok(()),
+			},
+		)
+	}
+}
@@ -1,9 +1,11 @@
 mod idispatch;
+mod idispatchsink;
 mod ipropertystore;
 mod itypeinfo;
 
 pub mod decl {
 	pub use super::idispatch::IDispatch;
+	pub use super::idispatchsink::IDispatchSink;
 	pub use super::ipropertystore::IPropertyStore;
 	pub use super::itypeinfo::ITypeInfo;
 }
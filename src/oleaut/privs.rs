@@ -1,3 +1,15 @@
+#![allow(non_snake_case)]
+
 const_values_num_privs! {
 	PID_FIRST_USABLE u32 = 0x2
 }
+
+/// [`DISPPARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/ns-oaidl-dispparams)
+/// struct.
+#[repr(C)]
+pub(in crate::oleaut) struct DispParams {
+	pub(in crate::oleaut) rgvarg: *mut crate::VARIANT,
+	pub(in crate::oleaut) rgdispidNamedArgs: *mut i32,
+	pub(in crate::oleaut) cArgs: u32,
+	pub(in crate::oleaut) cNamedArgs: u32,
+}
@@ -1,5 +1,6 @@
 use crate::kernel::ffi_types::*;
 
 extern_sys! { "psapi";
+	GetPerformanceInfo(PVOID, u32) -> BOOL
 	GetProcessMemoryInfo(HANDLE, PVOID, u32) -> BOOL
 }
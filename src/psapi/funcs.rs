@@ -0,0 +1,33 @@
+#![allow(non_snake_case)]
+
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::psapi::ffi;
+
+/// [`GetPerformanceInfo`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-getperformanceinfo)
+/// function.
+///
+/// Memory values are expressed in pages; multiply them by the `PageSize`
+/// member to get the number of bytes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let pi = w::GetPerformanceInfo()?;
+/// println!("Committed: {} bytes, {} processes",
+///     pi.CommitTotal * pi.PageSize, pi.ProcessCount);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub fn GetPerformanceInfo() -> SysResult<PERFORMANCE_INFORMATION> {
+	let mut pi = PERFORMANCE_INFORMATION::default();
+	bool_to_sysresult(
+		unsafe {
+			ffi::GetPerformanceInfo(
+				&mut pi as *mut _ as _,
+				std::mem::size_of::<PERFORMANCE_INFORMATION>() as _,
+			)
+		},
+	).map(|_| pi)
+}
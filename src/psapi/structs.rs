@@ -1,5 +1,27 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+/// [`PERFORMANCE_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/ns-psapi-performance_information)
+/// struct.
+#[repr(C)]
+pub struct PERFORMANCE_INFORMATION {
+	cb: u32,
+	pub CommitTotal: usize,
+	pub CommitLimit: usize,
+	pub CommitPeak: usize,
+	pub PhysicalTotal: usize,
+	pub PhysicalAvailable: usize,
+	pub SystemCache: usize,
+	pub KernelTotal: usize,
+	pub KernelPaged: usize,
+	pub KernelNonpaged: usize,
+	pub PageSize: usize,
+	pub HandleCount: u32,
+	pub ProcessCount: u32,
+	pub ThreadCount: u32,
+}
+
+impl_default_with_size!(PERFORMANCE_INFORMATION, cb);
+
 /// [`PROCESS_MEMORY_COUNTERS_EX`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/ns-psapi-process_memory_counters_ex)
 /// struct.
 #[repr(C)]
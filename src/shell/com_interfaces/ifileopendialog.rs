@@ -67,11 +67,13 @@ pub trait shell_IFileOpenDialog: shell_IFileDialog {
 		/// method.
 		///
 		/// If you chose multiple files, this is the method to retrieve the
-		/// paths.
+		/// paths. The simplest way is calling
+		/// [`IShellItemArray::file_system_paths`](crate::prelude::shell_IShellItemArray::file_system_paths).
 		///
 		/// # Examples
 		///
-		/// Collecting the file paths into a [`Vec`](std::vec::Vec):
+		/// Collecting the file paths into a [`Vec`](std::vec::Vec), failing if
+		/// any item has no file system path:
 		///
 		/// ```no_run
 		/// use winsafe::{self as w, prelude::*, co};
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
@@ -25,6 +26,43 @@ impl shell_IShellItemArray for IShellItemArray {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait shell_IShellItemArray: ole_IUnknown {
+	/// Returns the file system paths of all items, by calling
+	/// [`IShellItem::GetDisplayName`](crate::prelude::shell_IShellItem::GetDisplayName)
+	/// with [`co::SIGDN::FILESYSPATH`](crate::co::SIGDN::FILESYSPATH) on each
+	/// one of them.
+	///
+	/// Items which are not part of the file system – like virtual folders or
+	/// devices – don't have a path, and they are skipped: they are the ones
+	/// without the [`co::SFGAO::FILESYSTEM`](crate::co::SFGAO::FILESYSTEM)
+	/// attribute. To make sure such items can't be chosen in the first place,
+	/// set the [`co::FOS::FORCEFILESYSTEM`](crate::co::FOS::FORCEFILESYSTEM)
+	/// option in the file dialog.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let fo: w::IFileOpenDialog; // initialized somewhere
+	/// # let fo = unsafe { w::IFileOpenDialog::null() };
+	///
+	/// for path in fo.GetResults()?.file_system_paths()? {
+	///     println!("{}", path);
+	/// }
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn file_system_paths(&self) -> HrResult<Vec<String>> {
+		let mut paths = Vec::<String>::with_capacity(self.GetCount()? as _);
+		for shi in self.iter()? {
+			let shi = shi?;
+			if shi.GetAttributes(co::SFGAO::FILESYSTEM)?.has(co::SFGAO::FILESYSTEM) {
+				paths.push(shi.GetDisplayName(co::SIGDN::FILESYSPATH)?);
+			}
+		}
+		Ok(paths)
+	}
+
 	/// Returns an iterator over the [`IShellItem`](crate::IShellItem) elements
 	/// by calling
 	/// [`IShellItemArray::GetCount`](crate::prelude::shell_IShellItemArray::GetCount)
@@ -6,6 +6,10 @@ extern_sys! { "shell32";
 	DragFinish(HANDLE)
 	DragQueryFileW(HANDLE, u32, PSTR, u32) -> u32
 	DragQueryPoint(HANDLE, PVOID) -> BOOL
+	ExtractIconExW(PCSTR, i32, PVOID, PVOID, u32) -> u32
+	ILClone(PCVOID) -> PVOID
+	ILFindLastID(PCVOID) -> PVOID
+	ILRemoveLastID(PVOID) -> BOOL
 	SHAddToRecentDocs(u32, PCVOID)
 	SHCreateItemFromParsingName(PCSTR, PVOID, PCVOID, *mut COMPTR) -> HRES
 	Shell_NotifyIconW(u32, PCVOID) -> BOOL
@@ -14,6 +18,8 @@ extern_sys! { "shell32";
 	SHFileOperationW(PVOID) -> i32
 	SHGetFileInfoW(PCSTR, u32, PVOID, u32, u32) -> usize
 	SHGetStockIconInfo(u32, u32, PVOID) -> HRES
+	SHOpenFolderAndSelectItems(PCVOID, u32, *const PCVOID, u32) -> HRES
+	SHParseDisplayName(PCSTR, PVOID, *mut PVOID, u32, *mut u32) -> HRES
 }
 
 extern_sys! { "shlwapi";
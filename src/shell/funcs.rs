@@ -11,6 +11,14 @@ use crate::shell::ffi;
 /// [`CommandLineToArgv`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw)
 /// function.
 ///
+/// Splits the command line following the same quoting rules of the C runtime.
+/// The array returned by the system is freed with
+/// [`LocalFree`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree)
+/// before the function returns.
+///
+/// Note that, if `cmd_line` is empty, the system returns the path of the
+/// current executable as the only argument.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -34,16 +42,50 @@ pub fn CommandLineToArgv(cmd_line: &str) -> SysResult<Vec<String>> {
 	if lp_arr.is_null() {
 		return Err(GetLastError());
 	}
+	let _arr_guard = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(lp_arr as _)) }; // freed at the end of scope
 
-	let mut strs = Vec::with_capacity(num_args as _);
-	for lp in unsafe { std::slice::from_raw_parts(lp_arr, num_args as _) }.iter() {
-		strs.push(unsafe { WString::from_wchars_nullt(*lp) }.to_string());
-	}
-
-	let _ = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(lp_arr as _)) };
+	let strs = unsafe { std::slice::from_raw_parts(lp_arr, num_args as _) }
+		.iter()
+		.map(|lp| unsafe { WString::from_wchars_nullt(*lp) }.to_string())
+		.collect::<Vec<_>>();
 	Ok(strs)
 }
 
+/// Calls [`HINSTANCE::ExtractIconEx`](crate::prelude::shell_Hinstance::ExtractIconEx)
+/// with index `-1` to retrieve the number of icons in an executable, DLL or
+/// icon file.
+///
+/// Returns zero if the file has no icons.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let path = "C:\\Windows\\System32\\shell32.dll";
+/// let count = w::extract_icon_count(path)?;
+///
+/// for idx in 0..count {
+///     let (large, _) = w::HINSTANCE::ExtractIconEx(path, idx as _)?;
+///     println!("Icon {}: {}", idx, large.is_some());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn extract_icon_count(file: &str) -> SysResult<u32> {
+	match unsafe {
+		ffi::ExtractIconExW(
+			WString::from_str(file).as_ptr(),
+			-1,
+			std::ptr::null_mut(),
+			std::ptr::null_mut(),
+			0,
+		)
+	} {
+		u32::MAX => Err(GetLastError()),
+		n => Ok(n),
+	}
+}
+
 /// [`GetAllUsersProfileDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-getallusersprofiledirectoryw)
 /// function.
 ///
@@ -305,11 +347,41 @@ pub fn SHFileOperation(file_op: &mut SHFILEOPSTRUCT) -> HrResult<()> {
 
 /// [`SHGetFileInfo`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shgetfileinfow)
 /// function.
+///
+/// The meaning of the returned number depends on `flags`. With
+/// [`SHGFI::SYSICONINDEX`](crate::co::SHGFI::SYSICONINDEX), it's the handle
+/// to the system image list, and `iIcon` is the index of the icon within it;
+/// this image list is shared by the whole system and must not be destroyed.
+/// If you need the image list itself, prefer
+/// [`HIMAGELIST::system_image_list`](crate::prelude::comctl_shell_Himagelist::system_image_list).
+///
+/// With [`SHGFI::ICON`](crate::co::SHGFI::ICON), the `hIcon` field must be
+/// destroyed, which is done automatically by the returned guard.
+///
+/// # Examples
+///
+/// Retrieving the display name, the type name and the system image list
+/// index of a file:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let (_, shfi) = w::SHGetFileInfo(
+///     "C:\\Temp\\report.pdf",
+///     co::FILE_ATTRIBUTE::NORMAL,
+///     co::SHGFI::DISPLAYNAME | co::SHGFI::TYPENAME
+///         | co::SHGFI::SYSICONINDEX | co::SHGFI::SMALLICON,
+/// )?;
+///
+/// println!("{} - {} - icon #{}",
+///     shfi.szDisplayName(), shfi.szTypeName(), shfi.iIcon);
+/// # w::HrResult::Ok(())
+/// ```
 pub fn SHGetFileInfo(
 	path: &str,
 	file_attrs: co::FILE_ATTRIBUTE,
 	flags: co::SHGFI,
-) -> HrResult<(u32, DestroyIconShfiGuard)>
+) -> HrResult<(usize, DestroyIconShfiGuard)>
 {
 	let mut shfi = SHFILEINFO::default();
 	unsafe {
@@ -321,7 +393,7 @@ pub fn SHGetFileInfo(
 			flags.raw(),
 		) {
 			0 => Err(co::HRESULT::E_FAIL),
-			n => Ok((n as _, DestroyIconShfiGuard::new(shfi))),
+			n => Ok((n, DestroyIconShfiGuard::new(shfi))),
 		}
 	}
 }
@@ -360,3 +432,84 @@ pub fn SHGetStockIconInfo(
 		).map(|_| DestroyIconSiiGuard::new(sii))
 	}
 }
+
+/// [`SHOpenFolderAndSelectItems`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shopenfolderandselectitems)
+/// function.
+///
+/// Opens a File Explorer window with the given items selected. All the paths
+/// must be in the same folder. The paths are parsed into PIDLs with
+/// [`SHParseDisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shparsedisplayname),
+/// which are freed before the function returns.
+///
+/// COM must have been initialized with
+/// [`CoInitializeEx`](crate::CoInitializeEx).
+///
+/// # Examples
+///
+/// Implementing a "Show in folder" action:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _com_guard = w::CoInitializeEx(
+///     co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+///
+/// w::SHOpenFolderAndSelectItems(&["C:\\Temp\\report.pdf"])?;
+/// # w::HrResult::Ok(())
+/// ```
+pub fn SHOpenFolderAndSelectItems(paths: &[impl AsRef<str>]) -> HrResult<()> {
+	let pidls = paths.iter()
+		.map(|path| {
+			let mut pidl = std::ptr::null_mut();
+			unsafe {
+				ok_to_hrresult(
+					ffi::SHParseDisplayName(
+						WString::from_str(path.as_ref()).as_ptr(),
+						std::ptr::null_mut(),
+						&mut pidl,
+						0,
+						std::ptr::null_mut(),
+					),
+				).map(|_| CoTaskMemFreeGuard::new(pidl, 0))
+			}
+		})
+		.collect::<HrResult<Vec<_>>>()?;
+
+	match pidls.len() {
+		0 => Err(co::HRESULT::E_INVALIDARG),
+		1 => ok_to_hrresult( // a single absolute PIDL is selected within its folder
+			unsafe {
+				ffi::SHOpenFolderAndSelectItems(
+					pidls[0].as_ptr(),
+					0,
+					std::ptr::null(),
+					0,
+				)
+			},
+		),
+		_ => {
+			let mut folder = unsafe {
+				CoTaskMemFreeGuard::new(ffi::ILClone(pidls[0].as_ptr()), 0)
+			};
+			if folder.as_ptr().is_null() {
+				return Err(co::HRESULT::E_OUTOFMEMORY);
+			}
+			unsafe { ffi::ILRemoveLastID(folder.as_mut_ptr()); } // parent folder
+
+			let children = pidls.iter()
+				.map(|pidl| unsafe { ffi::ILFindLastID(pidl.as_ptr()) } as *const _)
+				.collect::<Vec<_>>();
+
+			ok_to_hrresult(
+				unsafe {
+					ffi::SHOpenFolderAndSelectItems(
+						folder.as_ptr(),
+						children.len() as _,
+						children.as_ptr(),
+						0,
+					)
+				},
+			)
+		},
+	}
+}
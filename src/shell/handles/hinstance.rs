@@ -0,0 +1,69 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+use crate::shell::ffi;
+
+impl shell_Hinstance for HINSTANCE {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`HINSTANCE`](crate::HINSTANCE).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_Hinstance: user_Hinstance {
+	/// [`ExtractIconEx`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-extracticonexw)
+	/// function.
+	///
+	/// Extracts the large and the small icons at the given zero-based `index`
+	/// of an executable, DLL or icon file. A negative `index` is the resource
+	/// ID of the icon. If the file has no icon at the given index (including
+	/// files with no icons at all), `None` is returned for the missing icons,
+	/// instead of an error.
+	///
+	/// To know how many icons a file has, use
+	/// [`extract_icon_count`](crate::extract_icon_count).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let (large, small) = w::HINSTANCE::ExtractIconEx("C:\\Windows\\notepad.exe", 0)?;
+	///
+	/// if let Some(large) = large {
+	///     println!("Large HICON: {}", *large);
+	/// }
+	/// if small.is_none() {
+	///     println!("No small icon.");
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn ExtractIconEx(
+		file: &str,
+		index: i32,
+	) -> SysResult<(Option<DestroyIconGuard>, Option<DestroyIconGuard>)>
+	{
+		let (mut hlarge, mut hsmall) = (HICON::NULL, HICON::NULL);
+		unsafe {
+			if ffi::ExtractIconExW(
+				WString::from_str(file).as_ptr(),
+				index,
+				&mut hlarge as *mut _ as _,
+				&mut hsmall as *mut _ as _,
+				1,
+			) == u32::MAX {
+				return Err(GetLastError());
+			}
+
+			Ok((
+				hlarge.as_opt().map(|h| DestroyIconGuard::new(h.raw_copy())),
+				hsmall.as_opt().map(|h| DestroyIconGuard::new(h.raw_copy())),
+			))
+		}
+	}
+}
@@ -51,8 +51,34 @@ pub trait shell_Hwnd: ole_Hwnd {
 
 	/// [`ShellExecute`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shellexecutew)
 	/// function.
+	///
+	/// If `operation` is `None`, the default verb is used – usually `"open"`.
+	/// The `HWND` can be [`HWND::NULL`](crate::HWND::NULL).
+	///
+	/// The legacy return values less than or equal to 32 are converted to the
+	/// corresponding [`co::ERROR`](crate::co::ERROR) values.
+	///
+	/// To retrieve a handle to the new process, use
+	/// [`ShellExecuteEx`](crate::ShellExecuteEx).
+	///
+	/// # Examples
+	///
+	/// Opening a URL in the default browser:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// w::HWND::NULL.ShellExecute(
+	///     None,
+	///     "https://www.rust-lang.org",
+	///     None,
+	///     None,
+	///     co::SW::SHOWNORMAL,
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn ShellExecute(&self,
-		operation: &str,
+		operation: Option<&str>,
 		file: &str,
 		parameters: Option<&str>,
 		directory: Option<&str>,
@@ -62,18 +88,22 @@ pub trait shell_Hwnd: ole_Hwnd {
 		let ret = unsafe {
 			ffi::ShellExecuteW(
 				self.ptr(),
-				WString::from_str(operation).as_ptr(),
+				WString::from_opt_str(operation).as_ptr(),
 				WString::from_str(file).as_ptr(),
-				parameters.map_or(std::ptr::null(), |lp| WString::from_str(lp).as_ptr()),
-				directory.map_or(std::ptr::null(), |lp| WString::from_str(lp).as_ptr()),
+				WString::from_opt_str(parameters).as_ptr(),
+				WString::from_opt_str(directory).as_ptr(),
 				show_cmd.raw(),
 			)
-		};
+		} as usize;
 
-		if ret as usize > 32 {
-			Ok(())
-		} else {
-			Err(GetLastError())
+		match ret {
+			0 => Err(co::ERROR::OUTOFMEMORY), // out of memory or resources
+			26 => Err(co::ERROR::SHARING_VIOLATION), // SE_ERR_SHARE
+			27 | 31 => Err(co::ERROR::NO_ASSOCIATION), // SE_ERR_ASSOCINCOMPLETE, SE_ERR_NOASSOC
+			28..=30 => Err(co::ERROR::DDE_FAIL), // SE_ERR_DDETIMEOUT, SE_ERR_DDEFAIL, SE_ERR_DDEBUSY
+			32 => Err(co::ERROR::DLL_NOT_FOUND), // SE_ERR_DLLNOTFOUND
+			1..=25 => Err(unsafe { co::ERROR::from_raw(ret as _) }), // same values of ERROR
+			_ => Ok(()),
 		}
 	}
 }
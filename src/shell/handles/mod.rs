@@ -1,4 +1,5 @@
 mod hdrop;
+mod hinstance;
 mod hwnd;
 
 pub mod decl {
@@ -7,5 +8,6 @@ pub mod decl {
 
 pub mod traits {
 	pub use super::hdrop::shell_Hdrop;
+	pub use super::hinstance::shell_Hinstance;
 	pub use super::hwnd::shell_Hwnd;
 }
@@ -5,6 +5,7 @@ mod enums;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub(in crate::shell) mod ffi;
 pub(in crate::shell) mod iterators;
@@ -20,6 +21,7 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
@@ -59,7 +59,7 @@ impl NOTIFYICONDATA {
 pub struct SHFILEINFO {
 	pub hIcon: HICON,
 	pub iIcon: i32,
-	dwAttributes: u32,
+	pub dwAttributes: co::SFGAO,
 	szDisplayName: [u16; MAX_PATH],
 	szTypeName: [u16; 80],
 }
@@ -0,0 +1,146 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Builds and runs a file operation with
+/// [`SHFileOperation`](crate::SHFileOperation), taking care of the
+/// double-null terminated path lists required by
+/// [`SHFILEOPSTRUCT`](crate::SHFILEOPSTRUCT).
+///
+/// The operation shows the standard system progress and confirmation UI,
+/// unless flags like [`co::FOF::NO_UI`](crate::co::FOF::NO_UI) are given.
+///
+/// Paths should be absolute; relative paths are resolved against the current
+/// working directory, which is not thread-safe.
+///
+/// For richer control, see [`IFileOperation`](crate::IFileOperation).
+///
+/// # Examples
+///
+/// Sending files to the Recycle Bin, with the native confirmation dialog:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let aborted = w::FileOp::delete(&["C:\\Temp\\a.txt", "C:\\Temp\\b.txt"])
+///     .hwnd(&hwnd)
+///     .flags(co::FOF::ALLOWUNDO)
+///     .run()?;
+///
+/// if aborted {
+///     println!("User cancelled.");
+/// }
+/// # w::HrResult::Ok(())
+/// ```
+///
+/// Copying a directory tree:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::FileOp::copy(&["C:\\Projects\\src"], "D:\\Backup")
+///     .flags(co::FOF::NOCONFIRMMKDIR)
+///     .progress_title("Backing up")
+///     .run()?;
+/// # w::HrResult::Ok(())
+/// ```
+pub struct FileOp<'a> {
+	func: co::FO,
+	from: Vec<String>,
+	to: Option<String>,
+	flags: co::FOF,
+	hwnd: Option<&'a HWND>,
+	progress_title: Option<String>,
+}
+
+impl<'a> FileOp<'a> {
+	fn new(func: co::FO, from: &[impl AsRef<str>], to: Option<&str>) -> Self {
+		Self {
+			func,
+			from: from.iter().map(|s| s.as_ref().to_owned()).collect(),
+			to: to.map(|s| s.to_owned()),
+			flags: co::FOF::default(),
+			hwnd: None,
+			progress_title: None,
+		}
+	}
+
+	/// Creates an operation which copies the given files or directories into
+	/// the destination directory.
+	#[must_use]
+	pub fn copy(src: &[impl AsRef<str>], dest: &str) -> Self {
+		Self::new(co::FO::COPY, src, Some(dest))
+	}
+
+	/// Creates an operation which deletes the given files or directories.
+	///
+	/// Directories are deleted recursively. To send the items to the Recycle
+	/// Bin, add [`co::FOF::ALLOWUNDO`](crate::co::FOF::ALLOWUNDO) with
+	/// [`flags`](crate::FileOp::flags).
+	#[must_use]
+	pub fn delete(paths: &[impl AsRef<str>]) -> Self {
+		Self::new(co::FO::DELETE, paths, None)
+	}
+
+	/// Creates an operation which moves the given files or directories into
+	/// the destination directory.
+	#[must_use]
+	pub fn move_to(src: &[impl AsRef<str>], dest: &str) -> Self {
+		Self::new(co::FO::MOVE, src, Some(dest))
+	}
+
+	/// Sets the operation flags.
+	///
+	/// Defaults to no flags.
+	#[must_use]
+	pub fn flags(mut self, flags: co::FOF) -> Self {
+		self.flags = flags;
+		self
+	}
+
+	/// Sets the window which will own the progress and confirmation dialogs.
+	#[must_use]
+	pub fn hwnd(mut self, hwnd: &'a HWND) -> Self {
+		self.hwnd = Some(hwnd);
+		self
+	}
+
+	/// Sets the title of the progress dialog, which is used only if
+	/// [`co::FOF::SIMPLEPROGRESS`](crate::co::FOF::SIMPLEPROGRESS) is given.
+	#[must_use]
+	pub fn progress_title(mut self, title: &str) -> Self {
+		self.progress_title = Some(title.to_owned());
+		self
+	}
+
+	/// Runs the operation by calling
+	/// [`SHFileOperation`](crate::SHFileOperation).
+	///
+	/// Returns `true` if the user cancelled any of the operations before they
+	/// were completed. In this case, the error code returned by
+	/// `SHFileOperation` is discarded.
+	pub fn run(&self) -> HrResult<bool> {
+		let mut w_from = WString::from_str_vec(&self.from);
+		let mut w_to = self.to.as_ref()
+			.map(|s| WString::from_str_vec(&[s]));
+		let mut w_title = self.progress_title.as_ref()
+			.map(WString::from_str);
+
+		let mut fo = SHFILEOPSTRUCT::default();
+		fo.hwnd = unsafe { self.hwnd.unwrap_or(&HWND::NULL).raw_copy() };
+		fo.wFunc = self.func;
+		fo.fFlags = self.flags;
+		fo.set_pFrom(Some(&mut w_from));
+		fo.set_pTo(w_to.as_mut());
+		fo.set_lpszProgressTitle(w_title.as_mut());
+
+		match SHFileOperation(&mut fo) {
+			Ok(_) => Ok(fo.fAnyOperationsAborted()),
+			Err(_) if fo.fAnyOperationsAborted() => Ok(true), // cancelling may also yield an error code
+			Err(e) => Err(e),
+		}
+	}
+}
@@ -0,0 +1,5 @@
+mod file_op;
+mod taskbar_progress;
+
+pub use file_op::FileOp;
+pub use taskbar_progress::TaskbarProgress;
@@ -0,0 +1,95 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Displays progress and overlay icons on the taskbar button of a window,
+/// through an [`ITaskbarList3`](crate::ITaskbarList3) object, which is
+/// created once and kept alive.
+///
+/// COM must be initialized in the current thread, with
+/// [`CoInitializeEx`](crate::CoInitializeEx), and the taskbar button must
+/// already exist – that is, the window must be created and visible.
+///
+/// # Examples
+///
+/// Showing download progress, and a red state on error:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let progress = w::TaskbarProgress::new(&hwnd)?;
+///
+/// progress.set_value(30, 100)?;
+///
+/// // download failed...
+/// progress.set_state(co::TBPF::ERROR)?;
+///
+/// // dismiss the progress
+/// progress.set_state(co::TBPF::NOPROGRESS)?;
+/// # w::HrResult::Ok(())
+/// ```
+pub struct TaskbarProgress {
+	hwnd: HWND,
+	taskbar: ITaskbarList3,
+}
+
+impl TaskbarProgress {
+	/// Creates the [`ITaskbarList3`](crate::ITaskbarList3) object with
+	/// [`CoCreateInstance`](crate::CoCreateInstance), and calls
+	/// [`ITaskbarList::HrInit`](crate::prelude::shell_ITaskbarList::HrInit)
+	/// on it.
+	pub fn new(hwnd: &HWND) -> HrResult<Self> {
+		let taskbar = CoCreateInstance::<ITaskbarList3>(
+			&co::CLSID::TaskbarList,
+			None,
+			co::CLSCTX::INPROC_SERVER,
+		)?;
+		taskbar.HrInit()?;
+
+		Ok(Self {
+			hwnd: unsafe { hwnd.raw_copy() },
+			taskbar,
+		})
+	}
+
+	/// Returns the underlying [`ITaskbarList3`](crate::ITaskbarList3) object.
+	#[must_use]
+	pub const fn taskbar_list(&self) -> &ITaskbarList3 {
+		&self.taskbar
+	}
+
+	/// Sets or removes the overlay icon by calling
+	/// [`ITaskbarList3::SetOverlayIcon`](crate::prelude::shell_ITaskbarList3::SetOverlayIcon).
+	///
+	/// The description is used by accessibility tools.
+	pub fn set_overlay_icon(&self,
+		hicon: Option<&HICON>,
+		description: &str,
+	) -> HrResult<()>
+	{
+		self.taskbar.SetOverlayIcon(&self.hwnd, hicon, description)
+	}
+
+	/// Sets the progress state by calling
+	/// [`ITaskbarList3::SetProgressState`](crate::prelude::shell_ITaskbarList3::SetProgressState).
+	///
+	/// Use [`co::TBPF::NOPROGRESS`](crate::co::TBPF::NOPROGRESS) to dismiss
+	/// the progress when the operation is completed.
+	pub fn set_state(&self, state: co::TBPF) -> HrResult<()> {
+		self.taskbar.SetProgressState(&self.hwnd, state)
+	}
+
+	/// Sets the progress value by calling
+	/// [`ITaskbarList3::SetProgressValue`](crate::prelude::shell_ITaskbarList3::SetProgressValue).
+	///
+	/// If the current state is
+	/// [`co::TBPF::NOPROGRESS`](crate::co::TBPF::NOPROGRESS) or
+	/// [`co::TBPF::INDETERMINATE`](crate::co::TBPF::INDETERMINATE), it's
+	/// automatically changed to [`co::TBPF::NORMAL`](crate::co::TBPF::NORMAL).
+	pub fn set_value(&self, completed: u64, total: u64) -> HrResult<()> {
+		self.taskbar.SetProgressValue(&self.hwnd, completed, total)
+	}
+}
@@ -452,6 +452,16 @@ const_bitflag! { CURSOR: u32;
 	SUPPRESSED 0x0000_0002
 }
 
+const_bitflag! { CWP: u32;
+	/// [`HWND::ChildWindowFromPointEx`](crate::prelude::user_Hwnd::ChildWindowFromPointEx)
+	/// `flags` (`u32`).
+	=>
+	ALL 0x0000
+	SKIPINVISIBLE 0x0001
+	SKIPDISABLED 0x0002
+	SKIPTRANSPARENT 0x0004
+}
+
 const_bitflag! { DC: u32;
 	/// [`HWND::DrawCaption`](crate::prelude::user_Hwnd::DrawCaption) `flags`
 	/// (`u32`).
@@ -527,6 +537,18 @@ const_ordinary! { DF: u32;
 	ALLOWOTHERACCOUNTHOOK 0x0001
 }
 
+const_bitflag! { DI: u32;
+	/// [`HDC::DrawIconEx`](crate::prelude::user_Hdc::DrawIconEx) `flags`
+	/// (`u32`).
+	=>
+	MASK 0x0001
+	IMAGE 0x0002
+	NORMAL 0x0003
+	COMPAT 0x0004
+	DEFAULTSIZE 0x0008
+	NOMIRROR 0x0010
+}
+
 const_ordinary! { DISP_CHANGE: i32;
 	/// [`ChangeDisplaySettings`](crate::ChangeDisplaySettings) return value
 	/// (`u32`).
@@ -1195,7 +1217,8 @@ const_cmd! { EN;
 }
 
 const_bitflag! { ENDSESSION: u32;
-	/// [`wm::EndSession`](crate::msg::wm::EndSession) event (`u32`).
+	/// [`wm::EndSession`](crate::msg::wm::EndSession) and
+	/// [`wm::QueryEndSession`](crate::msg::wm::QueryEndSession) event (`u32`).
 	=>
 	RESTARTORSHUTDOWN 0
 	CLOSEAPP 0x0000_0001
@@ -1284,6 +1307,59 @@ const_ordinary! { ESB: u32;
 	DISABLE_RTDN Self::DISABLE_RIGHT.0
 }
 
+const_ordinary! { EVENT: u32;
+	/// [`HWINEVENTHOOK::SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook)
+	/// [event constants](https://learn.microsoft.com/en-us/windows/win32/winauto/event-constants)
+	/// (`u32`).
+	=>
+	MIN 0x0000_0001
+	MAX 0x7fff_ffff
+
+	SYSTEM_SOUND 0x0001
+	SYSTEM_ALERT 0x0002
+	SYSTEM_FOREGROUND 0x0003
+	SYSTEM_MENUSTART 0x0004
+	SYSTEM_MENUEND 0x0005
+	SYSTEM_MENUPOPUPSTART 0x0006
+	SYSTEM_MENUPOPUPEND 0x0007
+	SYSTEM_CAPTURESTART 0x0008
+	SYSTEM_CAPTUREEND 0x0009
+	SYSTEM_MOVESIZESTART 0x000a
+	SYSTEM_MOVESIZEEND 0x000b
+	SYSTEM_CONTEXTHELPSTART 0x000c
+	SYSTEM_CONTEXTHELPEND 0x000d
+	SYSTEM_DRAGDROPSTART 0x000e
+	SYSTEM_DRAGDROPEND 0x000f
+	SYSTEM_DIALOGSTART 0x0010
+	SYSTEM_DIALOGEND 0x0011
+	SYSTEM_SCROLLINGSTART 0x0012
+	SYSTEM_SCROLLINGEND 0x0013
+	SYSTEM_SWITCHSTART 0x0014
+	SYSTEM_SWITCHEND 0x0015
+	SYSTEM_MINIMIZESTART 0x0016
+	SYSTEM_MINIMIZEEND 0x0017
+	SYSTEM_DESKTOPSWITCH 0x0020
+
+	OBJECT_CREATE 0x8000
+	OBJECT_DESTROY 0x8001
+	OBJECT_SHOW 0x8002
+	OBJECT_HIDE 0x8003
+	OBJECT_REORDER 0x8004
+	OBJECT_FOCUS 0x8005
+	OBJECT_SELECTION 0x8006
+	OBJECT_SELECTIONADD 0x8007
+	OBJECT_SELECTIONREMOVE 0x8008
+	OBJECT_SELECTIONWITHIN 0x8009
+	OBJECT_STATECHANGE 0x800a
+	OBJECT_LOCATIONCHANGE 0x800b
+	OBJECT_NAMECHANGE 0x800c
+	OBJECT_DESCRIPTIONCHANGE 0x800d
+	OBJECT_VALUECHANGE 0x800e
+	OBJECT_PARENTCHANGE 0x800f
+	OBJECT_CLOAKED 0x8017
+	OBJECT_UNCLOAKED 0x8018
+}
+
 const_bitflag! { EWX: u32;
 	/// [`ExitWindowsEx`](crate::ExitWindowsEx) `flags` (`u32`).
 	=>
@@ -1461,8 +1537,9 @@ const_ordinary! { HELPW: u32;
 }
 
 const_bitflag! { HOTKEYF: u16;
-	/// [`wm::GetHotkey`](crate::msg::wm::GetHotKey) and
-	/// [`wm::SetHotKey`](crate::msg::wm::SetHotKey) modifiers.
+	/// [`wm::GetHotkey`](crate::msg::wm::GetHotKey),
+	/// [`wm::SetHotKey`](crate::msg::wm::SetHotKey) and hot key control
+	/// modifiers (`u16`).
 	=>
 	/// None of the actual values (zero).
 	NoValue 0
@@ -1720,6 +1797,16 @@ const_bitflag! { LWA: u32;
 	COLORKEY 0x0000_0001
 }
 
+const_ordinary! { MAPVK: u32;
+	/// [`MapVirtualKey`](crate::MapVirtualKey) `map_type` (`u32`).
+	=>
+	VK_TO_VSC 0
+	VSC_TO_VK 1
+	VK_TO_CHAR 2
+	VSC_TO_VK_EX 3
+	VK_TO_VSC_EX 4
+}
+
 const_bitflag! { MB: u32;
 	/// [`HWND::MessageBox`](crate::prelude::user_Hwnd::MessageBox) `flags`
 	/// (`u32`).
@@ -2005,8 +2092,11 @@ const_bitflag! { MOD: u16;
 	=>
 	ALT 0x0001
 	CONTROL 0x0002
+	/// Keyboard auto-repeat does not yield multiple hotkey notifications.
 	NOREPEAT 0x4000
 	SHIFT 0x0004
+	/// Either Windows key; combinations with it are reserved for the operating
+	/// system.
 	WIN 0x0008
 }
 
@@ -2049,6 +2139,18 @@ const_bitflag! { MOUSEEVENTF: u32;
 	ABSOLUTE 0x8000
 }
 
+const_bitflag! { MOUSE_STATE: u16;
+	/// [`RAWMOUSE`](crate::RAWMOUSE) `usFlags` (`u16`).
+	///
+	/// Originally has `MOUSE` prefix.
+	=>
+	MOVE_RELATIVE 0x00
+	MOVE_ABSOLUTE 0x01
+	VIRTUAL_DESKTOP 0x02
+	ATTRIBUTES_CHANGED 0x04
+	MOVE_NOCOALESCE 0x08
+}
+
 const_ordinary! { MSGF: u8;
 	/// [`wm::EnterIdle`](crate::msg::wm::EnterIdle) reason (`u8`).
 	=>
@@ -2058,11 +2160,22 @@ const_ordinary! { MSGF: u8;
 
 const_ordinary! { OBJID: u32;
 	/// [`HWND::GetMenuBarInfo`](crate::prelude::user_Hwnd::GetMenuBarInfo)
-	/// `idObject` (`i32`).
+	/// and [`HWND::GetScrollBarInfo`](crate::prelude::user_Hwnd::GetScrollBarInfo)
+	/// `idObject`, also
+	/// [`WINEVENTPROC`](crate::WINEVENTPROC) `idObject` (`i32`).
 	=>
+	ALERT 0xffff_fff6
+	CARET 0xffff_fff8
 	CLIENT 0xffff_fffc
+	CURSOR 0xffff_fff7
+	HSCROLL 0xffff_fffa
 	MENU 0xffff_fffd
+	SIZEGRIP 0xffff_fff9
+	SOUND 0xffff_fff5
 	SYSMENU 0xffff_ffff
+	TITLEBAR 0xffff_fffe
+	VSCROLL 0xffff_fffb
+	WINDOW 0x0000_0000
 }
 
 const_ordinary! { OBM: u32;
@@ -2253,6 +2366,64 @@ const_ordinary! { REGION: i32;
 	COMPLEX 3
 }
 
+const_bitflag! { RIDEV: u32;
+	/// [`RAWINPUTDEVICE`](crate::RAWINPUTDEVICE) `dwFlags` (`u32`).
+	=>
+	REMOVE 0x0000_0001
+	EXCLUDE 0x0000_0010
+	PAGEONLY 0x0000_0020
+	NOLEGACY 0x0000_0030
+	INPUTSINK 0x0000_0100
+	CAPTUREMOUSE 0x0000_0200
+	NOHOTKEYS 0x0000_0200
+	APPKEYS 0x0000_0400
+	EXINPUTSINK 0x0000_1000
+	DEVNOTIFY 0x0000_2000
+}
+
+const_ordinary! { RIM: u8;
+	/// [`wm::Input`](crate::msg::wm::Input) input code (`u8`).
+	=>
+	/// Input occurred while the application was in the foreground.
+	INPUT 0
+	/// Input occurred while the application was not in the foreground.
+	INPUTSINK 1
+}
+
+const_ordinary! { RIM_TYPE: u32;
+	/// [`RAWINPUTHEADER`](crate::RAWINPUTHEADER) `dwType` (`u32`).
+	=>
+	MOUSE 0
+	KEYBOARD 1
+	HID 2
+}
+
+const_bitflag! { RI_KEY: u16;
+	/// [`RAWKEYBOARD`](crate::RAWKEYBOARD) `Flags` (`u16`).
+	=>
+	MAKE 0
+	BREAK 1
+	E0 2
+	E1 4
+}
+
+const_bitflag! { RI_MOUSE: u16;
+	/// [`RAWMOUSE`](crate::RAWMOUSE) `usButtonFlags` (`u16`).
+	=>
+	LEFT_BUTTON_DOWN 0x0001
+	LEFT_BUTTON_UP 0x0002
+	RIGHT_BUTTON_DOWN 0x0004
+	RIGHT_BUTTON_UP 0x0008
+	MIDDLE_BUTTON_DOWN 0x0010
+	MIDDLE_BUTTON_UP 0x0020
+	BUTTON_4_DOWN 0x0040
+	BUTTON_4_UP 0x0080
+	BUTTON_5_DOWN 0x0100
+	BUTTON_5_UP 0x0200
+	WHEEL 0x0400
+	HWHEEL 0x0800
+}
+
 const_ordinary! { SB_EM: u16;
 	/// [`em::Scroll`](crate::msg::em::Scroll) action.
 	///
@@ -2290,8 +2461,9 @@ const_ordinary! { SB_REQ: u16;
 const_ordinary! { SBB: i32;
 	/// [`HWND::EnableScrollBar`](crate::prelude::user_Hwnd::EnableScrollBar),
 	/// [`HWND::GetScrollInfo`](crate::prelude::user_Hwnd::GetScrollInfo),
-	/// [`HWND::SetScrollInfo`](crate::prelude::user_Hwnd::SetScrollInfo) and
-	/// [`HWND::SetScrollRange`](crate::prelude::user_Hwnd::SetScrollRange)
+	/// [`HWND::SetScrollInfo`](crate::prelude::user_Hwnd::SetScrollInfo),
+	/// [`HWND::SetScrollRange`](crate::prelude::user_Hwnd::SetScrollRange) and
+	/// [`HWND::ShowScrollBar`](crate::prelude::user_Hwnd::ShowScrollBar)
 	/// `bar` (`i32`).
 	///
 	/// Originally has `SB` prefix.
@@ -2643,6 +2815,56 @@ const_ordinary! { SPI: u32;
 	SETSNAPSIZING 0x008f
 	GETDOCKMOVING 0x0090
 	SETDOCKMOVING 0x0091
+	GETACTIVEWINDOWTRACKING 0x1000
+	SETACTIVEWINDOWTRACKING 0x1001
+	GETMENUANIMATION 0x1002
+	SETMENUANIMATION 0x1003
+	GETCOMBOBOXANIMATION 0x1004
+	SETCOMBOBOXANIMATION 0x1005
+	GETLISTBOXSMOOTHSCROLLING 0x1006
+	SETLISTBOXSMOOTHSCROLLING 0x1007
+	GETGRADIENTCAPTIONS 0x1008
+	SETGRADIENTCAPTIONS 0x1009
+	GETKEYBOARDCUES 0x100a
+	SETKEYBOARDCUES 0x100b
+	GETMENUUNDERLINES Self::GETKEYBOARDCUES.0
+	SETMENUUNDERLINES Self::SETKEYBOARDCUES.0
+	GETACTIVEWNDTRKZORDER 0x100c
+	SETACTIVEWNDTRKZORDER 0x100d
+	GETHOTTRACKING 0x100e
+	SETHOTTRACKING 0x100f
+	GETMENUFADE 0x1012
+	SETMENUFADE 0x1013
+	GETSELECTIONFADE 0x1014
+	SETSELECTIONFADE 0x1015
+	GETTOOLTIPANIMATION 0x1016
+	SETTOOLTIPANIMATION 0x1017
+	GETTOOLTIPFADE 0x1018
+	SETTOOLTIPFADE 0x1019
+	GETCURSORSHADOW 0x101a
+	SETCURSORSHADOW 0x101b
+	GETMOUSESONAR 0x101c
+	SETMOUSESONAR 0x101d
+	GETMOUSECLICKLOCK 0x101e
+	SETMOUSECLICKLOCK 0x101f
+	GETMOUSEVANISH 0x1020
+	SETMOUSEVANISH 0x1021
+	GETFLATMENU 0x1022
+	SETFLATMENU 0x1023
+	GETDROPSHADOW 0x1024
+	SETDROPSHADOW 0x1025
+	GETBLOCKSENDINPUTRESETS 0x1026
+	SETBLOCKSENDINPUTRESETS 0x1027
+	GETUIEFFECTS 0x103e
+	SETUIEFFECTS 0x103f
+	GETDISABLEOVERLAPPEDCONTENT 0x1040
+	SETDISABLEOVERLAPPEDCONTENT 0x1041
+	GETCLIENTAREAANIMATION 0x1042
+	SETCLIENTAREAANIMATION 0x1043
+	GETCLEARTYPE 0x1048
+	SETCLEARTYPE 0x1049
+	GETSPEECHRECOGNITION 0x104a
+	SETSPEECHRECOGNITION 0x104b
 }
 
 const_bitflag! { SPIF: u32;
@@ -3162,11 +3384,28 @@ const_ordinary! { WDA: u32;
 	/// [`HWND::SetWindowDisplayAffinity`](crate::prelude::user_Hwnd::SetWindowDisplayAffinity)
 	/// `dwAffinity` (`u32`).
 	=>
+	/// Imposes no restrictions on where the window can be displayed.
 	NONE 0x0000_0000
+	/// The window content is displayed only on a monitor; everywhere else,
+	/// like screenshots and screen recordings, it appears black. Requires
+	/// Windows 7.
 	MONITOR 0x0000_0001
+	/// The window is displayed only on a monitor; everywhere else it does not
+	/// appear at all. Requires Windows 10 version 2004; earlier versions
+	/// behave as [`WDA::MONITOR`](crate::co::WDA::MONITOR).
 	EXCLUDEFROMCAPTURE 0x0000_0011
 }
 
+const_bitflag! { WINEVENT: u32;
+	/// [`HWINEVENTHOOK::SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook)
+	/// `flags` (`u32`).
+	=>
+	OUTOFCONTEXT 0x0000
+	SKIPOWNTHREAD 0x0001
+	SKIPOWNPROCESS 0x0002
+	INCONTEXT 0x0004
+}
+
 const_ordinary! { WH: i32;
 	/// [`HHOOK::CallNextHookEx`](crate::prelude::user_Hhook::CallNextHookEx)
 	/// `code` and
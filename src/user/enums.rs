@@ -171,7 +171,21 @@ impl From<GmidxEnum> for u32 {
 
 /// Variant parameter for:
 ///
-/// * [`INPUT`](crate::INPUT).
+/// * [`HRAWINPUT::GetRawInputData`](crate::prelude::user_Hrawinput::GetRawInputData).
+#[derive(Clone)]
+pub enum HidKbMouse {
+	/// HID input, with each one of the reports.
+	Hid(Vec<Vec<u8>>),
+	/// Keyboard input.
+	Kb(RAWKEYBOARD),
+	/// Mouse input.
+	Mouse(RAWMOUSE),
+}
+
+/// Variant parameter for:
+///
+/// * [`INPUT`](crate::INPUT);
+/// * [`SendInput`](crate::SendInput).
 #[derive(Clone, Copy)]
 pub enum HwKbMouse {
 	/// Hardware event.
@@ -182,6 +196,92 @@ pub enum HwKbMouse {
 	Mouse(MOUSEINPUT),
 }
 
+impl HwKbMouse {
+	/// Returns the key down and key up events for the given virtual key.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// w::SendInput(&w::HwKbMouse::key_press(co::VK::RETURN))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn key_press(vkey: co::VK) -> [Self; 2] {
+		[
+			Self::Kb(KEYBDINPUT { wVk: vkey, ..Default::default() }),
+			Self::Kb(KEYBDINPUT {
+				wVk: vkey,
+				dwFlags: co::KEYEVENTF::KEYUP,
+				..Default::default()
+			}),
+		]
+	}
+
+	/// Returns the key down and key up events which type the given character,
+	/// using [`co::KEYEVENTF::UNICODE`](crate::co::KEYEVENTF::UNICODE), so the
+	/// current keyboard layout doesn't matter.
+	///
+	/// Characters outside the Basic Multilingual Plane are sent as a surrogate
+	/// pair, thus returning 4 events instead of 2.
+	///
+	/// # Examples
+	///
+	/// Typing a whole string:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let events = "Hello, world!".chars()
+	///     .flat_map(|ch| w::HwKbMouse::unicode_char(ch))
+	///     .collect::<Vec<_>>();
+	///
+	/// w::SendInput(&events)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn unicode_char(ch: char) -> Vec<Self> {
+		let mut buf = [0u16; 2];
+		let units = ch.encode_utf16(&mut buf);
+
+		let mut events = Vec::with_capacity(units.len() * 2);
+		for flags in [co::KEYEVENTF::NoValue, co::KEYEVENTF::KEYUP] {
+			for unit in units.iter() {
+				events.push(Self::Kb(KEYBDINPUT {
+					wScan: *unit,
+					dwFlags: co::KEYEVENTF::UNICODE | flags,
+					..Default::default()
+				}));
+			}
+		}
+		events
+	}
+
+	/// Returns a mouse event which moves the cursor to the given screen
+	/// coordinates, in pixels.
+	///
+	/// The coordinates are normalized to the `0..=65535` range expected by
+	/// [`co::MOUSEEVENTF::ABSOLUTE`](crate::co::MOUSEEVENTF::ABSOLUTE), relative
+	/// to the whole virtual screen, so secondary monitors are supported.
+	#[must_use]
+	pub fn mouse_move_abs(x: i32, y: i32) -> Self {
+		let normalize = |pos: i32, sm_orig: co::SM, sm_len: co::SM| -> i32 {
+			let orig = GetSystemMetrics(sm_orig) as i64;
+			let len = std::cmp::max(GetSystemMetrics(sm_len) as i64 - 1, 1);
+			((pos as i64 - orig) * 65535 / len) as _
+		};
+
+		Self::Mouse(MOUSEINPUT {
+			dx: normalize(x, co::SM::XVIRTUALSCREEN, co::SM::CXVIRTUALSCREEN),
+			dy: normalize(y, co::SM::YVIRTUALSCREEN, co::SM::CYVIRTUALSCREEN),
+			dwFlags: co::MOUSEEVENTF::MOVE | co::MOUSEEVENTF::ABSOLUTE
+				| co::MOUSEEVENTF::VIRTUALDESK,
+			..Default::default()
+		})
+	}
+}
+
 /// Variant parameter for:
 ///
 /// * [`wm::NextDlgCtl`](crate::msg::wm::NextDlgCtl).
@@ -417,7 +517,8 @@ impl IdPos {
 
 /// Variant parameter for:
 ///
-/// * [`HMENU::append_item`](crate::prelude::user_Hmenu::append_item).
+/// * [`HMENU::append_item`](crate::prelude::user_Hmenu::append_item);
+/// * [`HMENU::insert_item`](crate::prelude::user_Hmenu::insert_item).
 pub enum MenuItem<'a> {
 	/// A selectable entry item, with command ID and text.
 	Entry(u16, &'a str),
@@ -19,6 +19,7 @@ extern_sys! { "user32";
 }
 
 extern_sys! { "user32";
+	AddClipboardFormatListener(HANDLE) -> BOOL
 	AdjustWindowRectEx(PVOID, u32, BOOL, u32) -> BOOL
 	AdjustWindowRectExForDpi(PVOID, u32, BOOL, u32, u32) -> BOOL
 	AllowSetForegroundWindow(u32) -> BOOL
@@ -37,6 +38,7 @@ extern_sys! { "user32";
 	CheckMenuItem(HANDLE, u32, u32) -> i32
 	CheckMenuRadioItem(HANDLE, u32, u32, u32, u32) -> BOOL
 	ChildWindowFromPoint(HANDLE, i32, i32) -> HANDLE
+	ChildWindowFromPointEx(HANDLE, i32, i32, u32) -> HANDLE
 	ClientToScreen(HANDLE, PVOID) -> BOOL
 	ClipCursor(PCVOID) -> BOOL
 	CloseClipboard() -> BOOL
@@ -47,6 +49,7 @@ extern_sys! { "user32";
 	CreateDesktopExW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID, u32, PVOID) -> HANDLE
 	CreateDesktopW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID) -> HANDLE
 	CreateDialogParamW(HANDLE, PCSTR, HANDLE, PFUNC, isize) -> HANDLE
+	CreateIconIndirect(PCVOID) -> HANDLE
 	CreateMenu() -> HANDLE
 	CreatePopupMenu() -> HANDLE
 	CreateWindowExW(u32, PCSTR, PCSTR, u32, i32, i32, i32, i32, HANDLE, HANDLE, HANDLE, PVOID) -> HANDLE
@@ -64,6 +67,7 @@ extern_sys! { "user32";
 	DragDetect(HANDLE, i32, i32) -> BOOL
 	DrawCaption(HANDLE, HANDLE, PCVOID, u32) -> BOOL
 	DrawFocusRect(HANDLE, PCVOID) -> BOOL
+	DrawIconEx(HANDLE, i32, i32, HANDLE, i32, i32, u32, HANDLE, u32) -> BOOL
 	DrawMenuBar(HANDLE) -> BOOL
 	DrawTextExW(HANDLE, PCSTR, i32, PVOID, u32, PCVOID) -> i32
 	DrawTextW(HANDLE, PCSTR, i32, PVOID, u32) -> i32
@@ -117,6 +121,7 @@ extern_sys! { "user32";
 	GetGUIThreadInfo(u32, PVOID) -> BOOL
 	GetIconInfo(HANDLE, PVOID) -> BOOL
 	GetIconInfoExW(HANDLE, PVOID) -> BOOL
+	GetKeyNameTextW(i32, PSTR, i32) -> i32
 	GetLastActivePopup(HANDLE) -> HANDLE
 	GetLastInputInfo(PVOID) -> BOOL
 	GetMenu(HANDLE) -> HANDLE
@@ -135,10 +140,13 @@ extern_sys! { "user32";
 	GetMonitorInfoW(HANDLE, PVOID) -> BOOL
 	GetNextDlgGroupItem(HANDLE, HANDLE, BOOL) -> HANDLE
 	GetNextDlgTabItem(HANDLE, HANDLE, BOOL) -> HANDLE
+	GetOpenClipboardWindow() -> HANDLE
 	GetParent(HANDLE) -> HANDLE
 	GetPhysicalCursorPos(PVOID) -> BOOL
 	GetProcessDefaultLayout(*mut u32) -> BOOL
 	GetQueueStatus(u32) -> u32
+	GetRawInputData(HANDLE, u32, PVOID, *mut u32, u32) -> u32
+	GetScrollBarInfo(HANDLE, i32, PVOID) -> BOOL
 	GetScrollInfo(HANDLE, i32, PVOID) -> BOOL
 	GetScrollPos(HANDLE, i32) -> i32
 	GetShellWindow() -> HANDLE
@@ -176,6 +184,7 @@ extern_sys! { "user32";
 	InvalidateRgn(HANDLE, HANDLE, BOOL) -> BOOL
 	InvertRect(HANDLE, PCVOID) -> BOOL
 	IsChild(HANDLE, HANDLE) -> BOOL
+	IsClipboardFormatAvailable(u32) -> BOOL
 	IsDialogMessageW(HANDLE, PVOID) -> BOOL
 	IsGUIThread(BOOL) -> BOOL
 	IsIconic(HANDLE) -> BOOL
@@ -197,6 +206,7 @@ extern_sys! { "user32";
 	LockWindowUpdate(HANDLE) -> BOOL
 	LogicalToPhysicalPoint(HANDLE, PVOID) -> BOOL
 	MapDialogRect(HANDLE, PVOID) -> BOOL
+	MapVirtualKeyW(u32, u32) -> u32
 	MapWindowPoints(HANDLE, HANDLE, PVOID, u32) -> i32
 	MessageBeep(u32) -> BOOL
 	MessageBoxW(HANDLE, PCSTR, PCSTR, u32) -> i32
@@ -219,9 +229,12 @@ extern_sys! { "user32";
 	RedrawWindow(HANDLE, PCVOID, HANDLE, u32) -> BOOL
 	RegisterClassExW(PCVOID) -> u16
 	RegisterHotKey(HANDLE, i32, u32, u32) -> BOOL
+	RegisterPowerSettingNotification(HANDLE, PCVOID, u32) -> HANDLE
+	RegisterRawInputDevices(PCVOID, u32, u32) -> BOOL
 	RegisterWindowMessageW(PCSTR) -> u32
 	ReleaseCapture() -> BOOL
 	ReleaseDC(HANDLE, HANDLE) -> i32
+	RemoveClipboardFormatListener(HANDLE) -> BOOL
 	RemoveMenu(HANDLE, u32, u32) -> BOOL
 	ScreenToClient(HANDLE, PVOID) -> BOOL
 	ScrollWindowEx(HANDLE, i32, i32, PCVOID, PCVOID, HANDLE, PVOID, u32) -> i32
@@ -233,6 +246,7 @@ extern_sys! { "user32";
 	SetCaretBlinkTime(u32) -> BOOL
 	SetCaretPos(i32, i32) -> BOOL
 	SetClipboardData(u32, HANDLE) -> HANDLE
+	SetCursor(HANDLE) -> HANDLE
 	SetCursorPos(i32, i32) -> BOOL
 	SetDialogDpiChangeBehavior(HANDLE, u32, u32) -> BOOL
 	SetDoubleClickTime(u32) -> BOOL
@@ -263,11 +277,16 @@ extern_sys! { "user32";
 	SetWindowRgn(HANDLE, HANDLE, BOOL) -> i32
 	SetWindowsHookExW(i32, PFUNC, HANDLE, u32) -> HANDLE
 	SetWindowTextW(HANDLE, PCSTR) -> BOOL
+	SetWinEventHook(u32, u32, HANDLE, PFUNC, u32, u32, u32) -> HANDLE
 	ShowCaret(HANDLE) -> BOOL
 	ShowCursor(BOOL) -> i32
 	ShowOwnedPopups(HANDLE, BOOL) -> BOOL
+	ShowScrollBar(HANDLE, i32, BOOL) -> BOOL
 	ShowWindow(HANDLE, i32) -> BOOL
 	ShowWindowAsync(HANDLE, i32) -> BOOL
+	ShutdownBlockReasonCreate(HANDLE, PCSTR) -> BOOL
+	ShutdownBlockReasonDestroy(HANDLE) -> BOOL
+	ShutdownBlockReasonQuery(HANDLE, PSTR, *mut u32) -> BOOL
 	SoundSentry() -> BOOL
 	SubtractRect(PVOID, PCVOID, PCVOID) -> BOOL
 	SwapMouseButton(BOOL) -> BOOL
@@ -279,9 +298,11 @@ extern_sys! { "user32";
 	TranslateAcceleratorW(HANDLE, HANDLE, PVOID) -> i32
 	TranslateMessage(PCVOID) -> BOOL
 	UnhookWindowsHookEx(HANDLE) -> BOOL
+	UnhookWinEvent(HANDLE) -> BOOL
 	UnionRect(PVOID, PCVOID, PCVOID) -> BOOL
 	UnregisterClassW(PCSTR, HANDLE) -> BOOL
 	UnregisterHotKey(HANDLE, i32) -> BOOL
+	UnregisterPowerSettingNotification(HANDLE) -> BOOL
 	UpdateLayeredWindow(HANDLE, HANDLE, PCVOID, PCVOID, HANDLE, PCVOID, u32, PCVOID, u32) -> BOOL
 	UpdateWindow(HANDLE) -> BOOL
 	ValidateRect(HANDLE, PCVOID) -> BOOL
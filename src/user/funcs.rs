@@ -478,6 +478,10 @@ pub fn ExitWindowsEx(
 
 /// [`FlashWindowEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-flashwindowex)
 /// function.
+///
+/// Prefer using
+/// [`HWND::FlashWindowEx`](crate::prelude::user_Hwnd::FlashWindowEx), which
+/// fills the [`FLASHWINFO`](crate::FLASHWINFO) struct for you.
 pub fn FlashWindowEx(fwi: &FLASHWINFO) -> u32 {
 	unsafe { ffi::FlashWindowEx(fwi as *const _ as _) as _ }
 }
@@ -538,10 +542,29 @@ pub unsafe fn GetClipboardData(format: co::CF) -> SysResult<*mut u8> {
 /// [`GetClipboardSequenceNumber`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardsequencenumber)
 /// function.
 ///
+/// The number is incremented whenever the clipboard contents change, so it
+/// can be polled to cheaply detect changes, without a clipboard listener.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut last_seq = w::GetClipboardSequenceNumber();
+///
+/// // later...
+/// let seq = w::GetClipboardSequenceNumber();
+/// if seq != last_seq {
+///     println!("Clipboard changed.");
+///     last_seq = seq;
+/// }
+/// ```
+///
 /// # Related functions
 ///
 /// * [`EmptyClipboard`](crate::EmptyClipboard)
 /// * [`GetClipboardData`](crate::GetClipboardData)
+/// * [`HWND::GetOpenClipboardWindow`](crate::prelude::user_Hwnd::GetOpenClipboardWindow)
 /// * [`SetClipboardData`](crate::SetClipboardData)
 #[must_use]
 pub fn GetClipboardSequenceNumber() -> u32 {
@@ -589,8 +612,29 @@ pub fn GetDoubleClickTime() -> u32 {
 /// [`GetGUIThreadInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getguithreadinfo)
 /// function.
 ///
+/// If `thread_id` is `None`, the foreground thread is queried.
+///
+/// Note that `rcCaret` is in client coordinates of `hwndCaret`.
+///
 /// # Examples
 ///
+/// Retrieving the caret rectangle, in screen coordinates, of the application
+/// which currently has the focus:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let gti = w::GetGUIThreadInfo(None)?;
+///
+/// if let Some(hcaret) = gti.hwndCaret.as_opt() {
+///     let rc_screen = hcaret.ClientToScreenRc(gti.rcCaret)?;
+///     println!("Caret at {}", rc_screen);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// Querying the thread of a specific window:
+///
 /// ```no_run
 /// use winsafe::{self as w, prelude::*};
 ///
@@ -598,19 +642,45 @@ pub fn GetDoubleClickTime() -> u32 {
 /// # let hwnd = w::HWND::NULL;
 ///
 /// let (thread_id, _) = hwnd.GetWindowThreadProcessId();
-/// let gti = w::GetGUIThreadInfo(thread_id)?;
+/// let gti = w::GetGUIThreadInfo(Some(thread_id))?;
 ///
-/// println!("Caret rect: {}", gti.rcCaret);
+/// println!("Focused: {}", gti.hwndFocus);
 /// # w::SysResult::Ok(())
 /// ```
 #[must_use]
-pub fn GetGUIThreadInfo(thread_id: u32) -> SysResult<GUITHREADINFO> {
+pub fn GetGUIThreadInfo(thread_id: Option<u32>) -> SysResult<GUITHREADINFO> {
 	let mut gti = GUITHREADINFO::default();
 	bool_to_sysresult(
-		unsafe { ffi::GetGUIThreadInfo(thread_id, &mut gti as *mut _ as _) },
+		unsafe {
+			ffi::GetGUIThreadInfo(
+				thread_id.unwrap_or_default(),
+				&mut gti as *mut _ as _,
+			)
+		},
 	).map(|_| gti)
 }
 
+/// [`GetKeyNameText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeynametextw)
+/// function.
+///
+/// The `lparam` follows the format of the
+/// [`wm::KeyDown`](crate::msg::wm::KeyDown) message: scan code in bits 16-23,
+/// extended key flag in bit 24. The name is localized according to the
+/// current keyboard layout.
+///
+/// If you have a virtual key code, prefer using
+/// [`key_name`](crate::key_name).
+#[must_use]
+pub fn GetKeyNameText(lparam: i32) -> SysResult<String> {
+	let mut buf = WString::new_alloc_buf(64);
+	match unsafe {
+		ffi::GetKeyNameTextW(lparam, buf.as_mut_ptr(), buf.buf_len() as _)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
 /// [`GetLastInputInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlastinputinfo)
 /// function.
 #[must_use]
@@ -790,6 +860,17 @@ pub fn IsGUIThread(convert_to_gui_thread: bool) -> SysResult<bool> {
 	}
 }
 
+/// [`IsClipboardFormatAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable)
+/// function.
+///
+/// # Related functions
+///
+/// * [`GetClipboardData`](crate::GetClipboardData)
+#[must_use]
+pub fn IsClipboardFormatAvailable(format: co::CF) -> bool {
+	unsafe { ffi::IsClipboardFormatAvailable(format.raw() as _) != 0 }
+}
+
 /// [`IsRectEmpty`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isrectempty)
 /// function.
 #[must_use]
@@ -804,12 +885,57 @@ pub fn IsWow64Message() -> bool {
 	return unsafe { ffi::IsWow64Message() != 0}
 }
 
+/// Calls [`MapVirtualKey`](crate::MapVirtualKey) to retrieve the scan code of
+/// the virtual key, then calls [`GetKeyNameText`](crate::GetKeyNameText) to
+/// retrieve its localized name.
+///
+/// The `extended` flag distinguishes keys like
+/// [`VK::RIGHT`](crate::co::VK::RIGHT) from the numeric keypad ones; it's
+/// also set automatically when the system reports an extended scan code.
+///
+/// Fails with [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+/// if the virtual key has no scan code, like the mouse buttons.
+///
+/// # Examples
+///
+/// Displaying a shortcut like "Ctrl+Shift+P":
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let names = [co::VK::CONTROL, co::VK::SHIFT, co::VK::CHAR_P]
+///     .iter()
+///     .map(|vk| w::key_name(*vk, false))
+///     .collect::<w::SysResult<Vec<_>>>()?;
+///
+/// println!("{}", names.join("+"));
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn key_name(vk: co::VK, extended: bool) -> SysResult<String> {
+	let scan = MapVirtualKey(vk.raw() as _, co::MAPVK::VK_TO_VSC_EX);
+	if scan == 0 {
+		return Err(co::ERROR::INVALID_PARAMETER); // no scan code, like mouse buttons
+	}
+	let extended = extended || (scan & 0xff00) == 0xe000;
+	GetKeyNameText(((scan & 0xff) << 16 | (extended as u32) << 24) as _)
+}
+
 /// [`LockSetForegroundWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-locksetforegroundwindow)
 /// function.
 pub fn LockSetForegroundWindow(lock_code: co::LSFW) -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::LockSetForegroundWindow(lock_code.raw()) })
 }
 
+/// [`MapVirtualKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyw)
+/// function.
+///
+/// Returns zero if there is no translation.
+#[must_use]
+pub fn MapVirtualKey(code: u32, map_type: co::MAPVK) -> u32 {
+	unsafe { ffi::MapVirtualKeyW(code, map_type.raw()) }
+}
+
 /// [`MessageBeep`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-messagebeep)
 /// function.
 pub fn MessageBeep(sound_type: co::MBP) -> SysResult<()> {
@@ -892,6 +1018,45 @@ pub unsafe fn RegisterClassEx(wcx: &WNDCLASSEX) -> SysResult<ATOM> {
 	}
 }
 
+/// [`RegisterRawInputDevices`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerrawinputdevices)
+/// function.
+///
+/// After the registration, the target window will receive
+/// [`wm::Input`](crate::msg::wm::Input) messages, whose data is read with
+/// [`HRAWINPUT::GetRawInputData`](crate::prelude::user_Hrawinput::GetRawInputData).
+///
+/// # Examples
+///
+/// Registering the mouse, which has usage page 0x01 and usage 0x02:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// w::RegisterRawInputDevices(&[
+///     w::RAWINPUTDEVICE {
+///         usUsagePage: 0x01,
+///         usUsage: 0x02,
+///         dwFlags: co::RIDEV::INPUTSINK,
+///         hwndTarget: hwnd,
+///     },
+/// ])?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn RegisterRawInputDevices(devices: &[RAWINPUTDEVICE]) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::RegisterRawInputDevices(
+				vec_ptr(devices) as _,
+				devices.len() as _,
+				std::mem::size_of::<RAWINPUTDEVICE>() as _,
+			)
+		},
+	)
+}
+
 /// [`RegisterWindowMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
 /// function.
 #[must_use]
@@ -904,9 +1069,24 @@ pub fn RegisterWindowMessage(s: &str) -> SysResult<u32> {
 	}
 }
 
+/// [`ReleaseCapture`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasecapture)
+/// function.
+///
+/// Usually you don't need to call this function, since
+/// [`HWND::SetCapture`](crate::prelude::user_Hwnd::SetCapture) returns a
+/// guard which releases the capture automatically.
+pub fn ReleaseCapture() -> SysResult<()> {
+	bool_to_sysresult(unsafe { ffi::ReleaseCapture() })
+}
+
 /// [`SendInput`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendinput)
 /// function.
 ///
+/// The most common events can be built with
+/// [`HwKbMouse::key_press`](crate::HwKbMouse::key_press),
+/// [`HwKbMouse::unicode_char`](crate::HwKbMouse::unicode_char) and
+/// [`HwKbMouse::mouse_move_abs`](crate::HwKbMouse::mouse_move_abs).
+///
 /// # Examples
 ///
 /// Sending Win+D to toggle the desktop:
@@ -1109,10 +1289,36 @@ pub fn SwapMouseButton(swap: bool) -> bool {
 /// [`SystemParametersInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfow)
 /// function.
 ///
+/// For the most common actions, prefer the safe wrappers:
+///
+/// * [`SystemParametersInfo_get_iconmetrics`](crate::SystemParametersInfo_get_iconmetrics);
+/// * [`SystemParametersInfo_get_nonclientmetrics`](crate::SystemParametersInfo_get_nonclientmetrics);
+/// * [`SystemParametersInfo_get_workarea`](crate::SystemParametersInfo_get_workarea).
+///
 /// # Safety
 ///
 /// The `pv_param` type varies according to `action`. If you set it wrong,
 /// you're likely to cause a buffer overrun.
+///
+/// # Examples
+///
+/// Checking whether client area animations are enabled:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut enabled: i32 = 0; // BOOL
+/// unsafe {
+///     w::SystemParametersInfo(
+///         co::SPI::GETCLIENTAREAANIMATION,
+///         0,
+///         &mut enabled,
+///         co::SPIF::NoValue,
+///     )?;
+/// }
+/// println!("Animations: {}", enabled != 0);
+/// # w::SysResult::Ok(())
+/// ```
 pub unsafe fn SystemParametersInfo<T>(
 	action: co::SPI,
 	ui_param: u32,
@@ -1130,6 +1336,17 @@ pub unsafe fn SystemParametersInfo<T>(
 	)
 }
 
+/// [`SystemParametersInfo`](crate::SystemParametersInfo) function with
+/// [`co::SPI::GETWORKAREA`](crate::co::SPI::GETWORKAREA) action, which
+/// retrieves the work area of the primary monitor – that is, the screen area
+/// not obscured by the taskbar and other docked bars.
+pub fn SystemParametersInfo_get_workarea() -> SysResult<RECT> {
+	let mut rc = RECT::default();
+	unsafe {
+		SystemParametersInfo(co::SPI::GETWORKAREA, 0, &mut rc, co::SPIF::NoValue)
+	}.map(|_| rc)
+}
+
 /// [`TrackMouseEvent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-trackmouseevent)
 /// function.
 pub fn TrackMouseEvent(tme: &mut TRACKMOUSEEVENT) -> SysResult<()> {
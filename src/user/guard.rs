@@ -3,7 +3,7 @@ use std::ops::{Deref, DerefMut};
 
 use crate::decl::*;
 use crate::prelude::*;
-use crate::user::ffi;
+use crate::user::{ffi, proc};
 
 /// RAII implementation for clipboard which automatically calls
 /// [`CloseClipboard`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-closeclipboard)
@@ -149,13 +149,62 @@ impl<'a, H> EndPaintGuard<'a, H>
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for [`HWND`](crate::HWND) which automatically calls
+/// [`KillTimer`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-killtimer)
+/// when the object goes out of scope, also freeing the timer closure, if any.
+///
+/// Returned by
+/// [`HWND::SetTimerScoped`](crate::prelude::user_Hwnd::SetTimerScoped).
+pub struct KillTimerGuard<'a, H>
+	where H: user_Hwnd,
+{
+	hwnd: &'a H,
+	event_id: usize,
+}
+
+impl<'a, H> Drop for KillTimerGuard<'a, H>
+	where H: user_Hwnd,
+{
+	fn drop(&mut self) {
+		unsafe { ffi::KillTimer(self.hwnd.ptr(), self.event_id); } // ignore errors
+		proc::remove_timer_func(self.hwnd.ptr() as _, self.event_id);
+	}
+}
+
+impl<'a, H> KillTimerGuard<'a, H>
+	where H: user_Hwnd,
+{
+	/// Constructs the guard by taking ownership of the timer.
+	///
+	/// # Safety
+	///
+	/// Be sure you must call
+	/// [`KillTimer`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-killtimer)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(hwnd: &'a H, event_id: usize) -> Self {
+		Self { hwnd, event_id }
+	}
+
+	/// Returns the timer ID.
+	#[must_use]
+	pub const fn event_id(&self) -> usize {
+		self.event_id
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`HWND`](crate::HWND) which automatically calls
 /// [`ReleaseCapture`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasecapture)
 /// when the object goes out of scope.
+///
+/// The capture is released only if it's still held by the window, so the
+/// guard won't steal the capture taken meanwhile by another window.
 pub struct ReleaseCaptureGuard<'a, H>
 	where H: user_Hwnd,
 {
-	_hwnd: &'a H,
+	hwnd: &'a H,
 	hwnd_prev: Option<HWND>,
 }
 
@@ -163,7 +212,9 @@ impl<'a, H> Drop for ReleaseCaptureGuard<'a, H>
 	where H: user_Hwnd,
 {
 	fn drop(&mut self) {
-		unsafe { ffi::ReleaseCapture(); } // ignore errors
+		if unsafe { ffi::GetCapture() } == self.hwnd.ptr() {
+			unsafe { ffi::ReleaseCapture(); } // ignore errors
+		}
 	}
 }
 
@@ -179,7 +230,7 @@ impl<'a, H> ReleaseCaptureGuard<'a, H>
 	/// at the end of scope.
 	#[must_use]
 	pub const unsafe fn new(hwnd: &'a H, hwnd_prev: Option<HWND>) -> Self {
-		Self { _hwnd: hwnd, hwnd_prev }
+		Self { hwnd, hwnd_prev }
 	}
 
 	/// Returns a handle to the window that had previously captured the mouse,
@@ -258,3 +309,21 @@ impl<'a, H> ReleaseDCGuard<'a, H>
 		std::mem::replace(&mut self.hdc, HDC::INVALID)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+handle_guard! { UnhookWinEventGuard: HWINEVENTHOOK;
+	ffi::UnhookWinEvent;
+	/// RAII implementation for [`HWINEVENTHOOK`](crate::HWINEVENTHOOK) which
+	/// automatically calls
+	/// [`UnhookWinEvent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwinevent)
+	/// when the object goes out of scope.
+}
+
+handle_guard! { UnregisterPowerSettingNotificationGuard: HPOWERNOTIFY;
+	ffi::UnregisterPowerSettingNotification;
+	/// RAII implementation for [`HPOWERNOTIFY`](crate::HPOWERNOTIFY) which
+	/// automatically calls
+	/// [`UnregisterPowerSettingNotification`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterpowersettingnotification)
+	/// when the object goes out of scope.
+}
@@ -40,6 +40,16 @@ pub trait user_Hcursor: Handle {
 		ptr_to_option_handle(unsafe { ffi::GetCursor() })
 	}
 
+	/// [`SetCursor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursor)
+	/// function.
+	///
+	/// Returns the previous cursor, if any, which should be restored later.
+	/// To temporarily show the wait cursor, prefer using
+	/// [`gui::WaitCursor`](crate::gui::WaitCursor).
+	fn SetCursor(&self) -> Option<HCURSOR> {
+		ptr_to_option_handle(unsafe { ffi::SetCursor(self.ptr()) })
+	}
+
 	/// [`SetSystemCursor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setsystemcursor)
 	/// function.
 	fn SetSystemCursor(&self, id: co::OCR) -> SysResult<()> {
@@ -30,6 +30,34 @@ pub trait user_Hdc: Handle {
 		)
 	}
 
+	/// [`DrawIconEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawiconex)
+	/// function.
+	///
+	/// If `sz` is zero, the icon is drawn with its own size.
+	fn DrawIconEx(&self,
+		pos: POINT,
+		hicon: &HICON,
+		sz: SIZE,
+		step_if_ani_cur: u32,
+		hbr_flicker_free: Option<&HBRUSH>,
+		flags: co::DI,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::DrawIconEx(
+					self.ptr(),
+					pos.x, pos.y,
+					hicon.ptr(),
+					sz.cx, sz.cy,
+					step_if_ani_cur,
+					hbr_flicker_free.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					flags.raw(),
+				)
+			},
+		)
+	}
+
 	/// [`DrawText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawtextw)
 	/// function.
 	fn DrawText(&self,
@@ -55,11 +83,45 @@ pub trait user_Hdc: Handle {
 
 	/// [`DrawTextExW`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-drawtextexw)
 	/// function.
+	///
+	/// Returns the height of the drawn text, in logical units. The `bounds`
+	/// rectangle is updated when [`DT::CALCRECT`](crate::co::DT::CALCRECT) is
+	/// passed, and the `uiLengthDrawn` field of `dtp` receives the number of
+	/// characters processed.
+	///
+	/// Since the text buffer is internal,
+	/// [`DT::MODIFYSTRING`](crate::co::DT::MODIFYSTRING) is ignored.
+	///
+	/// # Examples
+	///
+	/// Drawing tab-separated columns, and laying out the next block below:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hdc: w::HDC; // initialized somewhere
+	/// # let hdc = w::HDC::NULL;
+	///
+	/// let mut dtp = w::DRAWTEXTPARAMS::default();
+	/// dtp.iTabLength = 12; // in average character widths
+	/// dtp.iLeftMargin = 4;
+	///
+	/// let mut rc = w::RECT { left: 10, top: 10, right: 400, bottom: 300 };
+	/// let height = hdc.DrawTextEx(
+	///     "Name\tSize\tDate\nreport.pdf\t1.2 MB\t2024-01-01",
+	///     &mut rc,
+	///     co::DT::EXPANDTABS | co::DT::TABSTOP | co::DT::NOPREFIX,
+	///     Some(&mut dtp),
+	/// )?;
+	///
+	/// rc.top += height; // next block goes below
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn DrawTextEx(&self,
 		text: &str,
-		bounds: RECT,
+		bounds: &mut RECT,
 		format: co::DT,
-		dtp: Option<&DRAWTEXTPARAMS>,
+		dtp: Option<&mut DRAWTEXTPARAMS>,
 	) -> SysResult<i32>
 	{
 		let wtext = WString::from_str(text);
@@ -68,9 +130,9 @@ pub trait user_Hdc: Handle {
 				self.ptr(),
 				wtext.as_ptr(),
 				wtext.str_len() as _,
-				&bounds as *const _ as _,
-				format.raw(),
-				dtp.map_or(std::ptr::null(), |p| p as *const _ as _),
+				bounds as *mut _ as _,
+				(format & !co::DT::MODIFYSTRING).raw(),
+				dtp.map_or(std::ptr::null(), |p| p as *mut _ as _),
 			)
 		} {
 			0 => Err(GetLastError()),
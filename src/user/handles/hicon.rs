@@ -32,6 +32,19 @@ pub trait user_Hicon: Handle {
 		}
 	}
 
+	/// [`CreateIconIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconindirect)
+	/// function.
+	///
+	/// The bitmaps in `icon_info` are copied, so they still must be deleted by
+	/// the caller.
+	fn CreateIconIndirect(icon_info: &ICONINFO) -> SysResult<DestroyIconGuard> {
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateIconIndirect(icon_info as *const _ as _),
+			).map(|h| DestroyIconGuard::new(h))
+		}
+	}
+
 	/// [`GetIconInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-geticoninfo)
 	/// function.
 	#[must_use]
@@ -74,6 +74,69 @@ pub trait user_Hmenu: Handle {
 		Ok(())
 	}
 
+	/// A more convenient
+	/// [`HMENU::InsertMenuItem`](crate::prelude::user_Hmenu::InsertMenuItem),
+	/// which inserts multiple entries before the given item, keeping their
+	/// order.
+	///
+	/// # Examples
+	///
+	/// Rebuilding a "Recent files" submenu, which is usually done when
+	/// handling [`wm::InitMenuPopup`](crate::msg::wm::InitMenuPopup):
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, seq_ids};
+	///
+	/// seq_ids! {
+	///     ID_RECENT_1 = 3001;
+	///     ID_RECENT_2
+	/// }
+	///
+	/// let hmenu_recent: w::HMENU; // initialized somewhere
+	/// # let hmenu_recent = w::HMENU::NULL;
+	///
+	/// for _ in 0..hmenu_recent.GetMenuItemCount()? {
+	///     hmenu_recent.DeleteMenu(w::IdPos::Pos(0))?;
+	/// }
+	///
+	/// hmenu_recent.insert_item(w::IdPos::Pos(0), &[
+	///     w::MenuItem::Entry(ID_RECENT_1, "&1 C:\\Temp\\foo.txt"),
+	///     w::MenuItem::Entry(ID_RECENT_2, "&2 C:\\Temp\\bar.txt"),
+	/// ])?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn insert_item(&self, before: IdPos, items: &[MenuItem]) -> SysResult<()> {
+		items.iter().enumerate().try_for_each(|(idx, item)| {
+			let before = match before {
+				IdPos::Pos(pos) => IdPos::Pos(pos + idx as u32), // previous items pushed it forward
+				id => id, // inserting before the same ID keeps the order
+			};
+
+			let mut mii = MENUITEMINFO::default();
+			let mut wtext; // must outlive the InsertMenuItem call
+			match item {
+				MenuItem::Entry(cmd_id, text) => {
+					wtext = WString::from_str(text);
+					mii.fMask = co::MIIM::ID | co::MIIM::STRING;
+					mii.wID = *cmd_id as _;
+					mii.dwTypeData = unsafe { wtext.as_mut_ptr() };
+				},
+				MenuItem::Separator => {
+					mii.fMask = co::MIIM::FTYPE;
+					mii.fType = co::MFT::SEPARATOR;
+				},
+				MenuItem::Submenu(hmenu, text) => {
+					wtext = WString::from_str(text);
+					mii.fMask = co::MIIM::STRING | co::MIIM::SUBMENU;
+					mii.hSubMenu = unsafe { hmenu.raw_copy() };
+					mii.dwTypeData = unsafe { wtext.as_mut_ptr() };
+				},
+			}
+
+			self.InsertMenuItem(before, &mii)
+		})
+	}
+
 	/// Simpler version of
 	/// [`HMENU::GetMenuItemInfo`](crate::prelude::user_Hmenu::GetMenuItemInfo),
 	/// which returns a [`MenuItemInfo`](crate::MenuItemInfo) instead of the
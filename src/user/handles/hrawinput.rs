@@ -0,0 +1,91 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+use crate::user::{ffi, privs::*};
+
+impl_handle! { HRAWINPUT;
+	/// Handle to a
+	/// [raw input](https://learn.microsoft.com/en-us/windows/win32/inputdev/raw-input),
+	/// received in the [`wm::Input`](crate::msg::wm::Input) message.
+}
+
+impl user_Hrawinput for HRAWINPUT {}
+
+/// This trait is enabled with the `user` feature, and provides methods for
+/// [`HRAWINPUT`](crate::HRAWINPUT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait user_Hrawinput: Handle {
+	/// [`GetRawInputData`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdata)
+	/// function, with `RID_INPUT`.
+	///
+	/// Queries the needed buffer size, then retrieves the whole
+	/// [`RAWINPUT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinput)
+	/// data, parsed according to the device type.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hrawinput: w::HRAWINPUT; // initialized somewhere
+	/// # let hrawinput = w::HRAWINPUT::NULL;
+	///
+	/// let (header, data) = hrawinput.GetRawInputData()?;
+	/// if let w::HidKbMouse::Mouse(mouse) = data {
+	///     println!("Device {:?} moved {}, {}",
+	///         header.hDevice, mouse.lLastX, mouse.lLastY);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn GetRawInputData(&self) -> SysResult<(RAWINPUTHEADER, HidKbMouse)> {
+		let sz_header = std::mem::size_of::<RAWINPUTHEADER>();
+		let mut sz = u32::default();
+		if unsafe {
+			ffi::GetRawInputData(
+				self.ptr(), RID_INPUT, std::ptr::null_mut(), &mut sz, sz_header as _)
+		} == u32::MAX {
+			return Err(GetLastError());
+		}
+
+		let mut buf = vec![0u64; (sz as usize).div_ceil(8)]; // 8-byte aligned buffer
+		if unsafe {
+			ffi::GetRawInputData(
+				self.ptr(), RID_INPUT, buf.as_mut_ptr() as _, &mut sz, sz_header as _)
+		} == u32::MAX {
+			return Err(GetLastError());
+		}
+
+		let ptr = buf.as_ptr() as *const u8;
+		let header = unsafe { *(ptr as *const RAWINPUTHEADER) };
+		let data_ptr = unsafe { ptr.add(sz_header) };
+
+		let data = match header.dwType {
+			co::RIM_TYPE::MOUSE => HidKbMouse::Mouse(
+				unsafe { *(data_ptr as *const RAWMOUSE) },
+			),
+			co::RIM_TYPE::KEYBOARD => HidKbMouse::Kb(
+				unsafe { *(data_ptr as *const RAWKEYBOARD) },
+			),
+			_ => { // RAWHID: dwSizeHid, dwCount, then the reports
+				let size_hid = unsafe { *(data_ptr as *const u32) } as usize;
+				let count = unsafe { *(data_ptr.add(4) as *const u32) } as usize;
+				let reports = unsafe {
+					std::slice::from_raw_parts(data_ptr.add(8), size_hid * count)
+				};
+				HidKbMouse::Hid(
+					reports.chunks(size_hid.max(1))
+						.map(|report| report.to_vec())
+						.collect(),
+				)
+			},
+		};
+		Ok((header, data))
+	}
+}
@@ -0,0 +1,56 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+use crate::user::ffi;
+
+impl_handle! { HWINEVENTHOOK;
+	/// Handle to an
+	/// [event hook](https://learn.microsoft.com/en-us/windows/win32/winauto/event-hook-functions)
+	/// function.
+}
+
+impl user_Hwineventhook for HWINEVENTHOOK {}
+
+/// This trait is enabled with the `user` feature, and provides methods for
+/// [`HWINEVENTHOOK`](crate::HWINEVENTHOOK).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait user_Hwineventhook: Handle {
+	/// [`SetWinEventHook`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwineventhook)
+	/// function.
+	///
+	/// For a simpler way to handle the events with a closure, see
+	/// [`WinEventHook`](crate::WinEventHook).
+	fn SetWinEventHook(
+		event_min: co::EVENT,
+		event_max: co::EVENT,
+		hmod_win_event_proc: Option<&HINSTANCE>,
+		win_event_proc: WINEVENTPROC,
+		process_id: Option<u32>,
+		thread_id: Option<u32>,
+		flags: co::WINEVENT,
+	) -> SysResult<UnhookWinEventGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::SetWinEventHook(
+					event_min.raw(),
+					event_max.raw(),
+					hmod_win_event_proc.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					win_event_proc as _,
+					process_id.unwrap_or_default(),
+					thread_id.unwrap_or_default(),
+					flags.raw(),
+				),
+			).map(|h| UnhookWinEventGuard::new(h))
+		}
+	}
+}
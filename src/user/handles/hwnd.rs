@@ -34,6 +34,180 @@ pub trait user_Hwnd: Handle {
 	/// [`HWND::GetDC`](crate::prelude::user_Hwnd::GetDC).
 	const DESKTOP: HWND = HWND(std::ptr::null_mut());
 
+	/// Shows the color picker dialog, owned by this window, through
+	/// [`ChooseColor`](crate::ChooseColor).
+	///
+	/// The [`co::CC::RGBINIT`](crate::co::CC::RGBINIT) flag is always added,
+	/// so `initial` is the color initially selected. The custom colors are read
+	/// from and written back to `custom_colors`, so they can be persisted
+	/// across calls.
+	///
+	/// Returns `None` if the user cancelled the dialog.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let mut custom_colors = [w::COLORREF::new(255, 255, 255); 16];
+	///
+	/// if let Some(color) = hwnd.choose_color(
+	///     w::COLORREF::new(255, 0, 0),
+	///     &mut custom_colors,
+	///     co::CC::ANYCOLOR | co::CC::FULLOPEN,
+	/// )? {
+	///     println!("The color: {}", color);
+	/// }
+	/// # Ok::<_, co::CDERR>(())
+	/// ```
+	fn choose_color(&self,
+		initial: COLORREF,
+		custom_colors: &mut [COLORREF; 16],
+		flags: co::CC,
+	) -> Result<Option<COLORREF>, co::CDERR>
+	{
+		let mut cc = CHOOSECOLOR::default();
+		cc.hwndOwner = unsafe { HWND::from_ptr(self.ptr()) };
+		cc.Flags = flags | co::CC::RGBINIT;
+		cc.rgbResult = initial;
+		cc.set_lpCustColors(Some(custom_colors));
+
+		ChooseColor(&mut cc)
+			.map(|chosen| if chosen { Some(cc.rgbResult) } else { None })
+	}
+
+	/// Makes the window gradually opaque, from fully transparent, during the
+	/// given time. If the window is hidden, it's shown.
+	///
+	/// The animation sets the [`co::WS_EX::LAYERED`](crate::co::WS_EX::LAYERED)
+	/// style and changes the alpha with
+	/// [`HWND::SetLayeredWindowAttributes`](crate::prelude::user_Hwnd::SetLayeredWindowAttributes),
+	/// driven by a [`HWND::SetTimer`](crate::prelude::user_Hwnd::SetTimer)
+	/// callback. Thus, it can't be used on child windows, nor on windows which
+	/// are updated with per-pixel alpha through `UpdateLayeredWindow`.
+	///
+	/// If a fade is already running, it's replaced. If the window is destroyed
+	/// mid-fade, the timer is destroyed along with it.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.fade_in(300)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn fade_in(&self, duration_ms: u32) -> SysResult<()> {
+		proc::start_fade(
+			unsafe { &HWND::from_ptr(self.ptr()) }, duration_ms, true, false)
+	}
+
+	/// Makes the window gradually transparent during the given time.
+	///
+	/// When the animation finishes, if `then_close` is `true`, a
+	/// [`wm::Close`](crate::msg::wm::Close) message is posted to the window;
+	/// otherwise the window is hidden and its opacity is restored, so it can be
+	/// shown again later.
+	///
+	/// See [`HWND::fade_in`](crate::prelude::user_Hwnd::fade_in) for the
+	/// restrictions.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.fade_out(300, true)?; // window will be closed at the end
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn fade_out(&self, duration_ms: u32, then_close: bool) -> SysResult<()> {
+		proc::start_fade(
+			unsafe { &HWND::from_ptr(self.ptr()) }, duration_ms, false, then_close)
+	}
+
+	/// Calls
+	/// [`HWND::FlashWindowEx`](crate::prelude::user_Hwnd::FlashWindowEx) with
+	/// `FLASHW::ALL | FLASHW::TIMERNOFG`, which flashes both the caption and
+	/// the taskbar button until the window comes to the foreground.
+	///
+	/// This is the standard way to get the user's attention without stealing
+	/// the focus, like
+	/// [`HWND::SetForegroundWindow`](crate::prelude::user_Hwnd::SetForegroundWindow)
+	/// does.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// // background task finished
+	/// hwnd.flash_until_focused();
+	/// ```
+	fn flash_until_focused(&self) {
+		self.FlashWindowEx(co::FLASHW::ALL | co::FLASHW::TIMERNOFG, 0, 0);
+	}
+
+	/// Brings the window to the foreground, even if the current process is not
+	/// the foreground one.
+	///
+	/// If the window is minimized, it's restored. Then, the input of the
+	/// current thread is temporarily attached to the thread of the current
+	/// foreground window with [`AttachThreadInput`](crate::AttachThreadInput),
+	/// so that
+	/// [`HWND::SetForegroundWindow`](crate::prelude::user_Hwnd::SetForegroundWindow)
+	/// is allowed, and detached right after.
+	///
+	/// Returns the value of `SetForegroundWindow`, that is, whether the window
+	/// was brought to the foreground.
+	///
+	/// This is intrusive to the user, and should be used only when explicitly
+	/// requested – e.g. when a second instance of the application is launched.
+	/// Otherwise, prefer
+	/// [`HWND::flash_until_focused`](crate::prelude::user_Hwnd::flash_until_focused).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.force_foreground()?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn force_foreground(&self) -> SysResult<bool> {
+		if self.IsIconic() {
+			self.ShowWindow(co::SW::RESTORE);
+		}
+
+		let cur_thread_id = GetCurrentThreadId();
+		let fore_thread_id = HWND::GetForegroundWindow()
+			.map_or(0, |hfore| hfore.GetWindowThreadProcessId().0);
+
+		if fore_thread_id == 0 || fore_thread_id == cur_thread_id {
+			return Ok(self.SetForegroundWindow()); // no need to attach
+		}
+
+		AttachThreadInput(cur_thread_id, fore_thread_id, true)?;
+		let brought = self.SetForegroundWindow();
+		let _ = self.BringWindowToTop(); // ignore errors, just a z-order hint
+		AttachThreadInput(cur_thread_id, fore_thread_id, false)?; // always detach
+		Ok(brought)
+	}
+
 	/// Calls
 	/// [`HWND::GetWindowLongPtr`](crate::prelude::user_Hwnd::GetWindowLongPtr)
 	/// to retrieve the window [`HINSTANCE`](crate::HINSTANCE).
@@ -53,6 +227,38 @@ pub trait user_Hwnd: Handle {
 		self.GetClassLongPtr(co::GCLP::ATOM) as u16 == WC_DIALOG
 	}
 
+	/// Calls [`HWND::SendMessage`](crate::prelude::user_Hwnd::SendMessage)
+	/// with raw message parameters, returning the raw result.
+	///
+	/// This is an escape hatch for messages which are not modeled by the
+	/// [`msg`](crate::msg) module. If you send a message repeatedly, prefer
+	/// implementing [`MsgSend`](crate::prelude::MsgSend) for your own struct,
+	/// which gives you type-safe parameters and return value.
+	///
+	/// # Safety
+	///
+	/// No conversions are performed: any pointers passed in `wparam` and
+	/// `lparam` must be valid for the duration of the call, and the meaning of
+	/// the returned value is entirely up to the caller.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let my_msg = unsafe { co::WM::from_raw(co::WM::USER.raw() + 10) };
+	///
+	/// let ret = unsafe {
+	///     hwnd.send_raw(my_msg, 0, 0)
+	/// };
+	/// ```
+	unsafe fn send_raw(&self, msg_id: co::WM, wparam: usize, lparam: isize) -> isize {
+		self.SendMessage(crate::msg::WndMsg::new(msg_id, wparam, lparam))
+	}
+
 	/// Calls
 	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr)
 	/// to set the window styles.
@@ -61,6 +267,47 @@ pub trait user_Hwnd: Handle {
 		unsafe { self.SetWindowLongPtr(co::GWLP::STYLE, style.raw() as _); }
 	}
 
+	/// Calls
+	/// [`HWND::SetWindowText`](crate::prelude::user_Hwnd::SetWindowText) with
+	/// formatted text, which is encoded straight into UTF-16, without building
+	/// an intermediate [`String`](std::string::String).
+	///
+	/// The text is always set, since there's no place to cache the previous
+	/// one in a raw `HWND`. The GUI windows and controls, however, provide the
+	/// [`set_text_if_changed`](crate::prelude::GuiWindowText::set_text_if_changed)
+	/// method, which avoids needless repaints.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let (done, total) = (42, 100);
+	/// hwnd.set_window_text_fmt(format_args!("Progress: {}/{}", done, total))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn set_window_text_fmt(&self, args: std::fmt::Arguments) -> SysResult<()> {
+		struct Utf16Writer(Vec<u16>);
+		impl std::fmt::Write for Utf16Writer {
+			fn write_str(&mut self, s: &str) -> std::fmt::Result {
+				self.0.extend(s.encode_utf16());
+				Ok(())
+			}
+		}
+
+		let mut writer = Utf16Writer(Vec::with_capacity(WString::SSO_LEN));
+		std::fmt::write(&mut writer, args)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)?; // a Display impl failed
+		writer.0.push(0x0000); // terminating null
+
+		bool_to_sysresult(
+			unsafe { ffi::SetWindowTextW(self.ptr(), writer.0.as_ptr()) },
+		)
+	}
+
 	/// Calls
 	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr)
 	/// to set the extended window styles.
@@ -69,6 +316,52 @@ pub trait user_Hwnd: Handle {
 		unsafe { self.SetWindowLongPtr(co::GWLP::EXSTYLE, ex_style.raw() as _); }
 	}
 
+	/// Calls
+	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr)
+	/// with [`co::GWLP::USERDATA`](crate::co::GWLP::USERDATA) to store a boxed
+	/// object in the window, which can be retrieved later with
+	/// [`user_data`](crate::prelude::user_Hwnd::user_data) and
+	/// [`take_user_data`](crate::prelude::user_Hwnd::take_user_data).
+	///
+	/// The box is leaked into the window, so you must call
+	/// [`take_user_data`](crate::prelude::user_Hwnd::take_user_data) when
+	/// processing [`wm::NcDestroy`](crate::msg::wm::NcDestroy), otherwise the
+	/// memory will never be freed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window already holds user data.
+	///
+	/// # Safety
+	///
+	/// The same type `T` must be used when retrieving the object.
+	///
+	/// Do not use this method on windows created by the [`gui`](crate::gui)
+	/// module, which already use `GWLP_USERDATA` internally.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// struct State { clicks: u32 }
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// unsafe { hwnd.set_user_data(Box::new(State { clicks: 0 })); }
+	///
+	/// // When processing WM_NCDESTROY:
+	/// let state = unsafe { hwnd.take_user_data::<State>() };
+	/// drop(state);
+	/// ```
+	unsafe fn set_user_data<T>(&self, data: Box<T>) {
+		if self.GetWindowLongPtr(co::GWLP::USERDATA) != 0 {
+			panic!("Window already holds user data.");
+		}
+		self.SetWindowLongPtr(co::GWLP::USERDATA, Box::into_raw(data) as _);
+	}
+
 	/// Calls
 	/// [`HWND::GetWindowLongPtr`](crate::prelude::user_Hwnd::GetWindowLongPtr)
 	/// to retrieve the window styles.
@@ -87,6 +380,60 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// Calls
+	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr)
+	/// to clear [`co::GWLP::USERDATA`](crate::co::GWLP::USERDATA), returning
+	/// the box previously stored with
+	/// [`set_user_data`](crate::prelude::user_Hwnd::set_user_data), if any.
+	///
+	/// This method should be called when processing
+	/// [`wm::NcDestroy`](crate::msg::wm::NcDestroy), so the object is freed.
+	///
+	/// # Safety
+	///
+	/// `T` must be the same type passed to
+	/// [`set_user_data`](crate::prelude::user_Hwnd::set_user_data).
+	#[must_use]
+	unsafe fn take_user_data<T>(&self) -> Option<Box<T>> {
+		match self.SetWindowLongPtr(co::GWLP::USERDATA, 0) {
+			0 => None,
+			ptr => Some(Box::from_raw(ptr as *mut T)),
+		}
+	}
+
+	/// Calls
+	/// [`HWND::GetWindowLongPtr`](crate::prelude::user_Hwnd::GetWindowLongPtr)
+	/// to retrieve a reference to the object stored with
+	/// [`set_user_data`](crate::prelude::user_Hwnd::set_user_data), if any.
+	///
+	/// For mutable state, wrap the fields in
+	/// [`Cell`](std::cell::Cell) or [`RefCell`](std::cell::RefCell).
+	///
+	/// # Safety
+	///
+	/// `T` must be the same type passed to
+	/// [`set_user_data`](crate::prelude::user_Hwnd::set_user_data), and the
+	/// returned reference must not outlive the call to
+	/// [`take_user_data`](crate::prelude::user_Hwnd::take_user_data).
+	#[must_use]
+	unsafe fn user_data<T>(&self) -> Option<&T> {
+		(self.GetWindowLongPtr(co::GWLP::USERDATA) as *const T).as_ref()
+	}
+
+	/// [`AddClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-addclipboardformatlistener)
+	/// function.
+	///
+	/// The window will receive a
+	/// [`wm::ClipboardUpdate`](crate::msg::wm::ClipboardUpdate) message
+	/// whenever the clipboard contents change.
+	///
+	/// # Related functions
+	///
+	/// * [`HWND::RemoveClipboardFormatListener`](crate::prelude::user_Hwnd::RemoveClipboardFormatListener)
+	fn AddClipboardFormatListener(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::AddClipboardFormatListener(self.ptr()) })
+	}
+
 	/// [`ArrangeIconicWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// function.
 	fn ArrangeIconicWindows(&self) -> SysResult<u32> {
@@ -161,6 +508,14 @@ pub trait user_Hwnd: Handle {
 
 	/// [`ChildWindowFromPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-childwindowfrompoint)
 	/// function.
+	///
+	/// The point must be in **client coordinates** of the parent window. Hidden,
+	/// disabled and transparent child windows are not skipped; if you need
+	/// this, see
+	/// [`HWND::ChildWindowFromPointEx`](crate::prelude::user_Hwnd::ChildWindowFromPointEx).
+	///
+	/// Returns the parent window itself if the point is within it, but not over
+	/// any child; returns `None` if the point is outside the parent window.
 	#[must_use]
 	fn ChildWindowFromPoint(&self, pt: POINT) -> Option<HWND> {
 		ptr_to_option_handle(
@@ -168,6 +523,44 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`ChildWindowFromPointEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-childwindowfrompointex)
+	/// function.
+	///
+	/// The point must be in **client coordinates** of the parent window.
+	///
+	/// Returns the parent window itself if the point is within it, but not over
+	/// any child; returns `None` if the point is outside the parent window.
+	///
+	/// # Examples
+	///
+	/// Finding the visible and enabled control under the cursor:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hparent: w::HWND; // initialized somewhere
+	/// # let hparent = w::HWND::NULL;
+	///
+	/// let pt_screen = w::GetCursorPos()?;
+	/// let pt_client = hparent.ScreenToClient(pt_screen)?;
+	///
+	/// if let Some(hctrl) = hparent.ChildWindowFromPointEx(
+	///     pt_client,
+	///     co::CWP::SKIPINVISIBLE | co::CWP::SKIPDISABLED,
+	/// ) {
+	///     println!("Control under cursor: {}", hctrl);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn ChildWindowFromPointEx(&self, pt: POINT, flags: co::CWP) -> Option<HWND> {
+		ptr_to_option_handle(
+			unsafe {
+				ffi::ChildWindowFromPointEx(self.ptr(), pt.x, pt.y, flags.raw())
+			},
+		)
+	}
+
 	/// [`ClientToScreen`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-clienttoscreen)
 	/// function.
 	///
@@ -427,6 +820,52 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`FlashWindowEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-flashwindowex)
+	/// function.
+	///
+	/// The [`FLASHWINFO`](crate::FLASHWINFO) struct is filled internally.
+	/// `count` is the number of flashes, and `timeout_ms` is the flash rate in
+	/// milliseconds; zero means the default cursor blink rate.
+	///
+	/// Returns whether the window caption was drawn as active before the call.
+	///
+	/// # Examples
+	///
+	/// Flashing the taskbar button 3 times:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.FlashWindowEx(co::FLASHW::TRAY, 3, 0);
+	/// ```
+	///
+	/// To stop flashing:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.FlashWindowEx(co::FLASHW::STOP, 0, 0);
+	/// ```
+	fn FlashWindowEx(&self,
+		flags: co::FLASHW,
+		count: u32,
+		timeout_ms: u32,
+	) -> bool
+	{
+		let mut fwi = FLASHWINFO::default();
+		fwi.hwnd = unsafe { HWND::from_ptr(self.ptr()) };
+		fwi.dwFlags = flags;
+		fwi.uCount = count;
+		fwi.dwTimeout = timeout_ms;
+		unsafe { ffi::FlashWindowEx(&fwi as *const _ as _) != 0 }
+	}
+
 	/// [`GetActiveWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getactivewindow)
 	/// function.
 	#[must_use]
@@ -468,6 +907,24 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetAncestor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getancestor)
 	/// function.
+	///
+	/// Returns `None` if the window has no such ancestor.
+	///
+	/// # Examples
+	///
+	/// Finding the top-level window of an arbitrary control:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hctrl: w::HWND; // initialized somewhere
+	/// # let hctrl = w::HWND::NULL;
+	///
+	/// if let Some(htop) = hctrl.GetAncestor(co::GA::ROOTOWNER) {
+	///     println!("Top-level: {}", htop.GetWindowText()?);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn GetAncestor(&self, flags: co::GA) -> Option<HWND> {
 		ptr_to_option_handle(unsafe { ffi::GetAncestor(self.ptr(), flags.raw()) })
@@ -688,11 +1145,84 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`GetOpenClipboardWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getopenclipboardwindow)
+	/// function.
+	///
+	/// Returns `None` if the clipboard is not open, or if it was opened with
+	/// a null `HWND`.
+	///
+	/// # Examples
+	///
+	/// Finding out which process is holding the clipboard open:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// if let Err(e) = w::HWND::NULL.OpenClipboard() {
+	///     if let Some(hwnd) = w::HWND::GetOpenClipboardWindow() {
+	///         let (_, pid) = hwnd.GetWindowThreadProcessId();
+	///         println!("Clipboard held open by process {}: {}", pid, e);
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	fn GetOpenClipboardWindow() -> Option<HWND> {
+		ptr_to_option_handle(unsafe { ffi::GetOpenClipboardWindow() })
+	}
+
 	/// [`GetParent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getparent)
 	/// function.
+	///
+	/// Note that, for a top-level window, this function returns the owner
+	/// window, if any. Returns `None` if the window has neither parent nor
+	/// owner. To retrieve strictly the parent, use
+	/// [`HWND::GetAncestor`](crate::prelude::user_Hwnd::GetAncestor) with
+	/// [`co::GA::PARENT`](crate::co::GA::PARENT).
 	#[must_use]
-	fn GetParent(&self) -> SysResult<HWND> {
-		ptr_to_sysresult_handle(unsafe { ffi::GetParent(self.ptr()) })
+	fn GetParent(&self) -> SysResult<Option<HWND>> {
+		SetLastError(co::ERROR::SUCCESS); // null return is ambiguous
+		match ptr_to_option_handle(unsafe { ffi::GetParent(self.ptr()) }) {
+			None => match GetLastError() {
+				co::ERROR::SUCCESS => Ok(None), // no parent nor owner
+				err => Err(err),
+			},
+			Some(h) => Ok(Some(h)),
+		}
+	}
+
+	/// [`GetScrollBarInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getscrollbarinfo)
+	/// function.
+	///
+	/// To retrieve the information of a scroll bar control, pass
+	/// [`co::OBJID::CLIENT`](crate::co::OBJID::CLIENT) to the control's own
+	/// `HWND`.
+	///
+	/// # Examples
+	///
+	/// Checking whether the vertical scroll bar of a window is visible:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let sbi = hwnd.GetScrollBarInfo(co::OBJID::VSCROLL)?;
+	/// let is_visible = !sbi.rgstate[0].has(co::STATE_SYSTEM::INVISIBLE);
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn GetScrollBarInfo(&self, obj_id: co::OBJID) -> SysResult<SCROLLBARINFO> {
+		let mut sbi = SCROLLBARINFO::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetScrollBarInfo(
+					self.ptr(),
+					obj_id.raw() as _,
+					&mut sbi as *mut _ as _,
+				)
+			},
+		).map(|_| sbi)
 	}
 
 	/// [`GetScrollInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getscrollinfo)
@@ -839,6 +1369,9 @@ pub trait user_Hwnd: Handle {
 	/// If you just want to retrieve the window styles, prefer using
 	/// [`HWND::style`](crate::prelude::user_Hwnd::style) and
 	/// [`HWND::style_ex`](crate::prelude::user_Hwnd::style_ex).
+	///
+	/// If you want to retrieve an object stored in `GWLP_USERDATA`, prefer
+	/// using [`HWND::user_data`](crate::prelude::user_Hwnd::user_data).
 	#[must_use]
 	fn GetWindowLongPtr(&self, index: co::GWLP) -> isize {
 		#[cfg(target_pointer_width = "32")]
@@ -930,6 +1463,7 @@ pub trait user_Hwnd: Handle {
 		}
 
 		let mut buf = WString::new_alloc_buf(len as usize + 1); // plus terminating null
+		SetLastError(co::ERROR::SUCCESS);
 		match unsafe {
 			ffi::GetWindowTextW(self.ptr(), buf.as_mut_ptr(), len + 1)
 		} {
@@ -1297,6 +1831,10 @@ pub trait user_Hwnd: Handle {
 	/// scope. You must, however, keep the guard alive, otherwise the cleanup
 	/// will be performed right away.
 	///
+	/// If the clipboard is already open by another window, this function
+	/// fails; that window can be retrieved with
+	/// [`HWND::GetOpenClipboardWindow`](crate::prelude::user_Hwnd::GetOpenClipboardWindow).
+	///
 	/// # Examples
 	///
 	/// ```no_run
@@ -1348,6 +1886,10 @@ pub trait user_Hwnd: Handle {
 
 	/// [`RealChildWindowFromPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-realchildwindowfrompoint)
 	/// function.
+	///
+	/// The point must be in **client coordinates** of the parent window. Unlike
+	/// [`HWND::ChildWindowFromPoint`](crate::prelude::user_Hwnd::ChildWindowFromPoint),
+	/// group boxes are skipped.
 	#[must_use]
 	fn RealChildWindowFromPoint(&self,
 		pt_parent_client_coords: POINT,
@@ -1402,6 +1944,44 @@ pub trait user_Hwnd: Handle {
 
 	/// [`RegisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerhotkey)
 	/// function.
+	///
+	/// When the hotkey is pressed, a
+	/// [`wm::HotKey`](crate::msg::wm::HotKey) message is posted to the
+	/// window. If the
+	/// handle is [`HWND::NULL`](crate::HWND::NULL), the message is posted to the
+	/// message queue of the calling thread instead.
+	///
+	/// Note that a
+	/// [message-only](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows)
+	/// window does not receive `WM_HOTKEY`; use a hidden top-level window, like
+	/// `gui::GlobalHotKeys` does.
+	///
+	/// Unless [`co::MOD::NOREPEAT`](crate::co::MOD::NOREPEAT) is passed, a new
+	/// `WM_HOTKEY` is posted for each keyboard auto-repeat while the keys are
+	/// held down.
+	///
+	/// The hotkey must be unregistered with
+	/// [`HWND::UnregisterHotKey`](crate::prelude::user_Hwnd::UnregisterHotKey).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.RegisterHotKey(
+	///     1, // arbitrary ID, passed to WM_HOTKEY
+	///     co::MOD::CONTROL | co::MOD::SHIFT | co::MOD::NOREPEAT,
+	///     co::VK::F9,
+	/// )?;
+	///
+	/// // ...
+	///
+	/// hwnd.UnregisterHotKey(1)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn RegisterHotKey(&self,
 		id: i32,
 		modifiers: co::MOD,
@@ -1420,6 +2000,54 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`RegisterPowerSettingNotification`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerpowersettingnotification)
+	/// function.
+	///
+	/// The window will receive
+	/// [`wm::PowerBroadcast`](crate::msg::wm::PowerBroadcast) messages with
+	/// [`co::PBT::POWERSETTINGCHANGE`](crate::co::PBT::POWERSETTINGCHANGE) event
+	/// whenever the setting changes, and also right after the registration,
+	/// with the current value.
+	///
+	/// # Examples
+	///
+	/// Being notified when switching between AC and battery:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let _notif = hwnd.RegisterPowerSettingNotification(
+	///     &co::POWER_SETTING::ACDC_POWER_SOURCE)?; // keep guard alive
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn RegisterPowerSettingNotification(&self,
+		power_setting: &co::POWER_SETTING,
+	) -> SysResult<UnregisterPowerSettingNotificationGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::RegisterPowerSettingNotification(
+					self.ptr(),
+					power_setting as *const _ as _,
+					0, // DEVICE_NOTIFY_WINDOW_HANDLE
+				),
+			).map(|h| UnregisterPowerSettingNotificationGuard::new(h))
+		}
+	}
+
+	/// [`RemoveClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+	/// function.
+	///
+	/// # Related functions
+	///
+	/// * [`HWND::AddClipboardFormatListener`](crate::prelude::user_Hwnd::AddClipboardFormatListener)
+	fn RemoveClipboardFormatListener(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::RemoveClipboardFormatListener(self.ptr()) })
+	}
+
 	/// [`ScreenToClient`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-screentoclient)
 	/// function.
 	///
@@ -1582,6 +2210,34 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetCapture`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcapture)
 	/// function.
+	///
+	/// The returned guard calls
+	/// [`ReleaseCapture`](crate::ReleaseCapture) when dropped, so the capture
+	/// is released even if the handler returns early with an error.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// fn track_drag(hwnd: &w::HWND) -> w::SysResult<()> {
+	///     let _capture = hwnd.SetCapture(); // released at the end of scope
+	///     let pos = w::GetCursorPos()?; // early return still releases
+	///     println!("Dragging at {}", pos);
+	///     Ok(())
+	/// }
+	///
+	/// track_drag(&hwnd)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	///
+	/// If the capture must outlive the current scope, as in a drag spanning
+	/// mouse-down and mouse-up messages, forget the guard with
+	/// [`std::mem::forget`](std::mem::forget) and call
+	/// [`ReleaseCapture`](crate::ReleaseCapture) when the drag ends.
 	fn SetCapture(&self) -> ReleaseCaptureGuard<'_, Self> {
 		unsafe {
 			ReleaseCaptureGuard::new(
@@ -1651,7 +2307,10 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetParent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setparent)
 	/// function.
+	///
+	/// Returns the previous parent, if any.
 	fn SetParent(&self, hwnd_new_parent: &HWND) -> SysResult<Option<HWND>> {
+		SetLastError(co::ERROR::SUCCESS); // null return is ambiguous
 		match ptr_to_option_handle(
 			unsafe { ffi::SetParent(self.ptr(), hwnd_new_parent.ptr()) },
 		) {
@@ -1665,6 +2324,12 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetScrollInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setscrollinfo)
 	/// function.
+	///
+	/// A standard scroll bar is automatically hidden when its page covers the
+	/// whole range, so this is usually simpler than calling
+	/// [`HWND::ShowScrollBar`](crate::prelude::user_Hwnd::ShowScrollBar)
+	/// yourself. If [`co::SIF::DISABLENOSCROLL`](crate::co::SIF::DISABLENOSCROLL)
+	/// is set, the scroll bar is disabled instead of hidden.
 	fn SetScrollInfo(&self, bar: co::SBB, si: &SCROLLINFO, redraw: bool) -> i32 {
 		unsafe {
 			ffi::SetScrollInfo(
@@ -1747,7 +2412,10 @@ pub trait user_Hwnd: Handle {
 	/// accept ordinary function pointers, not closures.
 	///
 	/// Handling the `wm_timer` message is simply more practical and efficient,
-	/// so the use of a callback is discouraged here.
+	/// so the use of a callback is discouraged here. If you really need a
+	/// closure, use
+	/// [`HWND::SetTimerScoped`](crate::prelude::user_Hwnd::SetTimerScoped),
+	/// which ties the closure lifetime to a guard.
 	fn SetTimer(&self,
 		event_id: usize,
 		elapse_ms: u32,
@@ -1767,8 +2435,90 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`SetTimer`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-settimer)
+	/// function, returning a guard which calls
+	/// [`KillTimer`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-killtimer)
+	/// when the object goes out of scope.
+	///
+	/// If a closure is given, it's called at each timer tick, through an
+	/// internal `TIMERPROC`, and it's freed by the guard. Otherwise, a
+	/// [`WM_TIMER`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-timer)
+	/// message is sent to the window at each tick.
+	///
+	/// The window must not be null. The timer must be set and the guard must be
+	/// dropped in the thread which owns the window. Since the closure is not called after the guard is
+	/// dropped, it doesn't need any synchronization.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let _timer = hwnd.SetTimerScoped(1, 500, Some(Box::new(|| {
+	///     println!("Tick");
+	/// })))?;
+	///
+	/// // The timer is killed when _timer goes out of scope.
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn SetTimerScoped(&self,
+		event_id: usize,
+		elapse_ms: u32,
+		func: Option<Box<dyn Fn()>>,
+	) -> SysResult<KillTimerGuard<'_, Self>>
+	{
+		let has_func = func.is_some();
+		if let Some(func) = func {
+			proc::set_timer_func(self.ptr() as _, event_id, func);
+		}
+
+		match unsafe {
+			ffi::SetTimer(
+				self.ptr(),
+				event_id,
+				elapse_ms,
+				if has_func { proc::hwnd_timer_func as _ } else { std::ptr::null() },
+			)
+		} {
+			0 => {
+				let err = GetLastError();
+				proc::remove_timer_func(self.ptr() as _, event_id);
+				Err(err)
+			},
+			_ => Ok(unsafe { KillTimerGuard::new(self, event_id) }),
+		}
+	}
+
 	/// [`SetWindowDisplayAffinity`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowdisplayaffinity)
 	/// function.
+	///
+	/// Works only for top-level windows created by the current process, and
+	/// requires desktop composition; otherwise the function fails.
+	///
+	/// [`co::WDA::EXCLUDEFROMCAPTURE`](crate::co::WDA::EXCLUDEFROMCAPTURE)
+	/// requires Windows 10 version 2004; on earlier versions, it behaves as
+	/// [`co::WDA::MONITOR`](crate::co::WDA::MONITOR), so the window appears
+	/// black instead of hidden.
+	///
+	/// Note that this is not a security boundary: it only affects capture
+	/// APIs, not a camera pointed at the screen.
+	///
+	/// # Examples
+	///
+	/// Hiding the window from screenshots and screen recordings:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.SetWindowDisplayAffinity(co::WDA::EXCLUDEFROMCAPTURE)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn SetWindowDisplayAffinity(&self, affinity: co::WDA) -> SysResult<()> {
 		bool_to_sysresult(
 			unsafe { ffi::SetWindowDisplayAffinity(self.ptr(), affinity.raw()) },
@@ -1782,6 +2532,10 @@ pub trait user_Hwnd: Handle {
 	/// [`HWND::set_style`](crate::prelude::user_Hwnd::set_style) and
 	/// [`HWND::set_style_ex`](crate::prelude::user_Hwnd::set_style_ex).
 	///
+	/// If you want to store an object in `GWLP_USERDATA`, prefer using
+	/// [`HWND::set_user_data`](crate::prelude::user_Hwnd::set_user_data) and
+	/// [`HWND::take_user_data`](crate::prelude::user_Hwnd::take_user_data).
+	///
 	/// # Safety
 	///
 	/// Changing these values may potentially cause undefined behavior to the
@@ -1878,6 +2632,23 @@ pub trait user_Hwnd: Handle {
 		bool_to_sysresult(unsafe { ffi::ShowOwnedPopups(self.ptr(), show as _) })
 	}
 
+	/// [`ShowScrollBar`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showscrollbar)
+	/// function.
+	///
+	/// Showing or hiding a standard scroll bar changes the size of the client
+	/// area, so the window will receive a
+	/// [`wm::Size`](crate::msg::wm::Size) message, and its contents should be
+	/// laid out again.
+	///
+	/// Consider letting
+	/// [`HWND::SetScrollInfo`](crate::prelude::user_Hwnd::SetScrollInfo) hide
+	/// the scroll bar automatically, when the page covers the whole range.
+	fn ShowScrollBar(&self, bar: co::SBB, show: bool) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { ffi::ShowScrollBar(self.ptr(), bar.raw(), show as _) },
+		)
+	}
+
 	/// [`ShowWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showwindow)
 	/// function.
 	fn ShowWindow(&self, show_cmd: co::SW) -> bool {
@@ -1892,6 +2663,56 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`ShutdownBlockReasonCreate`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-shutdownblockreasoncreate)
+	/// function.
+	///
+	/// Sets the reason shown to the user when the application blocks the
+	/// system shutdown, usually by returning `false` to
+	/// [`WM_QUERYENDSESSION`](crate::msg::wm::QueryEndSession). Must be
+	/// called from the thread which created the window.
+	///
+	/// The reason should be removed with
+	/// [`HWND::ShutdownBlockReasonDestroy`](crate::prelude::user_Hwnd::ShutdownBlockReasonDestroy)
+	/// when the operation which blocks the shutdown is done.
+	fn ShutdownBlockReasonCreate(&self, reason: &str) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::ShutdownBlockReasonCreate(
+					self.ptr(),
+					WString::from_str(reason).as_ptr(),
+				)
+			},
+		)
+	}
+
+	/// [`ShutdownBlockReasonDestroy`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-shutdownblockreasondestroy)
+	/// function.
+	fn ShutdownBlockReasonDestroy(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { ffi::ShutdownBlockReasonDestroy(self.ptr()) })
+	}
+
+	/// [`ShutdownBlockReasonQuery`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-shutdownblockreasonquery)
+	/// function.
+	///
+	/// Fails if no reason was set.
+	fn ShutdownBlockReasonQuery(&self) -> SysResult<String> {
+		let mut len = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::ShutdownBlockReasonQuery(
+					self.ptr(), std::ptr::null_mut(), &mut len)
+			},
+		)?; // retrieve the needed buffer size, including terminating null
+
+		let mut buf = WString::new_alloc_buf(len as _);
+		bool_to_sysresult(
+			unsafe {
+				ffi::ShutdownBlockReasonQuery(
+					self.ptr(), buf.as_mut_ptr(), &mut len)
+			},
+		).map(|_| buf.to_string())
+	}
+
 	/// [`TileWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-tilewindows)
 	/// function.
 	fn TileWindows(&self,
@@ -2008,6 +2829,10 @@ pub trait user_Hwnd: Handle {
 
 	/// [`WindowFromPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-windowfrompoint)
 	/// function.
+	///
+	/// The point must be in **screen coordinates**, like the ones returned by
+	/// [`GetCursorPos`](crate::GetCursorPos). Hidden and disabled windows are
+	/// not retrieved.
 	#[must_use]
 	fn WindowFromPoint(pt: POINT) -> Option<HWND> {
 		ptr_to_option_handle(unsafe { ffi::WindowFromPoint(pt.x, pt.y) })
@@ -9,6 +9,8 @@ mod hinstance;
 mod hmenu;
 mod hmonitor;
 mod hprocess;
+mod hrawinput;
+mod hwineventhook;
 mod hwnd;
 
 pub mod decl {
@@ -21,6 +23,8 @@ pub mod decl {
 	pub use super::hicon::HICON;
 	pub use super::hmenu::HMENU;
 	pub use super::hmonitor::HMONITOR;
+	pub use super::hrawinput::HRAWINPUT;
+	pub use super::hwineventhook::HWINEVENTHOOK;
 	pub use super::hwnd::HWND;
 
 	impl_handle! { HBITMAP;
@@ -38,6 +42,13 @@ pub mod decl {
 		/// [palette](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hpalette).
 	}
 
+	impl_handle! { HPOWERNOTIFY;
+		/// Handle to a
+		/// [power setting notification](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerpowersettingnotification),
+		/// returned by
+		/// [`HWND::RegisterPowerSettingNotification`](crate::prelude::user_Hwnd::RegisterPowerSettingNotification).
+	}
+
 	impl_handle! { HRGN;
 		/// Handle to a
 		/// [region](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hrgn)
@@ -57,5 +68,7 @@ pub mod traits {
 	pub use super::hmenu::user_Hmenu;
 	pub use super::hmonitor::user_Hmonitor;
 	pub use super::hprocess::user_Hprocess;
+	pub use super::hrawinput::user_Hrawinput;
+	pub use super::hwineventhook::user_Hwineventhook;
 	pub use super::hwnd::user_Hwnd;
 }
@@ -156,6 +156,10 @@ pub_struct_msg_empty_handleable! { ChildActivate: co::WM::CHILDACTIVATE;
 	/// [`WM_CHILDACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 }
 
+pub_struct_msg_empty_handleable! { ClipboardUpdate: co::WM::CLIPBOARDUPDATE;
+	/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+}
+
 pub_struct_msg_empty_handleable! { Close: co::WM::CLOSE;
 	/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 }
@@ -352,6 +356,40 @@ impl<'a> MsgSendRecv for DeviceChange<'a> {
 	}
 }
 
+/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DrawItem<'a> {
+	pub control_id: u16,
+	pub drawitemstruct: &'a DRAWITEMSTRUCT,
+}
+
+impl<'a> MsgSend for DrawItem<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DRAWITEM,
+			wparam: self.control_id as _,
+			lparam: self.drawitemstruct as *const _ as _,
+		}
+	}
+}
+
+impl<'a> MsgSendRecv for DrawItem<'a> {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			control_id: p.wparam as _,
+			drawitemstruct: &*(p.lparam as *const _),
+		}
+	}
+}
+
 /// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 /// message parameters.
 ///
@@ -836,6 +874,44 @@ impl<'a> MsgSendRecv for Help<'a> {
 	}
 }
 
+/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct HotKey {
+	/// The ID given to
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey),
+	/// or `IDHOT_SNAPWINDOW` (-1) and `IDHOT_SNAPDESKTOP` (-2) for the system
+	/// hotkeys.
+	pub hotkey_id: i32,
+	pub modifiers: co::MOD,
+	pub vkey_code: co::VK,
+}
+
+impl MsgSend for HotKey {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::HOTKEY,
+			wparam: self.hotkey_id as _,
+			lparam: MAKEDWORD(self.modifiers.raw(), self.vkey_code.raw()) as _,
+		}
+	}
+}
+
+impl MsgSendRecv for HotKey {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			hotkey_id: p.wparam as _,
+			modifiers: co::MOD::from_raw(LOWORD(p.lparam as _)),
+			vkey_code: co::VK::from_raw(HIWORD(p.lparam as _)),
+		}
+	}
+}
+
 /// [`WM_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-hscroll)
 /// message parameters.
 ///
@@ -945,6 +1021,40 @@ impl MsgSendRecv for InitMenuPopup {
 	}
 }
 
+/// [`WM_INPUT`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-input)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct Input {
+	pub input_code: co::RIM,
+	pub hrawinput: HRAWINPUT,
+}
+
+impl MsgSend for Input {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::INPUT,
+			wparam: self.input_code.raw() as _,
+			lparam: self.hrawinput.ptr() as _,
+		}
+	}
+}
+
+impl MsgSendRecv for Input {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			input_code: unsafe { co::RIM::from_raw((p.wparam & 0xff) as _) },
+			hrawinput: HRAWINPUT::from_ptr(p.lparam as _),
+		}
+	}
+}
+
 pub_struct_msg_char_key! { KeyDown: co::WM::KEYDOWN;
 	/// [`WM_KEYDOWN`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-keydown)
 }
@@ -1015,6 +1125,9 @@ pub_struct_msg_button! { MButtonUp: co::WM::MBUTTONUP;
 /// [`WM_MENUCOMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-menucommand)
 /// message parameters.
 ///
+/// Sent only for menus with the
+/// [`MNS::NOTIFYBYPOS`](crate::co::MNS::NOTIFYBYPOS) style.
+///
 /// Return type: `()`.
 pub struct MenuCommand {
 	pub item_index: u32,
@@ -1121,6 +1234,10 @@ pub_struct_msg_button! { MouseHover: co::WM::MOUSEHOVER;
 /// [`WM_MOUSEHWHEEL`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousehwheel)
 /// message parameters.
 ///
+/// The `wheel_distance` is expressed in multiples of `WHEEL_DELTA`, which is
+/// 120; high-resolution wheels may send smaller values. The `coords` are in
+/// screen coordinates.
+///
 /// Return type: `()`.
 pub struct MouseHWheel {
 	pub wheel_distance: i16,
@@ -1138,8 +1255,8 @@ impl MsgSend for MouseHWheel {
 	fn as_generic_wm(&mut self) -> WndMsg {
 		WndMsg {
 			msg_id: co::WM::MOUSEHWHEEL,
-			wparam: MAKEDWORD(self.wheel_distance as _, self.keys.raw()) as _,
-			lparam: u32::from(self.coords) as _,
+			wparam: MAKEDWORD(self.keys.raw(), self.wheel_distance as _) as _,
+			lparam: MAKEDWORD(self.coords.x as _, self.coords.y as _) as _,
 		}
 	}
 }
@@ -1147,9 +1264,12 @@ impl MsgSend for MouseHWheel {
 impl MsgSendRecv for MouseHWheel {
 	unsafe fn from_generic_wm(p: WndMsg) -> Self {
 		Self {
-			wheel_distance: LOWORD(p.wparam as _) as _,
-			keys: co::MK::from_raw(HIWORD(p.wparam as _)),
-			coords: POINT::from(p.lparam as u32),
+			wheel_distance: HIWORD(p.wparam as _) as _,
+			keys: co::MK::from_raw(LOWORD(p.wparam as _)),
+			coords: POINT::new(
+				LOWORD(p.lparam as _) as i16 as _, // screen coordinates may be negative
+				HIWORD(p.lparam as _) as i16 as _,
+			),
 		}
 	}
 }
@@ -1165,6 +1285,10 @@ pub_struct_msg_button! { MouseMove: co::WM::MOUSEMOVE;
 /// [`WM_MOUSEWHEEL`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel)
 /// message parameters.
 ///
+/// The `wheel_distance` is expressed in multiples of `WHEEL_DELTA`, which is
+/// 120; high-resolution wheels may send smaller values. The `coords` are in
+/// screen coordinates.
+///
 /// Return type: `()`.
 pub struct MouseWheel {
 	pub wheel_distance: i16,
@@ -1182,8 +1306,8 @@ impl MsgSend for MouseWheel {
 	fn as_generic_wm(&mut self) -> WndMsg {
 		WndMsg {
 			msg_id: co::WM::MOUSEWHEEL,
-			wparam: MAKEDWORD(self.wheel_distance as _, self.keys.raw()) as _,
-			lparam: u32::from(self.coords) as _,
+			wparam: MAKEDWORD(self.keys.raw(), self.wheel_distance as _) as _,
+			lparam: MAKEDWORD(self.coords.x as _, self.coords.y as _) as _,
 		}
 	}
 }
@@ -1191,9 +1315,12 @@ impl MsgSend for MouseWheel {
 impl MsgSendRecv for MouseWheel {
 	unsafe fn from_generic_wm(p: WndMsg) -> Self {
 		Self {
-			wheel_distance: LOWORD(p.wparam as _) as _,
-			keys: co::MK::from_raw(HIWORD(p.wparam as _)),
-			coords: POINT::from(p.lparam as u32),
+			wheel_distance: HIWORD(p.wparam as _) as _,
+			keys: co::MK::from_raw(LOWORD(p.wparam as _)),
+			coords: POINT::new(
+				LOWORD(p.lparam as _) as i16 as _, // screen coordinates may be negative
+				HIWORD(p.lparam as _) as i16 as _,
+			),
 		}
 	}
 }
@@ -1496,6 +1623,38 @@ impl<'a> MsgSendRecv for PowerBroadcast<'a> {
 	}
 }
 
+/// [`WM_QUERYENDSESSION`](https://learn.microsoft.com/en-us/windows/win32/shutdown/wm-queryendsession)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct QueryEndSession {
+	pub event: co::ENDSESSION,
+}
+
+impl MsgSend for QueryEndSession {
+	type RetType = bool;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::QUERYENDSESSION,
+			wparam: 0,
+			lparam: self.event.raw() as _,
+		}
+	}
+}
+
+impl MsgSendRecv for QueryEndSession {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			event: co::ENDSESSION::from_raw(p.lparam as _),
+		}
+	}
+}
+
 /// [`WM_QUERYOPEN`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-queryopen)
 /// message, which has no parameters.
 ///
@@ -2128,7 +2287,7 @@ impl<'a> MsgSendRecv for WindowPosChanged<'a> {
 ///
 /// Return type: `()`.
 pub struct WindowPosChanging<'a> {
-	pub windowpos: &'a WINDOWPOS,
+	pub windowpos: &'a mut WINDOWPOS,
 }
 
 impl<'a> MsgSend for WindowPosChanging<'a> {
@@ -2142,7 +2301,7 @@ impl<'a> MsgSend for WindowPosChanging<'a> {
 		WndMsg {
 			msg_id: co::WM::WINDOWPOSCHANGING,
 			wparam: 0,
-			lparam: self.windowpos as *const _ as _,
+			lparam: self.windowpos as *mut _ as _,
 		}
 	}
 }
@@ -2150,7 +2309,7 @@ impl<'a> MsgSend for WindowPosChanging<'a> {
 impl<'a> MsgSendRecv for WindowPosChanging<'a> {
 	unsafe fn from_generic_wm(p: WndMsg) -> Self {
 		Self {
-			windowpos: &*(p.lparam as *const _),
+			windowpos: &mut *(p.lparam as *mut _),
 		}
 	}
 }
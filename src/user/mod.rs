@@ -7,6 +7,7 @@ mod handles;
 mod msg_traits;
 mod proc;
 mod structs;
+mod utilities;
 
 pub(in crate::user) mod ffi;
 pub(in crate::user) mod iterators;
@@ -21,6 +22,7 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
@@ -9,6 +9,42 @@ use crate::msg::*;
 /// Used in functions like
 /// [`SendMessage`](crate::prelude::user_Hwnd::SendMessage) and
 /// [`DefWindowProc`](crate::prelude::user_Hwnd::DefWindowProc).
+///
+/// # Examples
+///
+/// You can implement this trait for your own structs, so messages not modeled
+/// by the library – like custom control messages – can be sent with type-safe
+/// parameters and return value:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, msg};
+///
+/// /// Custom message which receives a number and returns a bool.
+/// struct MyMsg {
+///     pub value: u32,
+/// }
+///
+/// impl MsgSend for MyMsg {
+///     type RetType = bool;
+///
+///     unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+///         v != 0
+///     }
+///
+///     fn as_generic_wm(&mut self) -> msg::WndMsg {
+///         msg::WndMsg {
+///             msg_id: unsafe { co::WM::from_raw(co::WM::USER.raw() + 10) },
+///             wparam: self.value as _,
+///             lparam: 0,
+///         }
+///     }
+/// }
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let ok: bool = unsafe { hwnd.SendMessage(MyMsg { value: 42 }) };
+/// ```
 pub trait MsgSend {
 	/// The specific type of the value returned by the message.
 	type RetType;
@@ -9,12 +9,14 @@ const_values_num_privs! {
 	CB_ERRSPACE i32 = -2
 	CCHDEVICENAME usize = 32
 	CCHFORMNAME usize = 32
+	CCHILDREN_SCROLLBAR usize = 5
 	CCHILDREN_TITLEBAR usize = 5
 	DM_SPECVERSION u16 = 0x0401
 	FAPPCOMMAND_MASK u16 = 0xf000
 	HWND_MESSAGE isize = -3
 	LB_ERR i32 = -1
 	LB_ERRSPACE i32 = -2
+	RID_INPUT u32 = 0x1000_0003
 	WC_DIALOG u16 = 0x8002
 }
 
@@ -1,5 +1,12 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
+use crate::msg::*;
+use crate::prelude::*;
 
 pub(in crate::user) extern "system" fn func_enum_thread_wnd<F>(
 	hwnd: HWND,
@@ -42,3 +49,184 @@ pub(in crate::user) extern "system" fn hwnd_enum_child_windows<F>(
 	let func = unsafe { &mut *(lparam as *mut F) };
 	func(hwnd) as _
 }
+
+/// Timer closure, along with the `HWND` pointer and the timer ID.
+type TimerFunc = (usize, usize, Rc<dyn Fn()>);
+
+thread_local! {
+	/// Closures of the timers set with `SetTimerScoped`, keyed by the `HWND`
+	/// pointer and the timer ID. Timers run in the thread which owns the window,
+	/// so the closures don't need to be `Send`.
+	static TIMER_FUNCS: RefCell<Vec<TimerFunc>> = RefCell::new(Vec::new());
+}
+
+/// Stores the timer closure, replacing any closure with the same timer ID.
+pub(in crate::user) fn set_timer_func(
+	hwnd_ptr: usize,
+	event_id: usize,
+	func: Box<dyn Fn()>,
+)
+{
+	TIMER_FUNCS.with_borrow_mut(|funcs| {
+		funcs.retain(|(ptr, id, _)| *ptr != hwnd_ptr || *id != event_id);
+		funcs.push((hwnd_ptr, event_id, Rc::from(func)));
+	});
+}
+
+/// Removes the timer closure, if any.
+pub(in crate::user) fn remove_timer_func(hwnd_ptr: usize, event_id: usize) {
+	let removed = TIMER_FUNCS.with_borrow_mut(|funcs| {
+		funcs.iter()
+			.position(|(ptr, id, _)| *ptr == hwnd_ptr && *id == event_id)
+			.map(|idx| funcs.remove(idx))
+	});
+	drop(removed); // closure freed outside the borrow, it may own other guards
+}
+
+pub(in crate::user) extern "system" fn hwnd_timer_func(
+	hwnd: HWND,
+	_: co::WM,
+	event_id: usize,
+	_: u32,
+)
+{
+	let func = TIMER_FUNCS.with_borrow(|funcs| {
+		funcs.iter()
+			.find(|(ptr, id, _)| *ptr == hwnd.ptr() as usize && *id == event_id)
+			.map(|(_, _, func)| func.clone()) // the closure may kill its own timer
+	});
+	if let Some(func) = func {
+		func();
+	}
+}
+
+/// Closure of an event hook set with `WinEventHook`, along with the
+/// `HWINEVENTHOOK` pointer.
+type WinEventFunc = (usize, Rc<dyn Fn(co::EVENT, HWND, co::OBJID, i32)>);
+
+thread_local! {
+	/// Closures of the event hooks. Out-of-context hooks are called in the
+	/// thread which installed them, so the closures don't need to be `Send`.
+	static WIN_EVENT_FUNCS: RefCell<Vec<WinEventFunc>> = RefCell::new(Vec::new());
+}
+
+/// Stores the event hook closure.
+pub(in crate::user) fn set_win_event_func(
+	hhook_ptr: usize,
+	func: Box<dyn Fn(co::EVENT, HWND, co::OBJID, i32)>,
+)
+{
+	WIN_EVENT_FUNCS.with_borrow_mut(|funcs| {
+		funcs.retain(|(ptr, _)| *ptr != hhook_ptr);
+		funcs.push((hhook_ptr, Rc::from(func)));
+	});
+}
+
+/// Removes the event hook closure, if any.
+pub(in crate::user) fn remove_win_event_func(hhook_ptr: usize) {
+	let removed = WIN_EVENT_FUNCS.with_borrow_mut(|funcs| {
+		funcs.iter()
+			.position(|(ptr, _)| *ptr == hhook_ptr)
+			.map(|idx| funcs.remove(idx))
+	});
+	drop(removed); // closure freed outside the borrow, it may own other guards
+}
+
+pub(in crate::user) extern "system" fn win_event_proc(
+	hhook: HWINEVENTHOOK,
+	event: co::EVENT,
+	hwnd: HWND,
+	id_object: co::OBJID,
+	id_child: i32,
+	_: u32,
+	_: u32,
+) {
+	let func = WIN_EVENT_FUNCS.with_borrow(|funcs| {
+		funcs.iter()
+			.find(|(ptr, _)| *ptr == hhook.ptr() as usize)
+			.map(|(_, func)| func.clone()) // the closure may drop its own hook
+	});
+	if let Some(func) = func {
+		func(event, hwnd, id_object, id_child);
+	}
+}
+
+/// Fade animations in progress, keyed by the `HWND` pointer.
+static FADES: Mutex<Vec<(usize, FadeState)>> = Mutex::new(Vec::new());
+
+struct FadeState {
+	start_ms: u64,
+	duration_ms: u32,
+	fade_in: bool,
+	then_close: bool,
+}
+
+/// Timer ID used by the fade animations.
+const FADE_TIMER_ID: usize = 0x5746_4144;
+
+/// Starts a fade animation, replacing any animation already running in the
+/// window.
+pub(in crate::user) fn start_fade(
+	hwnd: &HWND,
+	duration_ms: u32,
+	fade_in: bool,
+	then_close: bool,
+) -> SysResult<()>
+{
+	hwnd.set_style_ex(hwnd.style_ex() | co::WS_EX::LAYERED);
+	hwnd.SetLayeredWindowAttributes(COLORREF::new(0, 0, 0),
+		if fade_in { 0 } else { 255 }, co::LWA::ALPHA)?;
+	if fade_in && !hwnd.IsWindowVisible() {
+		hwnd.ShowWindow(co::SW::SHOW);
+	}
+
+	{
+		let mut fades = FADES.lock().unwrap();
+		fades.retain(|(ptr, _)| // remove windows destroyed mid-fade
+			*ptr != hwnd.ptr() as usize && unsafe { HWND::from_ptr(*ptr as _) }.IsWindow());
+		fades.push((hwnd.ptr() as _, FadeState {
+			start_ms: GetTickCount64(),
+			duration_ms,
+			fade_in,
+			then_close,
+		}));
+	}
+
+	hwnd.SetTimer(FADE_TIMER_ID, 15, Some(hwnd_fade_timer)).map(|_| ())
+}
+
+extern "system" fn hwnd_fade_timer(hwnd: HWND, _: co::WM, _: usize, _: u32) {
+	let mut fades = FADES.lock().unwrap();
+	let idx = match fades.iter().position(|(ptr, _)| *ptr == hwnd.ptr() as usize) {
+		Some(idx) => idx,
+		None => { // should never happen
+			hwnd.KillTimer(FADE_TIMER_ID).ok();
+			return;
+		},
+	};
+
+	let state = &fades[idx].1;
+	let elapsed = GetTickCount64() - state.start_ms;
+	let done = elapsed >= state.duration_ms as u64;
+	let progress = if done { 255 } else { (elapsed * 255 / state.duration_ms as u64) as u8 };
+	let alpha = if state.fade_in { progress } else { 255 - progress };
+
+	if !done {
+		hwnd.SetLayeredWindowAttributes(COLORREF::new(0, 0, 0), alpha, co::LWA::ALPHA).ok();
+		return;
+	}
+
+	let state = fades.remove(idx).1;
+	drop(fades);
+	hwnd.KillTimer(FADE_TIMER_ID).ok();
+
+	if state.fade_in {
+		hwnd.SetLayeredWindowAttributes(COLORREF::new(0, 0, 0), 255, co::LWA::ALPHA).ok();
+	} else if state.then_close {
+		hwnd.SetLayeredWindowAttributes(COLORREF::new(0, 0, 0), 0, co::LWA::ALPHA).ok();
+		unsafe { hwnd.PostMessage(wm::Close {}).ok(); } // only after the last tick
+	} else {
+		hwnd.ShowWindow(co::SW::HIDE); // hide, then restore opacity for next show
+		hwnd.SetLayeredWindowAttributes(COLORREF::new(0, 0, 0), 255, co::LWA::ALPHA).ok();
+	}
+}
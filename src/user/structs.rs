@@ -35,6 +35,20 @@ pub struct ALTTABINFO {
 
 impl_default_with_size!(ALTTABINFO, cbSize);
 
+/// [`ANIMATIONINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-animationinfo)
+/// struct.
+#[repr(C)]
+pub struct ANIMATIONINFO {
+	cbSize: u32,
+	iMinAnimate: i32,
+}
+
+impl_default_with_size!(ANIMATIONINFO, cbSize);
+
+impl ANIMATIONINFO {
+	pub_fn_bool_get_set!(iMinAnimate, set_iMinAnimate);
+}
+
 /// [`ATOM`](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#atom)
 /// returned by [`RegisterClassEx`](crate::RegisterClassEx).
 #[repr(transparent)]
@@ -84,6 +98,57 @@ impl Default for BLENDFUNCTION {
 	}
 }
 
+impl BLENDFUNCTION {
+	/// Creates a new `BLENDFUNCTION` with the
+	/// [`co::AC_SRC::OVER`](crate::co::AC_SRC::OVER) operation.
+	///
+	/// `source_constant_alpha` is applied to the whole source bitmap, from `0`
+	/// (transparent) to `255` (opaque). If `per_pixel_alpha` is `true`, the
+	/// alpha channel of the source bitmap is also used, so it must be a 32-bit
+	/// bitmap with premultiplied alpha.
+	///
+	/// # Examples
+	///
+	/// A half-transparent overlay:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w};
+	///
+	/// let bf = w::BLENDFUNCTION::new(128, false);
+	/// ```
+	#[must_use]
+	pub const fn new(source_constant_alpha: u8, per_pixel_alpha: bool) -> Self {
+		Self {
+			BlendOp: co::AC_SRC::OVER,
+			BlendFlags: 0,
+			SourceConstantAlpha: source_constant_alpha,
+			AlphaFormat: if per_pixel_alpha {
+				co::AC_SRC::ALPHA
+			} else {
+				co::AC_SRC::OVER
+			},
+		}
+	}
+
+	/// Tells whether the `AlphaFormat` member is
+	/// [`co::AC_SRC::ALPHA`](crate::co::AC_SRC::ALPHA), that is, the alpha
+	/// channel of the source bitmap is used.
+	#[must_use]
+	pub fn per_pixel_alpha(&self) -> bool {
+		self.AlphaFormat == co::AC_SRC::ALPHA
+	}
+
+	/// Sets the `AlphaFormat` member to
+	/// [`co::AC_SRC::ALPHA`](crate::co::AC_SRC::ALPHA) or zero.
+	pub fn set_per_pixel_alpha(&mut self, per_pixel_alpha: bool) {
+		self.AlphaFormat = if per_pixel_alpha {
+			co::AC_SRC::ALPHA
+		} else {
+			co::AC_SRC::OVER
+		};
+	}
+}
+
 /// [`CHOOSECOLOR`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosecolorw-r1)
 /// struct.
 #[repr(C)]
@@ -935,6 +1000,76 @@ impl POINT {
 	}
 }
 
+/// [`RAWINPUTDEVICE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputdevice)
+/// struct.
+#[repr(C)]
+pub struct RAWINPUTDEVICE {
+	pub usUsagePage: u16,
+	pub usUsage: u16,
+	pub dwFlags: co::RIDEV,
+	pub hwndTarget: HWND,
+}
+
+impl_default!(RAWINPUTDEVICE);
+
+/// [`RAWINPUTHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputheader)
+/// struct.
+///
+/// The `hDevice` field identifies the device which generated the input, and
+/// can be used to distinguish between multiple devices of the same type.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RAWINPUTHEADER {
+	pub dwType: co::RIM_TYPE,
+	pub dwSize: u32,
+	pub hDevice: HANDLE,
+	pub wParam: usize,
+}
+
+impl_default!(RAWINPUTHEADER);
+
+/// [`RAWKEYBOARD`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawkeyboard)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RAWKEYBOARD {
+	pub MakeCode: u16,
+	pub Flags: co::RI_KEY,
+	Reserved: u16,
+	pub VKey: co::VK,
+	pub Message: co::WM,
+	pub ExtraInformation: u32,
+}
+
+/// [`RAWMOUSE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawmouse)
+/// struct.
+///
+/// If `usFlags` has [`co::MOUSE_STATE::MOVE_ABSOLUTE`](crate::co::MOUSE_STATE::MOVE_ABSOLUTE),
+/// `lLastX` and `lLastY` are absolute coordinates, normalized from 0 to
+/// 65,535; otherwise they're the relative motion since the last event.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct RAWMOUSE {
+	pub usFlags: co::MOUSE_STATE,
+	padding: u16, // union with ulButtons is 4-byte aligned
+	pub usButtonFlags: co::RI_MOUSE,
+	pub usButtonData: u16,
+	pub ulRawButtons: u32,
+	pub lLastX: i32,
+	pub lLastY: i32,
+	pub ulExtraInformation: u32,
+}
+
+impl RAWMOUSE {
+	/// Returns the wheel delta, if `usButtonFlags` has
+	/// [`co::RI_MOUSE::WHEEL`](crate::co::RI_MOUSE::WHEEL) or
+	/// [`co::RI_MOUSE::HWHEEL`](crate::co::RI_MOUSE::HWHEEL).
+	#[must_use]
+	pub const fn wheel_delta(&self) -> i16 {
+		self.usButtonData as _
+	}
+}
+
 /// [`RECT`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ns-windef-rect)
 /// struct.
 #[repr(C)]
@@ -953,6 +1088,22 @@ impl std::fmt::Display for RECT {
 	}
 }
 
+/// [`SCROLLBARINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-scrollbarinfo)
+/// struct.
+#[repr(C)]
+#[derive(Clone)]
+pub struct SCROLLBARINFO {
+	cbSize: u32,
+	pub rcScrollBar: RECT,
+	pub dxyLineButton: i32,
+	pub xyThumbTop: i32,
+	pub xyThumbBottom: i32,
+	reserved: i32,
+	pub rgstate: [co::STATE_SYSTEM; CCHILDREN_SCROLLBAR + 1],
+}
+
+impl_default_with_size!(SCROLLBARINFO, cbSize);
+
 /// [`SCROLLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-scrollinfo)
 /// struct.
 #[repr(C)]
@@ -0,0 +1,88 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Builds an
+/// [accelerator table](https://learn.microsoft.com/en-us/windows/win32/menurc/keyboard-accelerators)
+/// out of [`ACCEL`](crate::ACCEL) entries, which is then created with
+/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable).
+///
+/// The resulting guard can be directly passed to
+/// [`WindowMainOpts::accel_table`](crate::gui::WindowMainOpts::accel_table).
+///
+/// # Examples
+///
+/// Ctrl+S mapped to command 1001, and Ctrl+Shift+S mapped to command 1002:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let haccel = w::Accelerators::new()
+///     .key(co::ACCELF::CONTROL, co::VK::CHAR_S, 1001)
+///     .key(co::ACCELF::CONTROL | co::ACCELF::SHIFT, co::VK::CHAR_S, 1002)
+///     .build()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Default, Clone)]
+pub struct Accelerators {
+	accels: Vec<ACCEL>,
+}
+
+impl Accelerators {
+	/// Creates a new, empty accelerator table builder.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds an accelerator for a character code.
+	///
+	/// For character accelerators, only
+	/// [`co::ACCELF::ALT`](crate::co::ACCELF::ALT) is meaningful as a
+	/// modifier; pass [`co::ACCELF::default()`](crate::co::ACCELF) for none.
+	///
+	/// # Panics
+	///
+	/// Panics if the character is outside the
+	/// [Basic Multilingual Plane](https://en.wikipedia.org/wiki/Plane_(Unicode)#Basic_Multilingual_Plane),
+	/// since it cannot be represented in a single UTF-16 unit.
+	#[must_use]
+	pub fn char(mut self, ch: char, mods: co::ACCELF, cmd_id: u16) -> Self {
+		let ch = u16::try_from(ch as u32)
+			.expect("Accelerator character must be in the Basic Multilingual Plane.");
+		self.accels.push(ACCEL {
+			fVirt: mods & !co::ACCELF::VIRTKEY,
+			key: unsafe { co::VK::from_raw(ch) },
+			cmd: cmd_id,
+		});
+		self
+	}
+
+	/// Adds an accelerator for a virtual key, with the given modifiers.
+	///
+	/// The [`co::ACCELF::VIRTKEY`](crate::co::ACCELF::VIRTKEY) flag is
+	/// automatically added.
+	#[must_use]
+	pub fn key(mut self, mods: co::ACCELF, vk: co::VK, cmd_id: u16) -> Self {
+		self.accels.push(ACCEL {
+			fVirt: mods | co::ACCELF::VIRTKEY,
+			key: vk,
+			cmd: cmd_id,
+		});
+		self
+	}
+
+	/// Returns the [`ACCEL`](crate::ACCEL) entries added so far.
+	#[must_use]
+	pub fn as_slice(&self) -> &[ACCEL] {
+		&self.accels
+	}
+
+	/// Creates the accelerator table by calling
+	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable).
+	#[must_use]
+	pub fn build(&self) -> SysResult<DestroyAcceleratorTableGuard> {
+		HACCEL::CreateAcceleratorTable(&self.accels)
+	}
+}
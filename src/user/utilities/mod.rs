@@ -0,0 +1,5 @@
+mod accelerators;
+mod win_event_hook;
+
+pub use accelerators::Accelerators;
+pub use win_event_hook::WinEventHook;
@@ -0,0 +1,93 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+use crate::user::proc;
+
+/// Installs an out-of-context
+/// [event hook](https://learn.microsoft.com/en-us/windows/win32/winauto/event-hook-functions)
+/// with
+/// [`HWINEVENTHOOK::SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook),
+/// calling a closure for each event raised by any process.
+///
+/// The closure receives the event, the window which generated it – if any –
+/// the object ID and the child ID. It's called in the thread which installed
+/// the hook, so this thread must run a message loop. The hook is removed when
+/// the object goes out of scope.
+///
+/// # Examples
+///
+/// Tracking the foreground window:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _hook = w::WinEventHook::new(
+///     co::EVENT::SYSTEM_FOREGROUND,
+///     co::EVENT::SYSTEM_FOREGROUND,
+///     |_event, hwnd, _id_object, _id_child| {
+///         if let Ok(title) = hwnd.GetWindowText() {
+///             println!("Foreground: {}", title);
+///         }
+///     },
+/// )?; // keep the hook alive while the message loop runs
+/// # w::SysResult::Ok(())
+/// ```
+pub struct WinEventHook {
+	hhook: UnhookWinEventGuard,
+}
+
+impl Drop for WinEventHook {
+	fn drop(&mut self) {
+		proc::remove_win_event_func(self.hhook.ptr() as _);
+	}
+}
+
+impl WinEventHook {
+	/// Installs the hook for all events from `event_min` to `event_max`, from
+	/// all processes, except the current one.
+	///
+	/// # Examples
+	///
+	/// Catching the creation and destruction of windows:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let _hook = w::WinEventHook::new(
+	///     co::EVENT::OBJECT_CREATE,
+	///     co::EVENT::OBJECT_DESTROY,
+	///     |event, hwnd, id_object, _| {
+	///         if id_object == co::OBJID::WINDOW {
+	///             println!("{} {}", event, hwnd);
+	///         }
+	///     },
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn new<F>(
+		event_min: co::EVENT,
+		event_max: co::EVENT,
+		func: F,
+	) -> SysResult<Self>
+		where F: Fn(co::EVENT, HWND, co::OBJID, i32) + 'static,
+	{
+		let hhook = HWINEVENTHOOK::SetWinEventHook(
+			event_min,
+			event_max,
+			None,
+			proc::win_event_proc,
+			None,
+			None,
+			co::WINEVENT::OUTOFCONTEXT | co::WINEVENT::SKIPOWNPROCESS,
+		)?;
+		proc::set_win_event_func(hhook.ptr() as _, Box::new(func));
+		Ok(Self { hhook })
+	}
+
+	/// Returns the underlying hook handle.
+	#[must_use]
+	pub fn hhook(&self) -> &HWINEVENTHOOK {
+		&self.hhook
+	}
+}
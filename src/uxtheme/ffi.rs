@@ -8,6 +8,8 @@ extern_sys! { "uxtheme";
 extern_sys! { "uxtheme";
 	CloseThemeData(HANDLE) -> HRES
 	DrawThemeBackground(HANDLE, HANDLE, i32, i32, PCVOID, PCVOID) -> HRES
+	DrawThemeParentBackground(HANDLE, HANDLE, PCVOID) -> HRES
+	DrawThemeText(HANDLE, HANDLE, i32, i32, PCSTR, i32, u32, u32, PCVOID) -> HRES
 	GetThemeAppProperties() -> u32
 	GetThemeBackgroundContentRect(HANDLE, HANDLE, i32, i32, PCVOID, PVOID) -> HRES
 	GetThemeBackgroundExtent(HANDLE, HANDLE, i32, i32, PCVOID, PVOID) -> HRES
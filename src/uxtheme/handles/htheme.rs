@@ -49,6 +49,34 @@ pub trait uxtheme_Htheme: Handle {
 		)
 	}
 
+	/// [`DrawThemeText`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-drawthemetext)
+	/// function.
+	fn DrawThemeText(&self,
+		hdc: &HDC,
+		part_state: co::VS,
+		text: &str,
+		format: co::DT,
+		rc: RECT,
+	) -> HrResult<()>
+	{
+		let wtext = WString::from_str(text);
+		ok_to_hrresult(
+			unsafe {
+				ffi::DrawThemeText(
+					self.ptr(),
+					hdc.ptr(),
+					part_state.part,
+					part_state.state,
+					wtext.as_ptr(),
+					wtext.str_len() as _,
+					format.raw(),
+					0,
+					&rc as *const _ as _,
+				)
+			},
+		)
+	}
+
 	/// [`GetThemeAppProperties`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemeappproperties)
 	/// function.
 	#[must_use]
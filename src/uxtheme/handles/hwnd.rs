@@ -17,8 +17,66 @@ impl uxtheme_Hwnd for HWND {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait uxtheme_Hwnd: ole_Hwnd {
+	/// [`DrawThemeParentBackground`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-drawthemeparentbackground)
+	/// function.
+	///
+	/// Usually called before drawing a themed part which is partially
+	/// transparent, as reported by
+	/// [`HTHEME::IsThemeBackgroundPartiallyTransparent`](crate::prelude::uxtheme_Htheme::IsThemeBackgroundPartiallyTransparent).
+	fn DrawThemeParentBackground(&self,
+		hdc: &HDC,
+		rc: Option<&RECT>,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				ffi::DrawThemeParentBackground(
+					self.ptr(),
+					hdc.ptr(),
+					rc.map_or(std::ptr::null(), |rc| rc as *const _ as _),
+				)
+			},
+		)
+	}
+
 	/// [`OpenThemeData`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-openthemedata)
 	/// function.
+	///
+	/// Returns `None` if visual styles are disabled, or if the class has no
+	/// theme data. In this case, you should draw with the classic GDI
+	/// functions.
+	///
+	/// # Examples
+	///
+	/// Drawing a themed push button inside a `WM_PAINT` handler, falling back
+	/// to a classic look:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let hdc = hwnd.BeginPaint()?;
+	/// let rc = hwnd.GetClientRect()?;
+	///
+	/// match hwnd.OpenThemeData("BUTTON") {
+	///     Some(htheme) => {
+	///         if htheme.IsThemeBackgroundPartiallyTransparent(co::VS::BUTTON_PUSHBUTTON_NORMAL) {
+	///             hwnd.DrawThemeParentBackground(&hdc, Some(&rc))?;
+	///         }
+	///         htheme.DrawThemeBackground(&hdc, co::VS::BUTTON_PUSHBUTTON_NORMAL, rc, None)?;
+	///         htheme.DrawThemeText(&hdc, co::VS::BUTTON_PUSHBUTTON_NORMAL, "Click me",
+	///             co::DT::CENTER | co::DT::VCENTER | co::DT::SINGLELINE, rc)?;
+	///     },
+	///     None => { // visual styles disabled
+	///         hdc.FillRect(rc, &w::HBRUSH::GetSysColorBrush(co::COLOR::BTNFACE)?)?;
+	///         hdc.DrawText("Click me", rc,
+	///             co::DT::CENTER | co::DT::VCENTER | co::DT::SINGLELINE)?;
+	///     },
+	/// }
+	/// # w::AnyResult::Ok(())
+	/// ```
 	#[must_use]
 	fn OpenThemeData(&self, class_list: &str) -> Option<CloseThemeDataGuard> {
 		unsafe {
@@ -31,6 +31,9 @@ pub trait version_Hversioninfo: Handle {
 	///
 	/// The returned buffer will be automatically allocated with
 	/// [`HVERSIONINFO::GetFileVersionInfoSize`](crate::prelude::version_Hversioninfo::GetFileVersionInfoSize).
+	///
+	/// For a simpler way to read the version numbers and the string values, see
+	/// [`FileVersion`](crate::FileVersion).
 	#[must_use]
 	fn GetFileVersionInfo(file_name: &str) -> SysResult<VersionInfoGuard> {
 		let block_sz = Self::GetFileVersionInfoSize(file_name)?;
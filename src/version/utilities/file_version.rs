@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// High-level access to the
+/// [version information](https://learn.microsoft.com/en-us/windows/win32/menurc/version-information)
+/// of an executable or DLL, loaded with
+/// [`HVERSIONINFO::GetFileVersionInfo`](crate::prelude::version_Hversioninfo::GetFileVersionInfo).
+///
+/// The string values are stored in blocks, one for each language and code page
+/// pair. When no pair is specified, the first block in the file is used.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let ver = w::FileVersion::from_path("C:\\Windows\\System32\\kernel32.dll")?;
+/// let (major, minor, build, rev) = ver.file_version;
+///
+/// println!("{}.{}.{}.{}", major, minor, build, rev);
+/// println!("{}", ver.string(None, "CompanyName").unwrap_or_default());
+/// # w::SysResult::Ok(())
+/// ```
+pub struct FileVersion {
+	/// The `dwFileVersionMS` and `dwFileVersionLS` fields of
+	/// [`VS_FIXEDFILEINFO`](crate::VS_FIXEDFILEINFO), or zeros if the file
+	/// has no fixed version block.
+	pub file_version: (u16, u16, u16, u16),
+	/// The `dwProductVersionMS` and `dwProductVersionLS` fields of
+	/// [`VS_FIXEDFILEINFO`](crate::VS_FIXEDFILEINFO), or zeros if the file
+	/// has no fixed version block.
+	pub product_version: (u16, u16, u16, u16),
+	hversion: VersionInfoGuard,
+}
+
+impl FileVersion {
+	/// Loads the version information of the given file.
+	///
+	/// If the file has no version information, fails with
+	/// [`co::ERROR::RESOURCE_TYPE_NOT_FOUND`](crate::co::ERROR::RESOURCE_TYPE_NOT_FOUND).
+	pub fn from_path(exe_path: &str) -> SysResult<Self> {
+		let hversion = HVERSIONINFO::GetFileVersionInfo(exe_path)?;
+
+		let (file_version, product_version) = match unsafe {
+			hversion.VerQueryValue::<VS_FIXEDFILEINFO>("\\")
+		} {
+			Ok((pvsf, sz)) if sz as usize >= std::mem::size_of::<VS_FIXEDFILEINFO>() => {
+				let vsf = unsafe { &*pvsf };
+				(to_tuple(vsf.dwFileVersion()), to_tuple(vsf.dwProductVersion()))
+			},
+			_ => ((0, 0, 0, 0), (0, 0, 0, 0)), // no fixed version block
+		};
+
+		Ok(Self { file_version, product_version, hversion })
+	}
+
+	/// Returns the underlying version information block.
+	#[must_use]
+	pub fn hversion(&self) -> &HVERSIONINFO {
+		&self.hversion
+	}
+
+	/// Returns the language and code page pairs declared in the file, by
+	/// calling
+	/// [`HVERSIONINFO::langs_and_cps`](crate::prelude::version_Hversioninfo::langs_and_cps).
+	#[must_use]
+	pub fn langs_and_cps(&self) -> Vec<(LANGID, co::CP)> {
+		self.hversion.langs_and_cps()
+			.map(|pairs| pairs.to_vec())
+			.unwrap_or_default()
+	}
+
+	/// Returns the string value with the given key – like `ProductName` or
+	/// `CompanyName` – from the block of the given language and code page, or
+	/// from the first block if `None`.
+	///
+	/// Returns `None` if the block or the key doesn't exist.
+	#[must_use]
+	pub fn string(&self,
+		lang_codepage: Option<(LANGID, co::CP)>,
+		key: &str,
+	) -> Option<String>
+	{
+		let block_key = self.block_key(lang_codepage)?;
+		unsafe {
+			self.hversion.VerQueryValue::<u16>(
+				&format!("\\StringFileInfo\\{}\\{}", block_key, key),
+			)
+		}.ok()
+			.filter(|(_, len)| *len > 0)
+			.map(|(pstr, len)| WString::from_wchars_slice(
+				unsafe { std::slice::from_raw_parts(pstr, len as _) },
+			).to_string())
+	}
+
+	/// Returns all the string values, as key/value pairs, from the block of
+	/// the given language and code page, or from the first block if `None`.
+	///
+	/// Returns an empty map if the block doesn't exist.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let ver = w::FileVersion::from_path("C:\\Temp\\foo.dll")?;
+	///
+	/// for (key, val) in ver.strings(None).iter() {
+	///     println!("{}: {}", key, val);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn strings(&self,
+		lang_codepage: Option<(LANGID, co::CP)>,
+	) -> HashMap<String, String>
+	{
+		let block_key = match self.block_key(lang_codepage) {
+			Some(block_key) => block_key,
+			None => return HashMap::new(),
+		};
+		let buf = self.raw_block();
+
+		Node::children_of(buf, Node::parse(buf, 0))
+			.find(|node| node.key == "StringFileInfo")
+			.and_then(|sfi| Node::children_of(buf, Some(sfi))
+				.find(|table| table.key.eq_ignore_ascii_case(&block_key)))
+			.map(|table| Node::children_of(buf, Some(table))
+				.map(|s| (s.key.clone(), s.text(buf)))
+				.collect())
+			.unwrap_or_default()
+	}
+
+	/// Returns the key of the string block, in the `040904b0` format.
+	fn block_key(&self, lang_codepage: Option<(LANGID, co::CP)>) -> Option<String> {
+		match lang_codepage {
+			Some((lang_id, code_page)) => Some(
+				format!("{:04x}{:04x}", u16::from(lang_id), u16::from(code_page)),
+			),
+			None => { // first block in the file
+				let buf = self.raw_block();
+				Node::children_of(buf, Node::parse(buf, 0))
+					.find(|node| node.key == "StringFileInfo")
+					.and_then(|sfi| Node::children_of(buf, Some(sfi)).next())
+					.map(|table| table.key)
+			},
+		}
+	}
+
+	/// Returns the whole version information block as bytes.
+	fn raw_block(&self) -> &[u8] {
+		unsafe {
+			let ptr = self.hversion.ptr() as *const u8;
+			let len = u16::from_le_bytes([*ptr, *ptr.add(1)]); // wLength of root
+			std::slice::from_raw_parts(ptr, len as _)
+		}
+	}
+}
+
+fn to_tuple(ver: [u16; 4]) -> (u16, u16, u16, u16) {
+	(ver[0], ver[1], ver[2], ver[3])
+}
+
+//------------------------------------------------------------------------------
+
+/// A node of the version information tree – `VS_VERSIONINFO`,
+/// `StringFileInfo`, `StringTable` and `String` all share this layout.
+struct Node {
+	key: String,
+	value: (usize, usize), // byte offsets
+	children: (usize, usize),
+	is_text: bool,
+}
+
+impl Node {
+	fn parse(buf: &[u8], off: usize) -> Option<Self> {
+		let word = |idx: usize| buf.get(idx..idx + 2)
+			.map(|b| u16::from_le_bytes([b[0], b[1]]));
+
+		let len = word(off)? as usize;
+		let end = (off + len).min(buf.len());
+		let value_len = word(off + 2)? as usize;
+		let is_text = word(off + 4)? == 1;
+
+		let mut pos = off + 6;
+		let mut key = Vec::<u16>::new();
+		loop {
+			match word(pos)? {
+				0 => break,
+				ch => key.push(ch),
+			}
+			pos += 2;
+		}
+		pos = align4(pos + 2); // skip null terminator
+
+		let value_bytes = if is_text { value_len * 2 } else { value_len };
+		let value = (pos.min(end), (pos + value_bytes).min(end));
+		let children = (align4(value.1).min(end), end);
+
+		if len < 6 || end <= off {
+			None
+		} else {
+			Some(Self { key: String::from_utf16_lossy(&key), value, children, is_text })
+		}
+	}
+
+	fn children_of(buf: &[u8], parent: Option<Self>) -> impl Iterator<Item = Self> + '_ {
+		let (mut pos, end) = parent.map_or((0, 0), |p| p.children);
+		std::iter::from_fn(move || {
+			if pos >= end {
+				return None;
+			}
+			let node = Self::parse(&buf[..end], pos)?;
+			let len = u16::from_le_bytes([buf[pos], buf[pos + 1]]) as usize;
+			pos = align4(pos + len);
+			Some(node)
+		})
+	}
+
+	fn text(&self, buf: &[u8]) -> String {
+		if !self.is_text {
+			return String::new();
+		}
+		let wchars = buf[self.value.0..self.value.1]
+			.chunks_exact(2)
+			.map(|b| u16::from_le_bytes([b[0], b[1]]))
+			.take_while(|ch| *ch != 0)
+			.collect::<Vec<_>>();
+		String::from_utf16_lossy(&wchars)
+	}
+}
+
+fn align4(n: usize) -> usize {
+	(n + 3) & !3
+}
@@ -0,0 +1,3 @@
+mod file_version;
+
+pub use file_version::FileVersion;
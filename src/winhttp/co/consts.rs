@@ -0,0 +1,117 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+const_ordinary! { WINHTTP_ACCESS_TYPE: u32;
+	/// [`HINTERNET::WinHttpOpen`](crate::prelude::winhttp_Hinternet::WinHttpOpen)
+	/// `access_type` (`u32`).
+	=>
+	DEFAULT_PROXY 0
+	NO_PROXY 1
+	NAMED_PROXY 3
+	AUTOMATIC_PROXY 4
+}
+
+const_bitflag! { WINHTTP_FLAG: u32;
+	/// [`HINTERNET::WinHttpOpenRequest`](crate::prelude::winhttp_Hinternet::WinHttpOpenRequest)
+	/// `flags` (`u32`).
+	=>
+	NoValue 0
+	ESCAPE_PERCENT 0x0000_0004
+	NULL_CODEPAGE 0x0000_0008
+	ESCAPE_DISABLE 0x0000_0040
+	ESCAPE_DISABLE_QUERY 0x0000_0080
+	BYPASS_PROXY_CACHE 0x0000_0100
+	REFRESH 0x0000_0100
+	AUTOMATIC_CHUNKING 0x0000_0200
+	SECURE 0x0080_0000
+}
+
+const_bitflag! { WINHTTP_OPEN: u32;
+	/// [`HINTERNET::WinHttpOpen`](crate::prelude::winhttp_Hinternet::WinHttpOpen)
+	/// `flags` (`u32`).
+	=>
+	NoValue 0
+	ASYNC 0x1000_0000
+	SECURE_DEFAULTS 0x3000_0000
+}
+
+const_ordinary! { WINHTTP_QUERY: u32;
+	/// [`HINTERNET::WinHttpQueryHeaders`](crate::prelude::winhttp_Hinternet::WinHttpQueryHeaders)
+	/// `info_level` (`u32`).
+	=>
+	MIME_VERSION 0
+	CONTENT_TYPE 1
+	CONTENT_TRANSFER_ENCODING 2
+	CONTENT_ID 3
+	CONTENT_DESCRIPTION 4
+	CONTENT_LENGTH 5
+	CONTENT_LANGUAGE 6
+	ALLOW 7
+	PUBLIC 8
+	DATE 9
+	EXPIRES 10
+	LAST_MODIFIED 11
+	MESSAGE_ID 12
+	URI 13
+	DERIVED_FROM 14
+	COST 15
+	LINK 16
+	PRAGMA 17
+	VERSION 18
+	STATUS_CODE 19
+	STATUS_TEXT 20
+	RAW_HEADERS 21
+	RAW_HEADERS_CRLF 22
+	CONNECTION 23
+	ACCEPT 24
+	ACCEPT_CHARSET 25
+	ACCEPT_ENCODING 26
+	ACCEPT_LANGUAGE 27
+	AUTHORIZATION 28
+	CONTENT_ENCODING 29
+	FORWARDED 30
+	FROM 31
+	IF_MODIFIED_SINCE 32
+	LOCATION 33
+	ORIG_URI 34
+	REFERER 35
+	RETRY_AFTER 36
+	SERVER 37
+	TITLE 38
+	USER_AGENT 39
+	WWW_AUTHENTICATE 40
+	PROXY_AUTHENTICATE 41
+	ACCEPT_RANGES 42
+	SET_COOKIE 43
+	COOKIE 44
+	REQUEST_METHOD 45
+	REFRESH 46
+	CONTENT_DISPOSITION 47
+	AGE 48
+	CACHE_CONTROL 49
+	CONTENT_BASE 50
+	CONTENT_LOCATION 51
+	CONTENT_MD5 52
+	CONTENT_RANGE 53
+	ETAG 54
+	HOST 55
+	IF_MATCH 56
+	IF_NONE_MATCH 57
+	IF_RANGE 58
+	IF_UNMODIFIED_SINCE 59
+	MAX_FORWARDS 60
+	PROXY_AUTHORIZATION 61
+	RANGE 62
+	TRANSFER_ENCODING 63
+	UPGRADE 64
+	VARY 65
+	VIA 66
+	WARNING 67
+	EXPECT 68
+	PROXY_CONNECTION 69
+	UNLESS_MODIFIED_SINCE 70
+	PROXY_SUPPORT 75
+	AUTHENTICATION_INFO 76
+	PASSPORT_URLS 77
+	PASSPORT_CONFIG 78
+	CUSTOM 65535
+}
@@ -0,0 +1,53 @@
+use crate::co::*;
+
+const_values! { ERROR;
+	WINHTTP_OUT_OF_HANDLES 12001
+	WINHTTP_TIMEOUT 12002
+	WINHTTP_INTERNAL_ERROR 12004
+	WINHTTP_INVALID_URL 12005
+	WINHTTP_UNRECOGNIZED_SCHEME 12006
+	WINHTTP_NAME_NOT_RESOLVED 12007
+	WINHTTP_INVALID_OPTION 12009
+	WINHTTP_OPTION_NOT_SETTABLE 12011
+	WINHTTP_SHUTDOWN 12012
+	WINHTTP_LOGIN_FAILURE 12015
+	WINHTTP_OPERATION_CANCELLED 12017
+	WINHTTP_INCORRECT_HANDLE_TYPE 12018
+	WINHTTP_INCORRECT_HANDLE_STATE 12019
+	WINHTTP_CANNOT_CONNECT 12029
+	WINHTTP_CONNECTION_ERROR 12030
+	WINHTTP_RESEND_REQUEST 12032
+	WINHTTP_SECURE_CERT_DATE_INVALID 12037
+	WINHTTP_SECURE_CERT_CN_INVALID 12038
+	WINHTTP_CLIENT_AUTH_CERT_NEEDED 12044
+	WINHTTP_SECURE_INVALID_CA 12045
+	WINHTTP_SECURE_CERT_REV_FAILED 12057
+	WINHTTP_CANNOT_CALL_BEFORE_OPEN 12100
+	WINHTTP_CANNOT_CALL_BEFORE_SEND 12101
+	WINHTTP_CANNOT_CALL_AFTER_SEND 12102
+	WINHTTP_CANNOT_CALL_AFTER_OPEN 12103
+	WINHTTP_HEADER_NOT_FOUND 12150
+	WINHTTP_INVALID_SERVER_RESPONSE 12152
+	WINHTTP_INVALID_HEADER 12153
+	WINHTTP_INVALID_QUERY_REQUEST 12154
+	WINHTTP_HEADER_ALREADY_EXISTS 12155
+	WINHTTP_REDIRECT_FAILED 12156
+	WINHTTP_SECURE_CHANNEL_ERROR 12157
+	WINHTTP_BAD_AUTO_PROXY_SCRIPT 12166
+	WINHTTP_UNABLE_TO_DOWNLOAD_SCRIPT 12167
+	WINHTTP_SECURE_INVALID_CERT 12169
+	WINHTTP_SECURE_CERT_REVOKED 12170
+	WINHTTP_NOT_INITIALIZED 12172
+	WINHTTP_SECURE_FAILURE 12175
+	WINHTTP_UNHANDLED_SCRIPT_TYPE 12176
+	WINHTTP_SCRIPT_EXECUTION_ERROR 12177
+	WINHTTP_AUTO_PROXY_SERVICE_ERROR 12178
+	WINHTTP_SECURE_CERT_WRONG_USAGE 12179
+	WINHTTP_AUTODETECTION_FAILED 12180
+	WINHTTP_HEADER_COUNT_EXCEEDED 12181
+	WINHTTP_HEADER_SIZE_OVERFLOW 12182
+	WINHTTP_CHUNKED_ENCODING_HEADER_SIZE_OVERFLOW 12183
+	WINHTTP_RESPONSE_DRAIN_OVERFLOW 12184
+	WINHTTP_CLIENT_CERT_NO_PRIVATE_KEY 12185
+	WINHTTP_CLIENT_CERT_NO_ACCESS_PRIVATE_KEY 12186
+}
@@ -0,0 +1,13 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "winhttp";
+	WinHttpCloseHandle(HANDLE) -> BOOL
+	WinHttpConnect(HANDLE, PCSTR, u16, u32) -> HANDLE
+	WinHttpOpen(PCSTR, u32, PCSTR, PCSTR, u32) -> HANDLE
+	WinHttpOpenRequest(HANDLE, PCSTR, PCSTR, PCSTR, PCSTR, *const PCSTR, u32) -> HANDLE
+	WinHttpQueryDataAvailable(HANDLE, *mut u32) -> BOOL
+	WinHttpQueryHeaders(HANDLE, u32, PCSTR, PVOID, *mut u32, *mut u32) -> BOOL
+	WinHttpReadData(HANDLE, PVOID, u32, *mut u32) -> BOOL
+	WinHttpReceiveResponse(HANDLE, PVOID) -> BOOL
+	WinHttpSendRequest(HANDLE, PCSTR, u32, PCVOID, u32, u32, usize) -> BOOL
+}
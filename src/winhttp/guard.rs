@@ -0,0 +1,11 @@
+use crate::decl::*;
+use crate::prelude::*;
+use crate::winhttp::ffi;
+
+handle_guard! { WinHttpCloseHandleGuard: HINTERNET;
+	ffi::WinHttpCloseHandle;
+	/// RAII implementation for [`HINTERNET`](crate::HINTERNET) which
+	/// automatically calls
+	/// [`WinHttpCloseHandle`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpclosehandle)
+	/// when the object goes out of scope.
+}
@@ -0,0 +1,260 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+use crate::winhttp::ffi;
+
+impl_handle! { HINTERNET;
+	/// Handle to a WinHTTP
+	/// [session, connection or request](https://learn.microsoft.com/en-us/windows/win32/winhttp/hinternet-handles-in-winhttp).
+}
+
+impl winhttp_Hinternet for HINTERNET {}
+
+/// This trait is enabled with the `winhttp` feature, and provides methods for
+/// [`HINTERNET`](crate::HINTERNET).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait winhttp_Hinternet: Handle {
+	/// [`WinHttpConnect`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpconnect)
+	/// function.
+	///
+	/// Must be called on a session handle, returned by
+	/// [`HINTERNET::WinHttpOpen`](crate::prelude::winhttp_Hinternet::WinHttpOpen).
+	fn WinHttpConnect(&self,
+		server_name: &str,
+		server_port: u16,
+	) -> SysResult<WinHttpCloseHandleGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::WinHttpConnect(
+					self.ptr(),
+					WString::from_str(server_name).as_ptr(),
+					server_port,
+					0,
+				),
+			).map(|h| WinHttpCloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`WinHttpOpen`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpopen)
+	/// function.
+	///
+	/// Returns a session handle.
+	///
+	/// # Examples
+	///
+	/// Downloading a page with the raw WinHTTP functions:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hsession = w::HINTERNET::WinHttpOpen(
+	///     Some("MyApp/1.0"),
+	///     co::WINHTTP_ACCESS_TYPE::AUTOMATIC_PROXY,
+	///     None,
+	///     None,
+	///     co::WINHTTP_OPEN::NoValue,
+	/// )?;
+	/// let hconnect = hsession.WinHttpConnect("example.com", 443)?;
+	/// let hrequest = hconnect.WinHttpOpenRequest(
+	///     "GET", "/", None, None, &[], co::WINHTTP_FLAG::SECURE)?;
+	///
+	/// hrequest.WinHttpSendRequest(None, &[])?;
+	/// hrequest.WinHttpReceiveResponse()?;
+	///
+	/// let status = hrequest.WinHttpQueryHeaders(co::WINHTTP_QUERY::STATUS_CODE)?;
+	/// println!("Status: {}", status);
+	///
+	/// let mut body = Vec::<u8>::new();
+	/// loop {
+	///     let avail = hrequest.WinHttpQueryDataAvailable()?;
+	///     if avail == 0 {
+	///         break;
+	///     }
+	///     let mut buf = vec![0u8; avail as _];
+	///     let num_read = hrequest.WinHttpReadData(&mut buf)?;
+	///     body.extend_from_slice(&buf[..num_read as _]);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn WinHttpOpen(
+		user_agent: Option<&str>,
+		access_type: co::WINHTTP_ACCESS_TYPE,
+		proxy_name: Option<&str>,
+		proxy_bypass: Option<&str>,
+		flags: co::WINHTTP_OPEN,
+	) -> SysResult<WinHttpCloseHandleGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::WinHttpOpen(
+					WString::from_opt_str(user_agent).as_ptr(),
+					access_type.raw(),
+					WString::from_opt_str(proxy_name).as_ptr(),
+					WString::from_opt_str(proxy_bypass).as_ptr(),
+					flags.raw(),
+				),
+			).map(|h| WinHttpCloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`WinHttpOpenRequest`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpopenrequest)
+	/// function.
+	///
+	/// Must be called on a connection handle, returned by
+	/// [`HINTERNET::WinHttpConnect`](crate::prelude::winhttp_Hinternet::WinHttpConnect).
+	/// If `accept_types` is empty, no types are accepted.
+	fn WinHttpOpenRequest(&self,
+		verb: &str,
+		object_name: &str,
+		version: Option<&str>,
+		referrer: Option<&str>,
+		accept_types: &[&str],
+		flags: co::WINHTTP_FLAG,
+	) -> SysResult<WinHttpCloseHandleGuard>
+	{
+		let waccept_types = accept_types.iter()
+			.map(WString::from_str)
+			.collect::<Vec<_>>();
+		let mut waccept_ptrs = waccept_types.iter()
+			.map(|ws| ws.as_ptr())
+			.collect::<Vec<_>>();
+		waccept_ptrs.push(std::ptr::null()); // array is null-terminated
+
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::WinHttpOpenRequest(
+					self.ptr(),
+					WString::from_str(verb).as_ptr(),
+					WString::from_str(object_name).as_ptr(),
+					WString::from_opt_str(version).as_ptr(),
+					WString::from_opt_str(referrer).as_ptr(),
+					if accept_types.is_empty() {
+						std::ptr::null()
+					} else {
+						waccept_ptrs.as_ptr()
+					},
+					flags.raw(),
+				),
+			).map(|h| WinHttpCloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`WinHttpQueryDataAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpquerydataavailable)
+	/// function.
+	///
+	/// Returns the number of bytes available to be read with
+	/// [`HINTERNET::WinHttpReadData`](crate::prelude::winhttp_Hinternet::WinHttpReadData).
+	/// Zero means the end of the response body.
+	fn WinHttpQueryDataAvailable(&self) -> SysResult<u32> {
+		let mut num_bytes = u32::default();
+		bool_to_sysresult(
+			unsafe { ffi::WinHttpQueryDataAvailable(self.ptr(), &mut num_bytes) },
+		).map(|_| num_bytes)
+	}
+
+	/// [`WinHttpQueryHeaders`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpqueryheaders)
+	/// function.
+	///
+	/// Returns the header value as a string. If the header is not present,
+	/// fails with
+	/// [`co::ERROR::WINHTTP_HEADER_NOT_FOUND`](crate::co::ERROR::WINHTTP_HEADER_NOT_FOUND).
+	fn WinHttpQueryHeaders(&self,
+		info_level: co::WINHTTP_QUERY,
+	) -> SysResult<String>
+	{
+		let mut buf_sz = u32::default(); // in bytes
+		match bool_to_sysresult(
+			unsafe {
+				ffi::WinHttpQueryHeaders(
+					self.ptr(),
+					info_level.raw(),
+					std::ptr::null(),
+					std::ptr::null_mut(),
+					&mut buf_sz,
+					std::ptr::null_mut(),
+				)
+			},
+		) {
+			Ok(_) => return Ok(String::new()),
+			Err(co::ERROR::INSUFFICIENT_BUFFER) => {}, // expected
+			Err(e) => return Err(e),
+		}
+
+		let mut buf = WString::new_alloc_buf(buf_sz as usize / 2 + 1);
+		bool_to_sysresult(
+			unsafe {
+				ffi::WinHttpQueryHeaders(
+					self.ptr(),
+					info_level.raw(),
+					std::ptr::null(),
+					buf.as_mut_ptr() as _,
+					&mut buf_sz,
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| buf.to_string())
+	}
+
+	/// [`WinHttpReadData`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpreaddata)
+	/// function.
+	///
+	/// Returns the number of bytes actually read, which is zero at the end of
+	/// the response body.
+	fn WinHttpReadData(&self, buffer: &mut [u8]) -> SysResult<u32> {
+		let mut num_read = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::WinHttpReadData(
+					self.ptr(),
+					buffer.as_mut_ptr() as _,
+					buffer.len() as _,
+					&mut num_read,
+				)
+			},
+		).map(|_| num_read)
+	}
+
+	/// [`WinHttpReceiveResponse`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpreceiveresponse)
+	/// function.
+	fn WinHttpReceiveResponse(&self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { ffi::WinHttpReceiveResponse(self.ptr(), std::ptr::null_mut()) },
+		)
+	}
+
+	/// [`WinHttpSendRequest`](https://learn.microsoft.com/en-us/windows/win32/api/winhttp/nf-winhttp-winhttpsendrequest)
+	/// function.
+	///
+	/// The `headers`, if any, must be separated by `\r\n`. The whole `optional`
+	/// data – usually the body of a POST request – is sent at once.
+	fn WinHttpSendRequest(&self,
+		headers: Option<&str>,
+		optional: &[u8],
+	) -> SysResult<()>
+	{
+		let wheaders = WString::from_opt_str(headers);
+		bool_to_sysresult(
+			unsafe {
+				ffi::WinHttpSendRequest(
+					self.ptr(),
+					wheaders.as_ptr(),
+					if wheaders.as_ptr().is_null() { 0 } else { u32::MAX }, // -1 means null-terminated
+					if optional.is_empty() { std::ptr::null() } else { optional.as_ptr() as _ },
+					optional.len() as _,
+					optional.len() as _,
+					0,
+				)
+			},
+		)
+	}
+}
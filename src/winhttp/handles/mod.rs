@@ -0,0 +1,9 @@
+mod hinternet;
+
+pub mod decl {
+	pub use super::hinternet::HINTERNET;
+}
+
+pub mod traits {
+	pub use super::hinternet::winhttp_Hinternet;
+}
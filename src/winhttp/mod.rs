@@ -0,0 +1,17 @@
+#![cfg(feature = "winhttp")]
+
+mod handles;
+mod utilities;
+
+pub(in crate::winhttp) mod ffi;
+pub mod co;
+pub mod guard;
+
+pub mod decl {
+	pub use super::handles::decl::*;
+	pub use super::utilities::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
@@ -0,0 +1,208 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// A minimal HTTP client built upon
+/// [WinHTTP](https://learn.microsoft.com/en-us/windows/win32/winhttp/about-winhttp),
+/// to perform simple GET and POST requests without any additional
+/// dependencies.
+///
+/// URLs without a scheme are requested with HTTPS. Redirects are followed
+/// automatically by WinHTTP, except from HTTPS to HTTP. The proxy settings are
+/// automatically detected.
+///
+/// Errors are reported as [`co::ERROR`](crate::co::ERROR) values – WinHTTP
+/// specific errors have the `WINHTTP_` prefix, like
+/// [`co::ERROR::WINHTTP_TIMEOUT`](crate::co::ERROR::WINHTTP_TIMEOUT).
+///
+/// # Examples
+///
+/// Checking for updates:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let client = w::HttpClient::new("MyApp/1.0")?;
+/// let resp = client.get("https://example.com/myapp/latest-version.txt")?;
+///
+/// if resp.status_code == 200 {
+///     println!("Latest version: {}", String::from_utf8_lossy(&resp.body));
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub struct HttpClient {
+	hsession: WinHttpCloseHandleGuard,
+}
+
+impl HttpClient {
+	/// Creates a new client, opening a WinHTTP session with
+	/// [`HINTERNET::WinHttpOpen`](crate::prelude::winhttp_Hinternet::WinHttpOpen).
+	pub fn new(user_agent: &str) -> SysResult<Self> {
+		let hsession = HINTERNET::WinHttpOpen(
+			Some(user_agent),
+			co::WINHTTP_ACCESS_TYPE::AUTOMATIC_PROXY,
+			None,
+			None,
+			co::WINHTTP_OPEN::NoValue,
+		)?;
+		Ok(Self { hsession })
+	}
+
+	/// Performs a GET request, returning the response after the whole body is
+	/// received.
+	pub fn get(&self, url: &str) -> SysResult<HttpResponse> {
+		self.request("GET", url, None, &[])
+	}
+
+	/// Performs a POST request with the given body, returning the response
+	/// after the whole body is received.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let client = w::HttpClient::new("MyApp/1.0")?;
+	/// let resp = client.post(
+	///     "https://example.com/api/report",
+	///     br#"{"event":"started"}"#,
+	///     "application/json",
+	/// )?;
+	///
+	/// println!("{}", resp.header("Content-Type").unwrap_or("unknown type"));
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn post(&self,
+		url: &str,
+		body: &[u8],
+		content_type: &str,
+	) -> SysResult<HttpResponse>
+	{
+		let headers = format!("Content-Type: {}", content_type);
+		self.request("POST", url, Some(&headers), body)
+	}
+
+	fn request(&self,
+		verb: &str,
+		url: &str,
+		headers: Option<&str>,
+		body: &[u8],
+	) -> SysResult<HttpResponse>
+	{
+		let url = Url::parse(url)?;
+		let hconnect = self.hsession.WinHttpConnect(url.host, url.port)?;
+		let hrequest = hconnect.WinHttpOpenRequest(
+			verb, &url.path, None, None, &[],
+			if url.secure { co::WINHTTP_FLAG::SECURE } else { co::WINHTTP_FLAG::NoValue },
+		)?;
+
+		hrequest.WinHttpSendRequest(headers, body)?;
+		hrequest.WinHttpReceiveResponse()?;
+
+		let status_code = hrequest.WinHttpQueryHeaders(co::WINHTTP_QUERY::STATUS_CODE)?
+			.parse::<u16>()
+			.map_err(|_| co::ERROR::WINHTTP_INVALID_SERVER_RESPONSE)?;
+
+		let headers = hrequest.WinHttpQueryHeaders(co::WINHTTP_QUERY::RAW_HEADERS_CRLF)?
+			.split("\r\n")
+			.skip(1) // status line
+			.filter_map(|line| line.split_once(':'))
+			.map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+			.collect::<Vec<_>>();
+
+		let mut body = Vec::<u8>::new();
+		let mut buf = [0u8; 8192];
+		loop {
+			match hrequest.WinHttpReadData(&mut buf)? {
+				0 => break,
+				num_read => body.extend_from_slice(&buf[..num_read as usize]),
+			}
+		}
+
+		Ok(HttpResponse { status_code, headers, body })
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Response of a request made by [`HttpClient`](crate::HttpClient).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct HttpResponse {
+	/// The HTTP status code, like `200`.
+	pub status_code: u16,
+	/// The response headers, as name/value pairs, in the order they were
+	/// received.
+	pub headers: Vec<(String, String)>,
+	/// The response body.
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	/// Returns the value of the first header with the given name, which is
+	/// case-insensitive.
+	#[must_use]
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers.iter()
+			.find(|(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, v)| v.as_str())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// The parts of an URL needed by WinHTTP.
+struct Url<'a> {
+	secure: bool,
+	host: &'a str,
+	port: u16,
+	path: String,
+}
+
+impl<'a> Url<'a> {
+	fn parse(url: &'a str) -> SysResult<Self> {
+		let (secure, rest) = if has_prefix_ci(url, "https://") {
+			(true, &url[8..])
+		} else if has_prefix_ci(url, "http://") {
+			(false, &url[7..])
+		} else if url.contains("://") {
+			return Err(co::ERROR::WINHTTP_UNRECOGNIZED_SCHEME);
+		} else {
+			(true, url) // no scheme, HTTPS by default
+		};
+
+		let (authority, path) = match rest.find(['/', '?', '#']) {
+			Some(idx) => rest.split_at(idx),
+			None => (rest, ""),
+		};
+		let path = path.split('#').next().unwrap(); // fragment is never sent
+		let path = if path.starts_with('/') {
+			path.to_owned()
+		} else {
+			format!("/{}", path)
+		};
+
+		if authority.contains('@') { // credentials not supported
+			return Err(co::ERROR::WINHTTP_INVALID_URL);
+		}
+		let default_port = if secure { 443 } else { 80 };
+		let (host, port) = match authority.rfind(':') {
+			Some(idx) if !authority[idx..].contains(']') => ( // not inside an IPv6 address
+				&authority[..idx],
+				authority[idx + 1..].parse::<u16>()
+					.map_err(|_| co::ERROR::WINHTTP_INVALID_URL)?,
+			),
+			_ => (authority, default_port),
+		};
+		if host.is_empty() {
+			return Err(co::ERROR::WINHTTP_INVALID_URL);
+		}
+
+		Ok(Self { secure, host, port, path })
+	}
+}
+
+fn has_prefix_ci(s: &str, prefix: &str) -> bool {
+	s.get(..prefix.len())
+		.is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
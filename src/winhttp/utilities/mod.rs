@@ -0,0 +1,3 @@
+mod http_client;
+
+pub use http_client::{HttpClient, HttpResponse};
@@ -0,0 +1,55 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+const_ordinary! { AF: i32;
+	/// Address family
+	/// [constants](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-socket)
+	/// (`i32`).
+	=>
+	UNSPEC 0
+	INET 2
+	INET6 23
+}
+
+const_ordinary! { IPPROTO: i32;
+	/// Socket protocol
+	/// [constants](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-socket)
+	/// (`i32`).
+	=>
+	IP 0
+	ICMP 1
+	TCP 6
+	UDP 17
+	ICMPV6 58
+}
+
+const_bitflag! { MSG: i32;
+	/// [`SOCKET::recv`](crate::prelude::ws2_Socket::recv) and
+	/// [`SOCKET::send`](crate::prelude::ws2_Socket::send) `flags` (`i32`).
+	=>
+	NoValue 0
+	OOB 0x1
+	PEEK 0x2
+	DONTROUTE 0x4
+	WAITALL 0x8
+}
+
+const_ordinary! { SD: i32;
+	/// [`SOCKET::shutdown`](crate::prelude::ws2_Socket::shutdown) `how`
+	/// (`i32`).
+	=>
+	RECEIVE 0
+	SEND 1
+	BOTH 2
+}
+
+const_ordinary! { SOCK: i32;
+	/// Socket type
+	/// [constants](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-socket)
+	/// (`i32`).
+	=>
+	STREAM 1
+	DGRAM 2
+	RAW 3
+	RDM 4
+	SEQPACKET 5
+}
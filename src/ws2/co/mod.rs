@@ -0,0 +1,4 @@
+mod consts;
+mod error;
+
+pub use consts::*;
@@ -0,0 +1,15 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "ws2_32";
+	closesocket(HANDLE) -> i32
+	connect(HANDLE, PCVOID, i32) -> i32
+	FreeAddrInfoW(PVOID)
+	GetAddrInfoW(PCSTR, PCSTR, PCVOID, *mut PVOID) -> i32
+	recv(HANDLE, PVOID, i32, i32) -> i32
+	send(HANDLE, PCVOID, i32, i32) -> i32
+	shutdown(HANDLE, i32) -> i32
+	socket(i32, i32, i32) -> HANDLE
+	WSACleanup() -> i32
+	WSAGetLastError() -> i32
+	WSAStartup(u16, PVOID) -> i32
+}
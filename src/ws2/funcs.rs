@@ -0,0 +1,98 @@
+#![allow(non_snake_case)]
+
+use std::net::SocketAddr;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::ws2::{ffi, privs::*};
+
+/// [`GetAddrInfoW`](https://learn.microsoft.com/en-us/windows/win32/api/ws2tcpip/nf-ws2tcpip-getaddrinfow)
+/// function.
+///
+/// Resolves the host name into the addresses which can be passed to
+/// [`SOCKET::connect`](crate::prelude::ws2_Socket::connect), filtered by the
+/// socket type. Requires a prior call to [`WSAStartup`](crate::WSAStartup).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _wsa = w::WSAStartup(0x0202)?;
+///
+/// for addr in w::GetAddrInfo("example.com", 80, co::SOCK::STREAM)? {
+///     println!("{}", addr);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub fn GetAddrInfo(
+	host: &str,
+	port: u16,
+	sock_type: co::SOCK,
+) -> SysResult<Vec<SocketAddr>>
+{
+	let hints = AddrInfoW {
+		ai_socktype: sock_type,
+		..Default::default()
+	};
+	let mut pres: PVOID = std::ptr::null_mut();
+
+	match unsafe {
+		ffi::GetAddrInfoW(
+			WString::from_str(host).as_ptr(),
+			WString::from_str(port.to_string()).as_ptr(),
+			&hints as *const _ as _,
+			&mut pres,
+		)
+	} {
+		0 => {},
+		err => return Err(unsafe { co::ERROR::from_raw(err as _) }),
+	}
+
+	let mut addrs = Vec::<SocketAddr>::new();
+	let mut pai = pres as *const AddrInfoW;
+	while let Some(ai) = unsafe { pai.as_ref() } {
+		if let Some(addr) = unsafe { raw_to_socket_addr(ai.ai_addr) } {
+			addrs.push(addr);
+		}
+		pai = ai.ai_next;
+	}
+
+	unsafe { ffi::FreeAddrInfoW(pres); }
+	Ok(addrs)
+}
+
+/// [`WSAGetLastError`](https://learn.microsoft.com/en-us/windows/win32/api/winsock/nf-winsock-wsagetlasterror)
+/// function.
+#[must_use]
+pub fn WSAGetLastError() -> co::ERROR {
+	unsafe { co::ERROR::from_raw(ffi::WSAGetLastError() as _) }
+}
+
+/// [`WSAStartup`](https://learn.microsoft.com/en-us/windows/win32/api/winsock/nf-winsock-wsastartup)
+/// function.
+///
+/// The version is usually `0x0202`. In the returned guard, the
+/// [`WSACleanupGuard::data`](crate::guard::WSACleanupGuard::data) method gives
+/// access to the [`WSADATA`](crate::WSADATA) filled by the call.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let wsa = w::WSAStartup(0x0202)?;
+/// println!("{}", wsa.data().szDescription());
+///
+/// // WSACleanup() automatically called
+/// # w::SysResult::Ok(())
+/// ```
+pub fn WSAStartup(version: u16) -> SysResult<WSACleanupGuard> {
+	let mut wsad = WSADATA::default();
+	match unsafe { ffi::WSAStartup(version, &mut wsad as *mut _ as _) } {
+		0 => Ok(unsafe { WSACleanupGuard::new(wsad) }),
+		err => Err(unsafe { co::ERROR::from_raw(err as _) }),
+	}
+}
@@ -0,0 +1,45 @@
+use crate::decl::*;
+use crate::prelude::*;
+use crate::ws2::ffi;
+
+handle_guard! { CloseSocketGuard: SOCKET;
+	ffi::closesocket;
+	/// RAII implementation for [`SOCKET`](crate::SOCKET) which automatically
+	/// calls
+	/// [`closesocket`](https://learn.microsoft.com/en-us/windows/win32/api/winsock/nf-winsock-closesocket)
+	/// when the object goes out of scope.
+}
+
+/// RAII implementation which automatically calls
+/// [`WSACleanup`](https://learn.microsoft.com/en-us/windows/win32/api/winsock/nf-winsock-wsacleanup)
+/// when the object goes out of scope.
+pub struct WSACleanupGuard {
+	data: WSADATA,
+}
+
+impl Drop for WSACleanupGuard {
+	fn drop(&mut self) {
+		unsafe { ffi::WSACleanup(); } // ignore errors
+	}
+}
+
+impl WSACleanupGuard {
+	/// Constructs the guard by taking ownership of the data.
+	///
+	/// # Safety
+	///
+	/// Be sure you need to call
+	/// [`WSACleanup`](https://learn.microsoft.com/en-us/windows/win32/api/winsock/nf-winsock-wsacleanup)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(data: WSADATA) -> Self {
+		Self { data }
+	}
+
+	/// Returns the [`WSADATA`](crate::WSADATA) filled by
+	/// [`WSAStartup`](crate::WSAStartup).
+	#[must_use]
+	pub const fn data(&self) -> &WSADATA {
+		&self.data
+	}
+}
@@ -0,0 +1,9 @@
+mod socket;
+
+pub mod decl {
+	pub use super::socket::SOCKET;
+}
+
+pub mod traits {
+	pub use super::socket::ws2_Socket;
+}
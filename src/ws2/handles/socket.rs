@@ -0,0 +1,147 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::net::SocketAddr;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+use crate::ws2::{ffi, privs::*};
+
+impl_handle! { SOCKET;
+	/// Handle to a Winsock
+	/// [socket](https://learn.microsoft.com/en-us/windows/win32/winsock/socket-data-type-2).
+}
+
+impl ws2_Socket for SOCKET {}
+
+/// This trait is enabled with the `ws2` feature, and provides methods for
+/// [`SOCKET`](crate::SOCKET).
+///
+/// All the sockets are blocking. Errors are retrieved with
+/// [`WSAGetLastError`](crate::WSAGetLastError).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ws2_Socket: Handle {
+	/// [`connect`](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-connect)
+	/// function.
+	fn connect(&self, addr: &SocketAddr) -> SysResult<()> {
+		let (raw_addr, len) = socket_addr_to_raw(addr);
+		match unsafe {
+			ffi::connect(self.ptr(), raw_addr.as_ptr() as _, len as _)
+		} {
+			0 => Ok(()),
+			_ => Err(WSAGetLastError()),
+		}
+	}
+
+	/// [`recv`](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-recv)
+	/// function.
+	///
+	/// Returns the number of bytes received, which is zero if the connection
+	/// has been gracefully closed.
+	fn recv(&self, buf: &mut [u8], flags: co::MSG) -> SysResult<usize> {
+		match unsafe {
+			ffi::recv(
+				self.ptr(),
+				buf.as_mut_ptr() as _,
+				buf.len().min(i32::MAX as _) as _,
+				flags.raw(),
+			)
+		} {
+			-1 => Err(WSAGetLastError()),
+			n => Ok(n as _),
+		}
+	}
+
+	/// [`send`](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-send)
+	/// function.
+	///
+	/// Returns the number of bytes sent, which may be less than the length of
+	/// `data`.
+	fn send(&self, data: &[u8], flags: co::MSG) -> SysResult<usize> {
+		match unsafe {
+			ffi::send(
+				self.ptr(),
+				data.as_ptr() as _,
+				data.len().min(i32::MAX as _) as _,
+				flags.raw(),
+			)
+		} {
+			-1 => Err(WSAGetLastError()),
+			n => Ok(n as _),
+		}
+	}
+
+	/// [`shutdown`](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-shutdown)
+	/// function.
+	fn shutdown(&self, how: co::SD) -> SysResult<()> {
+		match unsafe { ffi::shutdown(self.ptr(), how.raw()) } {
+			0 => Ok(()),
+			_ => Err(WSAGetLastError()),
+		}
+	}
+
+	/// [`socket`](https://learn.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-socket)
+	/// function.
+	///
+	/// Requires a prior call to [`WSAStartup`](crate::WSAStartup).
+	///
+	/// # Examples
+	///
+	/// Sending a raw HTTP request:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let _wsa = w::WSAStartup(0x0202)?;
+	///
+	/// let addrs = w::GetAddrInfo("example.com", 80, co::SOCK::STREAM)?;
+	/// let addr = addrs.first().unwrap();
+	///
+	/// let sock = w::SOCKET::socket(
+	///     if addr.is_ipv4() { co::AF::INET } else { co::AF::INET6 },
+	///     co::SOCK::STREAM,
+	///     co::IPPROTO::TCP,
+	/// )?;
+	/// sock.connect(addr)?;
+	///
+	/// sock.send(
+	///     b"GET / HTTP/1.0\r\nHost: example.com\r\n\r\n",
+	///     co::MSG::NoValue,
+	/// )?;
+	///
+	/// let mut resp = Vec::<u8>::new();
+	/// let mut buf = [0u8; 4096];
+	/// loop {
+	///     let n = sock.recv(&mut buf, co::MSG::NoValue)?;
+	///     if n == 0 {
+	///         break;
+	///     }
+	///     resp.extend_from_slice(&buf[..n]);
+	/// }
+	///
+	/// sock.shutdown(co::SD::BOTH)?;
+	/// println!("{}", String::from_utf8_lossy(&resp));
+	///
+	/// // closesocket() and WSACleanup() automatically called
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn socket(
+		af: co::AF,
+		sock_type: co::SOCK,
+		protocol: co::IPPROTO,
+	) -> SysResult<CloseSocketGuard>
+	{
+		match unsafe {
+			SOCKET::from_ptr(ffi::socket(af.raw(), sock_type.raw(), protocol.raw()))
+		} {
+			SOCKET::INVALID => Err(WSAGetLastError()),
+			h => Ok(unsafe { CloseSocketGuard::new(h) }),
+		}
+	}
+}
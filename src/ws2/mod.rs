@@ -0,0 +1,20 @@
+#![cfg(feature = "ws2")]
+
+mod funcs;
+mod handles;
+mod structs;
+
+pub(in crate::ws2) mod ffi;
+pub(in crate::ws2) mod privs;
+pub mod co;
+pub mod guard;
+
+pub mod decl {
+	pub use super::funcs::*;
+	pub use super::handles::decl::*;
+	pub use super::structs::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
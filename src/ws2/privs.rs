@@ -0,0 +1,77 @@
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::co;
+
+/// [`ADDRINFOW`](https://learn.microsoft.com/en-us/windows/win32/api/ws2def/ns-ws2def-addrinfow)
+/// struct, used only internally.
+#[repr(C)]
+pub(in crate::ws2) struct AddrInfoW {
+	pub(in crate::ws2) ai_flags: i32,
+	pub(in crate::ws2) ai_family: co::AF,
+	pub(in crate::ws2) ai_socktype: co::SOCK,
+	pub(in crate::ws2) ai_protocol: co::IPPROTO,
+	pub(in crate::ws2) ai_addrlen: usize,
+	pub(in crate::ws2) ai_canonname: *mut u16,
+	pub(in crate::ws2) ai_addr: *const u8,
+	pub(in crate::ws2) ai_next: *const AddrInfoW,
+}
+
+impl_default!(AddrInfoW);
+
+/// Size of the largest raw socket address, `SOCKADDR_IN6`.
+pub(in crate::ws2) const SOCKADDR_MAX: usize = 28;
+
+/// Serializes the address into a `SOCKADDR_IN` or `SOCKADDR_IN6` buffer,
+/// returning it along with the actual number of bytes used.
+pub(in crate::ws2) fn socket_addr_to_raw(
+	addr: &SocketAddr,
+) -> ([u8; SOCKADDR_MAX], usize)
+{
+	let mut buf = [0u8; SOCKADDR_MAX];
+	match addr {
+		SocketAddr::V4(v4) => {
+			buf[0..2].copy_from_slice(&(co::AF::INET.raw() as u16).to_ne_bytes());
+			buf[2..4].copy_from_slice(&v4.port().to_be_bytes());
+			buf[4..8].copy_from_slice(&v4.ip().octets());
+			(buf, 16)
+		},
+		SocketAddr::V6(v6) => {
+			buf[0..2].copy_from_slice(&(co::AF::INET6.raw() as u16).to_ne_bytes());
+			buf[2..4].copy_from_slice(&v6.port().to_be_bytes());
+			buf[4..8].copy_from_slice(&v6.flowinfo().to_ne_bytes());
+			buf[8..24].copy_from_slice(&v6.ip().octets());
+			buf[24..28].copy_from_slice(&v6.scope_id().to_ne_bytes());
+			(buf, 28)
+		},
+	}
+}
+
+/// Parses a raw `SOCKADDR_IN` or `SOCKADDR_IN6`, returning `None` if the
+/// address family is not supported.
+///
+/// # Safety
+///
+/// The pointer must point to a valid socket address.
+pub(in crate::ws2) unsafe fn raw_to_socket_addr(p: *const u8) -> Option<SocketAddr> {
+	let family = u16::from_ne_bytes(unsafe { [*p, *p.add(1)] });
+	let port = u16::from_be_bytes(unsafe { [*p.add(2), *p.add(3)] });
+
+	if family == co::AF::INET.raw() as u16 {
+		let mut ip = [0u8; 4];
+		unsafe { std::ptr::copy_nonoverlapping(p.add(4), ip.as_mut_ptr(), 4); }
+		Some(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(ip), port)))
+	} else if family == co::AF::INET6.raw() as u16 {
+		let mut raw = [0u8; SOCKADDR_MAX];
+		unsafe { std::ptr::copy_nonoverlapping(p, raw.as_mut_ptr(), SOCKADDR_MAX); }
+		let mut ip = [0u8; 16];
+		ip.copy_from_slice(&raw[8..24]);
+		Some(SocketAddr::V6(SocketAddrV6::new(
+			Ipv6Addr::from(ip),
+			port,
+			u32::from_ne_bytes([raw[4], raw[5], raw[6], raw[7]]),
+			u32::from_ne_bytes([raw[24], raw[25], raw[26], raw[27]]),
+		)))
+	} else {
+		None
+	}
+}
@@ -0,0 +1,41 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+/// [`WSADATA`](https://learn.microsoft.com/en-us/windows/win32/api/winsock/ns-winsock-wsadata)
+/// struct.
+///
+/// The field order differs between 32 and 64-bit targets, which is handled
+/// internally.
+#[repr(C)]
+pub struct WSADATA {
+	pub wVersion: u16,
+	pub wHighVersion: u16,
+	#[cfg(target_pointer_width = "64")] pub iMaxSockets: u16,
+	#[cfg(target_pointer_width = "64")] pub iMaxUdpDg: u16,
+	#[cfg(target_pointer_width = "64")] lpVendorInfo: *mut u8,
+	szDescription: [u8; 257],
+	szSystemStatus: [u8; 129],
+	#[cfg(target_pointer_width = "32")] pub iMaxSockets: u16,
+	#[cfg(target_pointer_width = "32")] pub iMaxUdpDg: u16,
+	#[cfg(target_pointer_width = "32")] lpVendorInfo: *mut u8,
+}
+
+impl_default!(WSADATA);
+
+impl WSADATA {
+	/// Returns the `szDescription` field.
+	#[must_use]
+	pub fn szDescription(&self) -> String {
+		ansi_arr_to_string(&self.szDescription)
+	}
+
+	/// Returns the `szSystemStatus` field.
+	#[must_use]
+	pub fn szSystemStatus(&self) -> String {
+		ansi_arr_to_string(&self.szSystemStatus)
+	}
+}
+
+fn ansi_arr_to_string(arr: &[u8]) -> String {
+	let len = arr.iter().position(|ch| *ch == 0).unwrap_or(arr.len());
+	String::from_utf8_lossy(&arr[..len]).into_owned()
+}
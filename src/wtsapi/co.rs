@@ -0,0 +1,53 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+const_ordinary! { WTS_CONNECTSTATE_CLASS: u32;
+	/// [`WTS_CONNECTSTATE_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/ne-wtsapi32-wts_connectstate_class)
+	/// enumeration (`u32`).
+	=>
+	Active 0
+	Connected 1
+	ConnectQuery 2
+	Shadow 3
+	Disconnected 4
+	Idle 5
+	Listen 6
+	Reset 7
+	Down 8
+	Init 9
+}
+
+const_ordinary! { WTS_INFO_CLASS: u32;
+	/// [`WTS_INFO_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/ne-wtsapi32-wts_info_class)
+	/// enumeration (`u32`).
+	=>
+	InitialProgram 0
+	ApplicationName 1
+	WorkingDirectory 2
+	OEMId 3
+	SessionId 4
+	UserName 5
+	WinStationName 6
+	DomainName 7
+	ConnectState 8
+	ClientBuildNumber 9
+	ClientName 10
+	ClientDirectory 11
+	ClientProductId 12
+	ClientHardwareId 13
+	ClientAddress 14
+	ClientDisplay 15
+	ClientProtocolType 16
+	IdleTime 17
+	LogonTime 18
+	IncomingBytes 19
+	OutgoingBytes 20
+	IncomingFrames 21
+	OutgoingFrames 22
+	ClientInfo 23
+	SessionInfo 24
+	SessionInfoEx 25
+	ConfigInfo 26
+	ValidationInfo 27
+	SessionAddressV4 28
+	IsRemoteSession 29
+}
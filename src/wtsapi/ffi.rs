@@ -0,0 +1,7 @@
+use crate::kernel::ffi_types::*;
+
+extern_sys! { "wtsapi32";
+	WTSEnumerateSessionsW(HANDLE, u32, u32, *mut PVOID, *mut u32) -> BOOL
+	WTSFreeMemory(PVOID)
+	WTSQuerySessionInformationW(HANDLE, u32, u32, *mut PSTR, *mut u32) -> BOOL
+}
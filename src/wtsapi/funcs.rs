@@ -0,0 +1,161 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi_types::*, privs::*};
+use crate::wtsapi::ffi;
+
+/// Memory layout of the
+/// [`WTS_SESSION_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/ns-wtsapi32-wts_session_infow)
+/// struct, which is copied into owned values before the memory is released.
+#[repr(C)]
+struct WTS_SESSION_INFO {
+	SessionId: u32,
+	pWinStationName: PSTR,
+	State: co::WTS_CONNECTSTATE_CLASS,
+}
+
+/// [`WTSEnumerateSessions`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsenumeratesessionsw)
+/// function, for the current server.
+///
+/// Returns, for each session, respectively:
+/// 1. the session ID;
+/// 2. the window station name;
+/// 3. the connection state.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// for (session_id, station, state) in w::WTSEnumerateSessions()? {
+///     let user = w::WTSQuerySessionInformation(
+///         session_id, co::WTS_INFO_CLASS::UserName)?;
+///     println!("{} {} {} {}",
+///         session_id, station, user,
+///         if state == co::WTS_CONNECTSTATE_CLASS::Active { "active" } else { "" });
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`WTSQuerySessionInformation`](crate::WTSQuerySessionInformation)
+#[must_use]
+pub fn WTSEnumerateSessions(
+) -> SysResult<Vec<(u32, String, co::WTS_CONNECTSTATE_CLASS)>>
+{
+	let mut ptr_infos = std::ptr::null_mut();
+	let mut count = u32::default();
+
+	bool_to_sysresult(
+		unsafe {
+			ffi::WTSEnumerateSessionsW(
+				std::ptr::null_mut(), // WTS_CURRENT_SERVER_HANDLE
+				0,
+				1,
+				&mut ptr_infos,
+				&mut count,
+			)
+		},
+	)?;
+	let _guard = unsafe { WTSFreeMemoryGuard::new(ptr_infos) };
+
+	if ptr_infos.is_null() {
+		return Ok(Vec::new());
+	}
+	let infos = unsafe {
+		std::slice::from_raw_parts(ptr_infos as *const WTS_SESSION_INFO, count as _)
+	};
+
+	Ok(
+		infos.iter()
+			.map(|info| (
+				info.SessionId,
+				if info.pWinStationName.is_null() {
+					String::new()
+				} else {
+					unsafe { WString::from_wchars_nullt(info.pWinStationName) }.to_string()
+				},
+				info.State,
+			))
+			.collect(),
+	)
+}
+
+/// [`WTSQuerySessionInformation`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsquerysessioninformationw)
+/// function, for the current server.
+///
+/// Only the information classes which return strings are supported:
+/// `InitialProgram`, `ApplicationName`, `WorkingDirectory`, `UserName`,
+/// `WinStationName`, `DomainName`, `ClientName` and `ClientDirectory`. Any
+/// other class fails with
+/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+///
+/// Querying a session of another user requires appropriate privileges,
+/// otherwise the function fails with
+/// [`co::ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let session_id: u32; // initialized somewhere
+/// # let session_id = 0;
+///
+/// let user = w::WTSQuerySessionInformation(
+///     session_id, co::WTS_INFO_CLASS::UserName)?;
+/// let domain = w::WTSQuerySessionInformation(
+///     session_id, co::WTS_INFO_CLASS::DomainName)?;
+///
+/// println!("{}\\{}", domain, user);
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`WTSEnumerateSessions`](crate::WTSEnumerateSessions)
+#[must_use]
+pub fn WTSQuerySessionInformation(
+	session_id: u32,
+	info_class: co::WTS_INFO_CLASS,
+) -> SysResult<String>
+{
+	match info_class {
+		co::WTS_INFO_CLASS::InitialProgram
+			| co::WTS_INFO_CLASS::ApplicationName
+			| co::WTS_INFO_CLASS::WorkingDirectory
+			| co::WTS_INFO_CLASS::UserName
+			| co::WTS_INFO_CLASS::WinStationName
+			| co::WTS_INFO_CLASS::DomainName
+			| co::WTS_INFO_CLASS::ClientName
+			| co::WTS_INFO_CLASS::ClientDirectory => {},
+		_ => return Err(co::ERROR::INVALID_PARAMETER), // not a string
+	}
+
+	let mut pbuf: PSTR = std::ptr::null_mut();
+	let mut num_bytes = u32::default();
+
+	bool_to_sysresult(
+		unsafe {
+			ffi::WTSQuerySessionInformationW(
+				std::ptr::null_mut(), // WTS_CURRENT_SERVER_HANDLE
+				session_id,
+				info_class.raw(),
+				&mut pbuf,
+				&mut num_bytes,
+			)
+		},
+	)?;
+	let _guard = unsafe { WTSFreeMemoryGuard::new(pbuf as _) };
+
+	Ok(
+		if pbuf.is_null() {
+			String::new()
+		} else {
+			unsafe { WString::from_wchars_nullt(pbuf) }.to_string()
+		},
+	)
+}
@@ -0,0 +1,42 @@
+use crate::wtsapi::ffi;
+
+/// RAII implementation which automatically calls
+/// [`WTSFreeMemory`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsfreememory)
+/// when the object goes out of scope.
+pub struct WTSFreeMemoryGuard {
+	pmem: *mut std::ffi::c_void,
+}
+
+impl Drop for WTSFreeMemoryGuard {
+	fn drop(&mut self) {
+		if !self.pmem.is_null() {
+			unsafe { ffi::WTSFreeMemory(self.pmem); }
+		}
+	}
+}
+
+impl WTSFreeMemoryGuard {
+	/// Constructs the guard.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer was allocated by a WTS function, and must be freed
+	/// with
+	/// [`WTSFreeMemory`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsfreememory)
+	/// at the end of the scope.
+	#[must_use]
+	pub const unsafe fn new(pmem: *mut std::ffi::c_void) -> Self {
+		Self { pmem }
+	}
+
+	/// Ejects the underlying memory pointer, leaving a null pointer in its
+	/// place.
+	///
+	/// Since the internal memory pointer will be invalidated, the destructor
+	/// will not run. It's your responsibility to run it, otherwise you'll cause
+	/// a memory leak.
+	#[must_use]
+	pub fn leak(&mut self) -> *mut std::ffi::c_void {
+		std::mem::replace(&mut self.pmem, std::ptr::null_mut())
+	}
+}
@@ -0,0 +1,11 @@
+#![cfg(feature = "wtsapi")]
+
+mod funcs;
+
+pub(in crate::wtsapi) mod ffi;
+pub mod co;
+pub mod guard;
+
+pub mod decl {
+	pub use super::funcs::*;
+}